@@ -0,0 +1,42 @@
+// Copyright (c) 2019 Alain Brenzikofer
+// This file is part of Encointer
+//
+// Encointer is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Encointer is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Encointer.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition required by Balances RPC extensions.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use encointer_primitives::{
+	balances::{BalanceType, Demurrage},
+	communities::CommunityIdentifier,
+};
+use parity_scale_codec::{Decode, Encode};
+
+sp_api::decl_runtime_apis! {
+
+	pub trait BalancesApi<AccountId, BlockNumber>
+		where AccountId: Encode + Decode,
+		BlockNumber: Encode + Decode{
+
+		/// The balance of `account` in `cid`, with demurrage projected up to `block`. `None` if
+		/// `block` precedes the last update of the balance.
+		fn balance_at_block(cid: &CommunityIdentifier, account: &AccountId, block: BlockNumber) -> Option<BalanceType>;
+		/// The demurrage per block which applies to balances in `cid`.
+		fn demurrage_per_block(cid: &CommunityIdentifier) -> Demurrage;
+		/// The total issuance of `cid`, with demurrage projected up to `block`. `None` if `block`
+		/// precedes the last update of the total issuance.
+		fn total_issuance_at(cid: &CommunityIdentifier, block: BlockNumber) -> Option<BalanceType>;
+	}
+}
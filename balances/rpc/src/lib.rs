@@ -0,0 +1,220 @@
+// Copyright (c) 2019 Alain Brenzikofer
+// This file is part of Encointer
+//
+// Encointer is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Encointer is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Encointer.  If not, see <http://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod tests;
+
+use encointer_primitives::{
+	balances::{
+		balance_history_head_key, balance_history_key, balance_history_link_key, BalanceEntry,
+		BalanceType,
+	},
+	communities::CommunityIdentifier,
+};
+use encointer_rpc::Error;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use pallet_encointer_balances_rpc_runtime_api::BalancesApi as BalancesRuntimeApi;
+use parity_scale_codec::{Decode, Encode};
+use parking_lot::RwLock;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::sync::Arc;
+
+/// The maximum number of entries returned by `encointer_getBalanceHistory`.
+pub const MAX_BALANCE_HISTORY_LEN: u32 = 1000;
+
+#[rpc(client, server)]
+pub trait BalancesApi<BlockHash, AccountId, BlockNumber>
+where
+	AccountId: 'static + Encode + Decode + Send + Sync,
+	BlockNumber: 'static + Encode + Decode + Send + Sync,
+{
+	/// The balance of `account` in `cid` at the future `block`, assuming no transfers happen
+	/// until then. `None` if `block` precedes the last update of the balance.
+	#[method(name = "encointer_getBalanceAtBlock")]
+	fn get_balance_at_block(
+		&self,
+		cid: CommunityIdentifier,
+		account: AccountId,
+		block: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<BalanceEntry<BlockNumber>>>;
+
+	/// The demurrage per block in `cid` as a decimal string.
+	#[method(name = "encointer_getDemurragePerBlock")]
+	fn get_demurrage_per_block(
+		&self,
+		cid: CommunityIdentifier,
+		at: Option<BlockHash>,
+	) -> RpcResult<String>;
+
+	/// The total issuance of `cid` at the future `block`, assuming no issuance or burning
+	/// happens until then. `None` if `block` precedes the last update of the total issuance.
+	#[method(name = "encointer_getTotalIssuanceAt")]
+	fn get_total_issuance_at(
+		&self,
+		cid: CommunityIdentifier,
+		block: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<BalanceEntry<BlockNumber>>>;
+
+	/// The principal of `account` in `cid` after each block in which it changed, oldest first.
+	/// A removed account is listed with a zero principal. Returns at most the `limit` most
+	/// recent entries.
+	///
+	/// The history is recorded on block import and is not reverted on reorgs, so entries above
+	/// the finalized block may stem from an abandoned fork.
+	#[method(name = "encointer_getBalanceHistory")]
+	fn get_balance_history(
+		&self,
+		cid: CommunityIdentifier,
+		account: AccountId,
+		limit: Option<u32>,
+	) -> RpcResult<Vec<BalanceEntry<BlockNumber>>>;
+}
+
+pub struct BalancesRpc<Client, Block, S> {
+	client: Arc<Client>,
+	storage: Arc<RwLock<S>>,
+	offchain_indexing: bool,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block, S> BalancesRpc<C, Block, S>
+where
+	S: 'static + OffchainStorage,
+{
+	/// Create new `Balances` with the given reference to the client and to the offchain storage
+	pub fn new(client: Arc<C>, storage: S, offchain_indexing: bool) -> Self {
+		BalancesRpc {
+			client,
+			storage: Arc::new(RwLock::new(storage)),
+			offchain_indexing,
+			_marker: Default::default(),
+		}
+	}
+
+	pub fn get_storage<V: Decode>(&self, key: &[u8]) -> RpcResult<Option<V>> {
+		self.storage
+			.read()
+			.get(STORAGE_PREFIX, key)
+			.map(|v| Decode::decode(&mut v.as_slice()))
+			.transpose()
+			.map_err(|e| Error::OffchainStorageDecodeError(e.to_string()).into())
+	}
+
+	/// Follows the offchain-indexed records of `account` in `cid` back from the most recent one,
+	/// until `limit` records are collected or the history ends.
+	pub fn balance_history<AccountId: Encode, BlockNumber: Encode + Decode>(
+		&self,
+		cid: &CommunityIdentifier,
+		account: &AccountId,
+		limit: usize,
+	) -> RpcResult<Vec<BalanceEntry<BlockNumber>>> {
+		let mut history = Vec::new();
+		let mut next = self.get_storage::<BlockNumber>(&balance_history_head_key(cid, account))?;
+		while let Some(block) = next {
+			if history.len() >= limit {
+				break;
+			}
+			let Some(principal) =
+				self.get_storage::<BalanceType>(&balance_history_key(cid, account, &block))?
+			else {
+				break;
+			};
+			next = self
+				.get_storage::<Option<BlockNumber>>(&balance_history_link_key(
+					cid, account, &block,
+				))?
+				.flatten();
+			history.push(BalanceEntry { principal, last_update: block });
+		}
+		history.reverse();
+		Ok(history)
+	}
+}
+
+type BlockNumberFor<B> = <<B as BlockT>::Header as HeaderT>::Number;
+
+impl<C, Block, S, AccountId>
+	BalancesApiServer<<Block as BlockT>::Hash, AccountId, BlockNumberFor<Block>>
+	for BalancesRpc<C, Block, S>
+where
+	AccountId: 'static + Clone + Encode + Decode + Send + Sync + PartialEq,
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: BalancesRuntimeApi<Block, AccountId, BlockNumberFor<Block>>,
+	S: 'static + OffchainStorage,
+{
+	fn get_balance_at_block(
+		&self,
+		cid: CommunityIdentifier,
+		account: AccountId,
+		block: BlockNumberFor<Block>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<BalanceEntry<BlockNumberFor<Block>>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let principal = api
+			.balance_at_block(at, &cid, &account, block)
+			.map_err(|e| Error::Runtime(e.into()))?;
+		Ok(principal.map(|principal| BalanceEntry { principal, last_update: block }))
+	}
+
+	fn get_demurrage_per_block(
+		&self,
+		cid: CommunityIdentifier,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<String> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		Ok(api
+			.demurrage_per_block(at, &cid)
+			.map_err(|e| Error::Runtime(e.into()))?
+			.to_string())
+	}
+
+	fn get_total_issuance_at(
+		&self,
+		cid: CommunityIdentifier,
+		block: BlockNumberFor<Block>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<BalanceEntry<BlockNumberFor<Block>>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let principal =
+			api.total_issuance_at(at, &cid, block).map_err(|e| Error::Runtime(e.into()))?;
+		Ok(principal.map(|principal| BalanceEntry { principal, last_update: block }))
+	}
+
+	fn get_balance_history(
+		&self,
+		cid: CommunityIdentifier,
+		account: AccountId,
+		limit: Option<u32>,
+	) -> RpcResult<Vec<BalanceEntry<BlockNumberFor<Block>>>> {
+		if !self.offchain_indexing {
+			return Err(
+				Error::OffchainIndexingDisabled("encointer_getBalanceHistory".to_string()).into()
+			);
+		}
+
+		let limit = limit.unwrap_or(MAX_BALANCE_HISTORY_LEN).min(MAX_BALANCE_HISTORY_LEN);
+		self.balance_history(&cid, &account, limit as usize)
+	}
+}
@@ -0,0 +1,57 @@
+// Copyright (c) 2019 Alain Brenzikofer
+// This file is part of Encointer
+//
+// Encointer is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Encointer is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Encointer.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use sp_core::offchain::storage::InMemOffchainStorage;
+
+fn set_storage<V: Encode>(storage: &mut InMemOffchainStorage, key: &[u8], val: &V) {
+	storage.set(STORAGE_PREFIX, key, &val.encode());
+}
+
+#[test]
+fn balance_history_follows_links() {
+	let cid = CommunityIdentifier::default();
+	let account = 7u64;
+	let mut storage = InMemOffchainStorage::default();
+	set_storage(&mut storage, &balance_history_head_key(&cid, &account), &9u32);
+	set_storage(
+		&mut storage,
+		&balance_history_key(&cid, &account, &9u32),
+		&BalanceType::from_num(3),
+	);
+	set_storage(&mut storage, &balance_history_link_key(&cid, &account, &9u32), &Some(4u32));
+	set_storage(
+		&mut storage,
+		&balance_history_key(&cid, &account, &4u32),
+		&BalanceType::from_num(5),
+	);
+	set_storage(&mut storage, &balance_history_link_key(&cid, &account, &4u32), &None::<u32>);
+
+	let balances: BalancesRpc<_, (), _> = BalancesRpc::new(Arc::new(()), storage, true);
+
+	assert_eq!(
+		balances.balance_history::<_, u32>(&cid, &account, 10).unwrap(),
+		vec![
+			BalanceEntry { principal: BalanceType::from_num(5), last_update: 4 },
+			BalanceEntry { principal: BalanceType::from_num(3), last_update: 9 },
+		]
+	);
+	assert_eq!(
+		balances.balance_history::<_, u32>(&cid, &account, 1).unwrap(),
+		vec![BalanceEntry { principal: BalanceType::from_num(3), last_update: 9 }]
+	);
+	assert!(balances.balance_history::<_, u32>(&cid, &8u64, 10).unwrap().is_empty());
+}
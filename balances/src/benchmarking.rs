@@ -1,8 +1,33 @@
 use crate::*;
 use approx::assert_abs_diff_eq;
-use encointer_primitives::{balances::BalanceType, fixed::traits::LossyInto};
+use encointer_primitives::{
+	balances::{
+		BalanceType, PaymentReference, StandingOrderDue, StandingOrderIdType, StandingOrderPeriod,
+	},
+	fixed::traits::LossyInto,
+};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::{traits::Currency, BoundedVec};
 use frame_system::RawOrigin;
+use sp_std::{vec, vec::Vec};
+
+/// Gives `who` enough native tokens to reserve a deposit.
+fn endow_deposit<T: Config>(who: &T::AccountId) {
+	let amount = T::ApprovalDeposit::get()
+		.saturating_mul(10u32.into())
+		.saturating_add(T::DepositCurrency::minimum_balance());
+	T::DepositCurrency::make_free_balance_be(who, amount);
+}
+
+/// Fills the standing order queue with `n` orders which are due after all others.
+fn queue_standing_orders<T: Config>(n: u32) {
+	StandingOrderQueue::<T>::mutate(|queue| {
+		for i in 0..n {
+			let id = StandingOrderIdType::MAX - StandingOrderIdType::from(i);
+			queue.try_push((StandingOrderDue::Ceremony(CeremonyIndexType::MAX), id)).ok();
+		}
+	});
+}
 
 benchmarks! {
 	transfer {
@@ -19,6 +44,36 @@ benchmarks! {
 		assert_abs_diff_eq!(balance_bob, 10f64, epsilon= 0.0001);
 	}
 
+	transfer_with_reference {
+		let cid = CommunityIdentifier::default();
+		let alice: T::AccountId = account("alice", 1, 1);
+		let bob: T::AccountId = account("bob", 2, 2);
+		let reference = PaymentReference::truncate_from(vec![1u8; 64]);
+
+		Pallet::<T>::issue(cid, &alice, BalanceType::from_num(12i32)).ok();
+	}: _(RawOrigin::Signed(alice.clone()), bob.clone(), cid, BalanceType::from_num(10i32), reference)
+	verify{
+		let balance_bob: f64 = Pallet::<T>::balance(cid, &bob).lossy_into();
+		assert_abs_diff_eq!(balance_bob, 10f64, epsilon= 0.0001);
+	}
+
+	transfer_many {
+		let n in 1 .. T::MaxTransfersPerBatch::get();
+		let cid = CommunityIdentifier::default();
+		let alice: T::AccountId = account("alice", 1, 1);
+		// worst case: every destination needs to be endowed
+		let transfers: Vec<(T::AccountId, BalanceType)> =
+			(0..n).map(|i| (account("dest", i, i), BalanceType::from_num(10i32))).collect();
+
+		Pallet::<T>::issue(cid, &alice, BalanceType::from_num(10 * n + 2)).ok();
+	}: _(RawOrigin::Signed(alice.clone()), cid, BoundedVec::truncate_from(transfers))
+	verify{
+		let balance_alice: f64 = Pallet::<T>::balance(cid, &alice).lossy_into();
+		assert_abs_diff_eq!(balance_alice, 2f64, epsilon= 0.0001);
+		let balance_dest: f64 = Pallet::<T>::balance(cid, &account("dest", n - 1, n - 1)).lossy_into();
+		assert_abs_diff_eq!(balance_dest, 10f64, epsilon= 0.0001);
+	}
+
 	transfer_all {
 		let cid = CommunityIdentifier::default();
 		let alice: T::AccountId = account("alice", 1, 1);
@@ -32,6 +87,100 @@ benchmarks! {
 		assert_abs_diff_eq!(balance_bob, 12f64, epsilon= 0.0001);
 	}
 
+	approve {
+		let cid = CommunityIdentifier::default();
+		let alice: T::AccountId = account("alice", 1, 1);
+		let bob: T::AccountId = account("bob", 2, 2);
+		endow_deposit::<T>(&alice);
+	}: _(RawOrigin::Signed(alice.clone()), cid, bob.clone(), BalanceType::from_num(10i32), None)
+	verify{
+		let allowance: f64 = Pallet::<T>::allowance(cid, &alice, &bob).lossy_into();
+		assert_abs_diff_eq!(allowance, 10f64, epsilon= 0.0001);
+	}
+
+	transfer_from {
+		let cid = CommunityIdentifier::default();
+		let alice: T::AccountId = account("alice", 1, 1);
+		let bob: T::AccountId = account("bob", 2, 2);
+		let charlie: T::AccountId = account("charlie", 3, 3);
+
+		Pallet::<T>::issue(cid, &alice, BalanceType::from_num(12i32)).ok();
+		endow_deposit::<T>(&alice);
+		Pallet::<T>::do_approve(cid, &alice, &bob, BalanceType::from_num(12i32), None).ok();
+	}: _(RawOrigin::Signed(bob.clone()), cid, alice.clone(), charlie.clone(), BalanceType::from_num(10i32))
+	verify{
+		let balance_charlie: f64 = Pallet::<T>::balance(cid, &charlie).lossy_into();
+		assert_abs_diff_eq!(balance_charlie, 10f64, epsilon= 0.0001);
+		let allowance: f64 = Pallet::<T>::allowance(cid, &alice, &bob).lossy_into();
+		assert_abs_diff_eq!(allowance, 2f64, epsilon= 0.0001);
+	}
+
+	create_standing_order {
+		let n in 1 .. T::MaxStandingOrders::get();
+		let cid = CommunityIdentifier::default();
+		let alice: T::AccountId = account("alice", 1, 1);
+		let bob: T::AccountId = account("bob", 2, 2);
+		let period = StandingOrderPeriod::Blocks(10u32.into());
+		Pallet::<T>::issue(cid, &alice, BalanceType::from_num(12i32)).ok();
+		endow_deposit::<T>(&alice);
+		queue_standing_orders::<T>(n - 1);
+	}: _(RawOrigin::Signed(alice.clone()), cid, bob, BalanceType::from_num(10i32), period, None)
+	verify{
+		assert!(StandingOrders::<T>::contains_key(0));
+		assert_eq!(StandingOrderQueue::<T>::get().len(), n as usize);
+	}
+
+	cancel_standing_order {
+		let n in 1 .. T::MaxStandingOrders::get();
+		let cid = CommunityIdentifier::default();
+		let alice: T::AccountId = account("alice", 1, 1);
+		let bob: T::AccountId = account("bob", 2, 2);
+		let period = StandingOrderPeriod::Blocks(10u32.into());
+		Pallet::<T>::issue(cid, &alice, BalanceType::from_num(12i32)).ok();
+		endow_deposit::<T>(&alice);
+		Pallet::<T>::create_standing_order(
+			RawOrigin::Signed(alice.clone()).into(),
+			cid,
+			bob,
+			BalanceType::from_num(10i32),
+			period,
+			None
+		).ok();
+		queue_standing_orders::<T>(n - 1);
+	}: _(RawOrigin::Signed(alice), 0)
+	verify{
+		assert!(!StandingOrders::<T>::contains_key(0));
+		assert_eq!(StandingOrderQueue::<T>::get().len(), n as usize - 1);
+	}
+
+	execute_standing_order {
+		let n in 1 .. T::MaxStandingOrders::get();
+		let cid = CommunityIdentifier::default();
+		let alice: T::AccountId = account("alice", 1, 1);
+		let bob: T::AccountId = account("bob", 2, 2);
+		let period = StandingOrderPeriod::Blocks(10u32.into());
+
+		Pallet::<T>::issue(cid, &alice, BalanceType::from_num(12i32)).ok();
+		endow_deposit::<T>(&alice);
+		Pallet::<T>::create_standing_order(
+			RawOrigin::Signed(alice.clone()).into(),
+			cid,
+			bob.clone(),
+			BalanceType::from_num(10i32),
+			period,
+			None
+		).ok();
+		queue_standing_orders::<T>(n - 1);
+	}: {
+		let (due, id) = StandingOrderQueue::<T>::get()[0];
+		Pallet::<T>::execute_standing_order(id, due);
+	}
+	verify{
+		let balance_bob: f64 = Pallet::<T>::balance(cid, &bob).lossy_into();
+		assert_abs_diff_eq!(balance_bob, 10f64, epsilon= 0.0001);
+		assert_eq!(StandingOrderQueue::<T>::get().len(), n as usize);
+	}
+
 	set_fee_conversion_factor {
 		let alice: T::AccountId = account("alice", 1, 1);
 		let f : FeeConversionFactorType = 1;
@@ -18,6 +18,7 @@ use super::*;
 use crate::fungibles::{DecreaseIssuance, IncreaseIssuance};
 use encointer_primitives::balances::EncointerBalanceConverter;
 use sp_runtime::traits::{Convert, Zero};
+use sp_std::cmp::max;
 
 pub use frame_support::traits::tokens::{
 	DepositConsequence, Fortitude, Preservation, Provenance, WithdrawConsequence,
@@ -43,8 +44,8 @@ impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
 		Pallet::<T>::total_issuance(asset) > 0
 	}
 
-	fn minimum_balance(_asset: Self::AssetId) -> Self::Balance {
-		0
+	fn minimum_balance(asset: Self::AssetId) -> Self::Balance {
+		fungible(Pallet::<T>::existential_deposit(asset))
 	}
 
 	fn balance(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
@@ -52,16 +53,21 @@ impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
 	}
 
 	fn total_balance(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
-		fungible(Pallet::<T>::balance(asset, who))
+		fungible(
+			Pallet::<T>::balance(asset, who).saturating_add(Pallet::<T>::held_balance(asset, who)),
+		)
 	}
 
 	fn reducible_balance(
 		asset: Self::AssetId,
 		who: &T::AccountId,
 		_preservation: Preservation,
-		_force: Fortitude,
+		force: Fortitude,
 	) -> Self::Balance {
-		fungible(Pallet::<T>::balance(asset, who))
+		match force {
+			Fortitude::Force => fungible(Pallet::<T>::balance(asset, who)),
+			Fortitude::Polite => fungible(Pallet::<T>::spendable_balance(asset, who)),
+		}
 	}
 
 	fn can_deposit(
@@ -74,7 +80,7 @@ impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
 			return DepositConsequence::UnknownAsset;
 		};
 
-		let total_issuance = Pallet::<T>::total_issuance_entry(asset).principal;
+		let total_issuance = Pallet::<T>::total_issuance(asset);
 
 		let balance_amount = balance_type(amount);
 		if total_issuance.checked_add(balance_amount).is_none() {
@@ -87,6 +93,14 @@ impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
 			return DepositConsequence::Overflow;
 		}
 
+		// like `do_transfer`, only new accounts need to exceed the existential deposit
+		if !amount.is_zero() &&
+			!<Balance<T>>::contains_key(asset, who) &&
+			balance_amount <= Pallet::<T>::existential_deposit(asset)
+		{
+			return DepositConsequence::BelowMinimum;
+		}
+
 		DepositConsequence::Success
 	}
 
@@ -101,8 +115,8 @@ impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
 			return UnknownAsset;
 		};
 
-		let total_issuance = Pallet::<T>::total_issuance_entry(asset);
-		if fungible(total_issuance.principal).checked_sub(amount).is_none() {
+		let total_issuance = Pallet::<T>::total_issuance(asset);
+		if fungible(total_issuance).checked_sub(amount).is_none() {
 			return Underflow;
 		}
 
@@ -112,8 +126,11 @@ impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
 
 		let balance = fungible(Pallet::<T>::balance(asset, who));
 
-		if balance.checked_sub(amount).is_none() {
+		let Some(remaining) = balance.checked_sub(amount) else {
 			return WithdrawConsequence::BalanceLow;
+		};
+		if remaining < fungible(Pallet::<T>::untouchable_balance(asset, who)) {
+			return WithdrawConsequence::Frozen;
 		}
 		WithdrawConsequence::Success
 	}
@@ -125,8 +142,12 @@ impl<T: Config> fungibles::Unbalanced<T::AccountId> for Pallet<T> {
 		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> Result<Option<Self::Balance>, DispatchError> {
+		if Pallet::<T>::is_demurrage_exempt(asset, who) {
+			let old_balance = Pallet::<T>::balance(asset, who);
+			Pallet::<T>::shift_exempt_issuance_by_change(asset, old_balance, balance_type(amount));
+		}
 		let current_block = frame_system::Pallet::<T>::block_number();
-		<Balance<T>>::insert(
+		Pallet::<T>::insert_balance_entry(
 			asset,
 			who,
 			BalanceEntry { principal: balance_type(amount), last_update: current_block },
@@ -136,10 +157,9 @@ impl<T: Config> fungibles::Unbalanced<T::AccountId> for Pallet<T> {
 
 	fn set_total_issuance(asset: Self::AssetId, amount: Self::Balance) {
 		let current_block = frame_system::Pallet::<T>::block_number();
-		<TotalIssuance<T>>::insert(
-			asset,
-			BalanceEntry { principal: balance_type(amount), last_update: current_block },
-		);
+		// the exempt part of the issuance is tracked along with the exempt balances
+		let principal = balance_type(amount).saturating_sub(<ExemptIssuance<T>>::get(asset));
+		<TotalIssuance<T>>::insert(asset, BalanceEntry { principal, last_update: current_block });
 	}
 
 	fn handle_dust(_dust: fungibles::Dust<T::AccountId, Self>) {}
@@ -149,3 +169,207 @@ impl<T: Config> fungibles::Balanced<T::AccountId> for Pallet<T> {
 	type OnDropDebt = IncreaseIssuance<T::AccountId, Self>;
 	type OnDropCredit = DecreaseIssuance<T::AccountId, Self>;
 }
+
+impl<T: Config> fungibles::InspectHold<T::AccountId> for Pallet<T> {
+	type Reason = T::RuntimeHoldReason;
+
+	fn total_balance_on_hold(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
+		fungible(Pallet::<T>::held_balance(asset, who))
+	}
+
+	fn reducible_total_balance_on_hold(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		force: Fortitude,
+	) -> Self::Balance {
+		let held = Pallet::<T>::held_balance(asset, who);
+		let unavailable = match force {
+			Fortitude::Force => BalanceType::from_num(0),
+			// the part of a freeze which isn't covered by the free balance
+			Fortitude::Polite => Pallet::<T>::frozen_balance(asset, who)
+				.saturating_sub(Pallet::<T>::balance(asset, who))
+				.max(BalanceType::from_num(0)),
+		};
+		fungible(held.saturating_sub(unavailable).max(BalanceType::from_num(0)))
+	}
+
+	fn balance_on_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+	) -> Self::Balance {
+		<Holds<T>>::get(asset, who)
+			.into_iter()
+			.find(|hold| &hold.id == reason)
+			.map(|hold| {
+				fungible(
+					Pallet::<T>::apply_demurrage(
+						hold.amount,
+						Pallet::<T>::account_demurrage(&asset, who),
+					)
+					.principal,
+				)
+			})
+			.unwrap_or_default()
+	}
+
+	fn hold_available(asset: Self::AssetId, reason: &Self::Reason, who: &T::AccountId) -> bool {
+		if !<Balance<T>>::contains_key(asset, who) {
+			return false;
+		}
+		let holds = <Holds<T>>::get(asset, who);
+		holds.iter().any(|hold| &hold.id == reason) || holds.len() < T::MaxHolds::get() as usize
+	}
+}
+
+impl<T: Config> fungibles::UnbalancedHold<T::AccountId> for Pallet<T> {
+	fn set_balance_on_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		let old_amount =
+			balance_type(<Self as fungibles::InspectHold<_>>::balance_on_hold(asset, reason, who));
+		let current_block = frame_system::Pallet::<T>::block_number();
+		let entry = BalanceEntry { principal: balance_type(amount), last_update: current_block };
+		<Holds<T>>::try_mutate_exists(asset, who, |maybe_holds| -> DispatchResult {
+			let mut holds = maybe_holds.take().unwrap_or_default();
+			match holds.iter().position(|hold| &hold.id == reason) {
+				Some(i) if amount.is_zero() => {
+					holds.remove(i);
+				},
+				Some(i) => holds[i].amount = entry,
+				None if amount.is_zero() => (),
+				None => holds
+					.try_push(IdAmount { id: *reason, amount: entry })
+					.map_err(|_| Error::<T>::TooManyHolds)?,
+			}
+			*maybe_holds = (!holds.is_empty()).then_some(holds);
+			Ok(())
+		})?;
+		if Pallet::<T>::is_demurrage_exempt(asset, who) {
+			Pallet::<T>::shift_exempt_issuance_by_change(asset, old_amount, balance_type(amount));
+		}
+		Ok(())
+	}
+}
+
+impl<T: Config> fungibles::MutateHold<T::AccountId> for Pallet<T> {}
+
+impl<T: Config> fungibles::InspectFreeze<T::AccountId> for Pallet<T> {
+	type Id = T::RuntimeFreezeReason;
+
+	fn balance_frozen(asset: Self::AssetId, id: &Self::Id, who: &T::AccountId) -> Self::Balance {
+		<Freezes<T>>::get(asset, who)
+			.into_iter()
+			.find(|freeze| &freeze.id == id)
+			.map(|freeze| fungible(freeze.amount))
+			.unwrap_or_default()
+	}
+
+	fn can_freeze(asset: Self::AssetId, id: &Self::Id, who: &T::AccountId) -> bool {
+		let freezes = <Freezes<T>>::get(asset, who);
+		freezes.iter().any(|freeze| &freeze.id == id) ||
+			freezes.len() < T::MaxFreezes::get() as usize
+	}
+}
+
+impl<T: Config> fungibles::MutateFreeze<T::AccountId> for Pallet<T> {
+	fn set_freeze(
+		asset: Self::AssetId,
+		id: &Self::Id,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Self::thaw(asset, id, who);
+		}
+		mutate_freeze::<T>(asset, id, who, |_| amount)
+	}
+
+	fn extend_freeze(
+		asset: Self::AssetId,
+		id: &Self::Id,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		mutate_freeze::<T>(asset, id, who, |frozen| max(frozen, amount))
+	}
+
+	fn thaw(asset: Self::AssetId, id: &Self::Id, who: &T::AccountId) -> DispatchResult {
+		<Freezes<T>>::mutate_exists(asset, who, |maybe_freezes| {
+			if let Some(freezes) = maybe_freezes {
+				freezes.retain(|freeze| &freeze.id != id);
+				if freezes.is_empty() {
+					*maybe_freezes = None;
+				}
+			}
+		});
+		Ok(())
+	}
+}
+
+/// Sets the freeze `id` of `who` to the amount returned by `f`, which is passed the amount
+/// currently frozen by `id`.
+fn mutate_freeze<T: Config>(
+	asset: CommunityIdentifier,
+	id: &T::RuntimeFreezeReason,
+	who: &T::AccountId,
+	f: impl FnOnce(u128) -> u128,
+) -> DispatchResult {
+	<Freezes<T>>::try_mutate(asset, who, |freezes| -> DispatchResult {
+		match freezes.iter_mut().find(|freeze| &freeze.id == id) {
+			Some(freeze) => freeze.amount = balance_type(f(fungible(freeze.amount))),
+			None => freezes
+				.try_push(IdAmount { id: *id, amount: balance_type(f(0)) })
+				.map_err(|_| Error::<T>::TooManyFreezes)?,
+		}
+		Ok(())
+	})
+}
+
+impl<T: Config> fungibles::approvals::Inspect<T::AccountId> for Pallet<T> {
+	fn allowance(asset: Self::AssetId, owner: &T::AccountId, delegate: &T::AccountId) -> u128 {
+		fungible(Pallet::<T>::allowance(asset, owner, delegate))
+	}
+}
+
+impl<T: Config> fungibles::approvals::Mutate<T::AccountId> for Pallet<T> {
+	/// Adds `amount` to the allowance of `delegate`, like `pallet-assets` does. An allowance which
+	/// is still valid keeps its expiry, otherwise the new allowance does not expire.
+	fn approve(
+		asset: Self::AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		let current = Pallet::<T>::allowance(asset, owner, delegate);
+		let expiry = if current > 0u128 {
+			<Approvals<T>>::get(asset, (owner, delegate)).and_then(|allowance| allowance.expiry)
+		} else {
+			None
+		};
+		Pallet::<T>::do_approve(
+			asset,
+			owner,
+			delegate,
+			current.saturating_add(balance_type(amount)),
+			expiry,
+		)
+	}
+
+	fn transfer_from(
+		asset: Self::AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		dest: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Pallet::<T>::do_transfer_from(asset, owner, delegate, dest, balance_type(amount))?;
+		Ok(())
+	}
+}
@@ -19,17 +19,28 @@
 pub use crate::weights::WeightInfo;
 use core::marker::PhantomData;
 use encointer_primitives::{
-	balances::{BalanceEntry, BalanceType, Demurrage, FeeConversionFactorType},
+	balances::{
+		balance_history_head_key, balance_history_key, balance_history_link_key, Allowance,
+		BalanceEntry, BalanceType, Demurrage, FeeConversionFactorType, PaymentReference,
+		StandingOrder, StandingOrderDue, StandingOrderIdType, StandingOrderPeriod,
+	},
 	communities::{validate_demurrage, CommunityIdentifier, RangeError},
+	scheduler::CeremonyIndexType,
 };
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
-	traits::{tokens::fungibles, Get},
+	storage::with_storage_layer,
+	traits::{
+		tokens::{fungibles, IdAmount},
+		Currency, Get, ReservableCurrency,
+	},
+	weights::Weight,
 };
 use frame_system::{self as frame_system, ensure_signed, pallet_prelude::BlockNumberFor};
 use log::{debug, info};
-use sp_runtime::DispatchError;
+use parity_scale_codec::Encode;
+use sp_runtime::{traits::Saturating, ArithmeticError, DispatchError};
 use sp_std::convert::TryInto;
 
 // Logger target
@@ -46,6 +57,9 @@ const LOG: &str = "encointer";
 // BalanceType::from_bits(0x0000000000000000000001E3F0A8A973_i128);
 pub use pallet::*;
 
+pub type DepositBalanceOf<T> =
+	<<T as Config>::DepositCurrency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 mod impl_fungibles;
 pub mod weights;
 
@@ -73,18 +87,66 @@ pub mod pallet {
 		#[pallet::constant]
 		type DefaultDemurrage: Get<Demurrage>;
 
-		/// Existential deposit needed to have an account in the respective community currency
+		/// Existential deposit needed to have an account in the respective community currency,
+		/// unless the community defines its own.
 		///
 		/// This does currently not prevent dust-accounts, but it prevents account creation
 		/// by transferring tiny amounts of funds.
 		#[pallet::constant]
 		type ExistentialDeposit: Get<BalanceType>;
 
+		/// The overarching reason for which community currency can be put on hold.
+		type RuntimeHoldReason: Parameter + Member + MaxEncodedLen + Copy;
+
+		/// The overarching identifier for freezes of community currency.
+		type RuntimeFreezeReason: Parameter + Member + MaxEncodedLen + Copy;
+
+		/// The maximum number of holds per community and account.
+		#[pallet::constant]
+		type MaxHolds: Get<u32>;
+
+		/// The maximum number of freezes per community and account.
+		#[pallet::constant]
+		type MaxFreezes: Get<u32>;
+
+		/// Currency in which deposits for allowances are reserved.
+		type DepositCurrency: ReservableCurrency<Self::AccountId>;
+
+		/// Native tokens reserved from the owner of an allowance as long as it exists.
+		#[pallet::constant]
+		type ApprovalDeposit: Get<DepositBalanceOf<Self>>;
+
+		/// Native tokens reserved from the payer of a standing order as long as it exists.
+		#[pallet::constant]
+		type StandingOrderDeposit: Get<DepositBalanceOf<Self>>;
+
+		/// The current ceremony index, which schedules standing orders paid once per cycle.
+		type CurrentCeremonyIndex: Get<CeremonyIndexType>;
+
+		/// The maximum number of standing orders which can be active at the same time.
+		#[pallet::constant]
+		type MaxStandingOrders: Get<u32>;
+
+		/// The maximum number of standing orders per payer.
+		#[pallet::constant]
+		type MaxStandingOrdersPerPayer: Get<u32>;
+
+		/// The maximum number of destinations in a single `transfer_many`.
+		#[pallet::constant]
+		type MaxTransfersPerBatch: Get<u32>;
+
 		type WeightInfo: WeightInfo;
 
 		type CeremonyMaster: EnsureOrigin<Self::RuntimeOrigin>;
 	}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_block: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::execute_due_standing_orders(remaining_weight)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Transfer some balance to another account.
@@ -101,13 +163,44 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Transfer some balance to another account, tagged with a `reference` that lets the
+		/// recipient match the payment, e.g. to an invoice.
+		#[pallet::call_index(7)]
+		#[pallet::weight((<T as Config>::WeightInfo::transfer_with_reference(), DispatchClass::Normal, Pays::Yes))]
+		pub fn transfer_with_reference(
+			origin: OriginFor<T>,
+			dest: T::AccountId,
+			community_id: CommunityIdentifier,
+			amount: BalanceType,
+			reference: PaymentReference,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			Self::do_transfer_with_reference(community_id, &from, &dest, amount, Some(reference))?;
+			Ok(().into())
+		}
+
+		/// Transfer balance in `cid` to several accounts at once, e.g. for payroll.
+		///
+		/// Either all transfers succeed or none. Transfers to the sender are ignored.
+		#[pallet::call_index(8)]
+		#[pallet::weight((<T as Config>::WeightInfo::transfer_many(transfers.len() as u32), DispatchClass::Normal, Pays::Yes))]
+		pub fn transfer_many(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			transfers: BoundedVec<(T::AccountId, BalanceType), T::MaxTransfersPerBatch>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			Self::do_transfer_many(cid, &from, &transfers)?;
+			Ok(().into())
+		}
+
 		#[pallet::call_index(1)]
 		#[pallet::weight((<T as Config>::WeightInfo::set_fee_conversion_factor(), DispatchClass::Normal))]
 		pub fn set_fee_conversion_factor(
 			origin: OriginFor<T>,
 			fee_conversion_factor: FeeConversionFactorType,
 		) -> DispatchResultWithPostInfo {
-			T::CeremonyMaster::ensure_origin(origin)?;
+			<T as Config>::CeremonyMaster::ensure_origin(origin)?;
 			<FeeConversionFactor<T>>::put(fee_conversion_factor);
 			info!(target: LOG, "set fee conversion factor to {fee_conversion_factor}");
 			Self::deposit_event(Event::FeeConversionFactorUpdated(fee_conversion_factor));
@@ -122,10 +215,123 @@ pub mod pallet {
 			cid: CommunityIdentifier,
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
-			let amount = Self::balance(cid, &from);
+			let amount = Self::spendable_balance(cid, &from);
 			Self::do_transfer(cid, &from, &dest, amount)?;
 			Ok(().into())
 		}
+
+		/// Allow `spender` to transfer up to `amount` of the caller's balance in `cid` until the
+		/// block `expiry` (inclusive). Replaces any previous allowance; an `amount` of zero
+		/// revokes it.
+		///
+		/// The allowance is subject to the same demurrage as the caller's balance. A deposit of
+		/// `ApprovalDeposit` is reserved from the caller until the allowance is revoked or used
+		/// up, so expired allowances should be revoked.
+		#[pallet::call_index(3)]
+		#[pallet::weight((<T as Config>::WeightInfo::approve(), DispatchClass::Normal))]
+		pub fn approve(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			spender: T::AccountId,
+			amount: BalanceType,
+			expiry: Option<BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			if let Some(expiry) = expiry {
+				ensure!(
+					expiry >= frame_system::Pallet::<T>::block_number(),
+					Error::<T>::AllowanceExpired
+				);
+			}
+			Self::do_approve(cid, &owner, &spender, amount, expiry)?;
+			Ok(().into())
+		}
+
+		/// Transfer `amount` from `owner` to `dest`, spending the allowance `owner` has granted
+		/// the caller.
+		#[pallet::call_index(4)]
+		#[pallet::weight((<T as Config>::WeightInfo::transfer_from(), DispatchClass::Normal))]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			owner: T::AccountId,
+			dest: T::AccountId,
+			amount: BalanceType,
+		) -> DispatchResultWithPostInfo {
+			let spender = ensure_signed(origin)?;
+			Self::do_transfer_from(cid, &owner, &spender, &dest, amount)?;
+			Ok(().into())
+		}
+
+		/// Pay `amount` to `recipient` every `period` until the block `end` (inclusive).
+		///
+		/// The first payment is due one period from now. Orders are executed when the chain is
+		/// idle, so payments may be delayed. An order is dropped if a payment fails.
+		///
+		/// `StandingOrderDeposit` is reserved from the payer until the order is cancelled,
+		/// completed or dropped.
+		#[pallet::call_index(5)]
+		#[pallet::weight((
+			<T as Config>::WeightInfo::create_standing_order(T::MaxStandingOrders::get()),
+			DispatchClass::Normal
+		))]
+		pub fn create_standing_order(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			recipient: T::AccountId,
+			amount: BalanceType,
+			period: StandingOrderPeriod<BlockNumberFor<T>>,
+			end: Option<BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			let payer = ensure_signed(origin)?;
+			ensure!(amount > 0, Error::<T>::InvalidStandingOrderAmount);
+			ensure!(<Balance<T>>::contains_key(cid, &payer), Error::<T>::NoAccount);
+			if let StandingOrderPeriod::Blocks(blocks) = period {
+				ensure!(!blocks.is_zero(), Error::<T>::InvalidStandingOrderPeriod);
+			}
+
+			let id = Self::standing_order_count();
+			let next_id = id.checked_add(1).ok_or(Error::<T>::StandingOrderIdOutOfBounds)?;
+			<StandingOrdersOf<T>>::try_mutate(&payer, |ids| ids.try_push(id))
+				.map_err(|_| Error::<T>::TooManyStandingOrders)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::index_standing_order(id, Some(Self::next_standing_order_due(period, now)))?;
+			let deposit = T::StandingOrderDeposit::get();
+			T::DepositCurrency::reserve(&payer, deposit)?;
+			<StandingOrderCount<T>>::put(next_id);
+			<StandingOrders<T>>::insert(
+				id,
+				StandingOrder {
+					payer: payer.clone(),
+					recipient: recipient.clone(),
+					cid,
+					amount,
+					period,
+					end,
+					deposit,
+				},
+			);
+
+			Self::deposit_event(Event::StandingOrderCreated { id, cid, payer, recipient, amount });
+			Ok(().into())
+		}
+
+		#[pallet::call_index(6)]
+		#[pallet::weight((
+			<T as Config>::WeightInfo::cancel_standing_order(T::MaxStandingOrders::get()),
+			DispatchClass::Normal
+		))]
+		pub fn cancel_standing_order(
+			origin: OriginFor<T>,
+			id: StandingOrderIdType,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let order = Self::standing_orders(id).ok_or(Error::<T>::StandingOrderInexistent)?;
+			ensure!(order.payer == sender, Error::<T>::NotStandingOrderPayer);
+			Self::remove_standing_order(id, &order);
+			Self::deposit_event(Event::StandingOrderCancelled { id });
+			Ok(().into())
+		}
 	}
 
 	#[derive(frame_support::DefaultNoBound)]
@@ -169,6 +375,39 @@ pub mod pallet {
 		Burned(CommunityIdentifier, T::AccountId, BalanceType),
 		/// fee conversion factor updated successfully
 		FeeConversionFactorUpdated(FeeConversionFactorType),
+		/// An allowance has been set. A zero `amount` means it has been revoked
+		Approved {
+			cid: CommunityIdentifier,
+			owner: T::AccountId,
+			spender: T::AccountId,
+			amount: BalanceType,
+			expiry: Option<BlockNumberFor<T>>,
+		},
+		/// A standing order has been created
+		StandingOrderCreated {
+			id: StandingOrderIdType,
+			cid: CommunityIdentifier,
+			payer: T::AccountId,
+			recipient: T::AccountId,
+			amount: BalanceType,
+		},
+		/// A payment of a standing order has been made
+		StandingOrderExecuted { id: StandingOrderIdType },
+		/// A standing order has been cancelled by its payer
+		StandingOrderCancelled { id: StandingOrderIdType },
+		/// A standing order has reached its end
+		StandingOrderCompleted { id: StandingOrderIdType },
+		/// A standing order has been dropped because its payment failed
+		StandingOrderDropped { id: StandingOrderIdType, error: DispatchError },
+		/// Follows `Transferred` for a payment tagged with a reference `[community_id, from, to,
+		/// amount, reference]`
+		TransferredWithReference(
+			CommunityIdentifier,
+			T::AccountId,
+			T::AccountId,
+			BalanceType,
+			PaymentReference,
+		),
 	}
 
 	#[pallet::error]
@@ -181,6 +420,28 @@ pub mod pallet {
 		NoAccount,
 		/// Balance too low to create an account
 		ExistentialDeposit,
+		/// the balance is frozen and can't be spent
+		Frozen,
+		/// the account has reached the maximum number of holds in this community
+		TooManyHolds,
+		/// the account has reached the maximum number of freezes in this community
+		TooManyFreezes,
+		/// the amount exceeds the allowance granted by the owner
+		AllowanceExceeded,
+		/// the allowance has expired
+		AllowanceExpired,
+		/// too many standing orders
+		TooManyStandingOrders,
+		/// the period of a standing order must not be zero
+		InvalidStandingOrderPeriod,
+		/// the amount of a standing order must be positive
+		InvalidStandingOrderAmount,
+		/// standing order id out of bounds
+		StandingOrderIdOutOfBounds,
+		/// no standing order with this id
+		StandingOrderInexistent,
+		/// only the payer can cancel a standing order
+		NotStandingOrderPayer,
 	}
 
 	#[pallet::storage]
@@ -205,11 +466,115 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Balances which are on hold, by reason. They are part of the total issuance and are subject
+	/// to demurrage just like free balances.
+	#[pallet::storage]
+	#[pallet::getter(fn holds)]
+	pub type Holds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityIdentifier,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<T::RuntimeHoldReason, BalanceEntry<BlockNumberFor<T>>>, T::MaxHolds>,
+		ValueQuery,
+	>;
+
+	/// Nominal amounts of the total balance (free and on hold) which must not be spent, by id.
+	/// Freezes overlap and are not subject to demurrage.
+	#[pallet::storage]
+	#[pallet::getter(fn freezes)]
+	pub type Freezes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityIdentifier,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<T::RuntimeFreezeReason, BalanceType>, T::MaxFreezes>,
+		ValueQuery,
+	>;
+
+	/// Allowances keyed by (owner, spender). They decay with the demurrage applying to the owner,
+	/// so an allowance keeps its share of the owner's balance. The owner's deposit is returned
+	/// once the allowance is revoked or used up.
+	#[pallet::storage]
+	pub type Approvals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityIdentifier,
+		Blake2_128Concat,
+		(T::AccountId, T::AccountId),
+		Allowance<BlockNumberFor<T>, DepositBalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// Accounts whose balances (free and on hold) are exempt from demurrage, e.g. community
+	/// treasuries or escrow accounts.
+	#[pallet::storage]
+	pub type DemurrageExempt<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityIdentifier,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// The part of the total issuance which is held by demurrage-exempt accounts. `TotalIssuance`
+	/// only accounts for the part which is subject to demurrage.
+	#[pallet::storage]
+	#[pallet::getter(fn exempt_issuance)]
+	pub type ExemptIssuance<T: Config> =
+		StorageMap<_, Blake2_128Concat, CommunityIdentifier, BalanceType, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn standing_order_count)]
+	pub type StandingOrderCount<T: Config> = StorageValue<_, StandingOrderIdType, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn standing_orders)]
+	pub type StandingOrders<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		StandingOrderIdType,
+		StandingOrder<T::AccountId, BlockNumberFor<T>, DepositBalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The ids of the standing orders of each payer.
+	#[pallet::storage]
+	#[pallet::getter(fn standing_orders_of)]
+	pub type StandingOrdersOf<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<StandingOrderIdType, T::MaxStandingOrdersPerPayer>,
+		ValueQuery,
+	>;
+
+	/// All standing orders, sorted by when they are due next.
+	#[pallet::storage]
+	#[pallet::getter(fn standing_order_queue)]
+	pub type StandingOrderQueue<T: Config> = StorageValue<
+		_,
+		BoundedVec<
+			(StandingOrderDue<BlockNumberFor<T>>, StandingOrderIdType),
+			T::MaxStandingOrders,
+		>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn demurrage_per_block)]
 	pub type DemurragePerBlock<T: Config> =
 		StorageMap<_, Blake2_128Concat, CommunityIdentifier, Demurrage, ValueQuery>;
 
+	/// Community-specific existential deposits, overriding `Config::ExistentialDeposit`.
+	#[pallet::storage]
+	pub type ExistentialDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, CommunityIdentifier, BalanceType, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn fee_conversion_factor)]
 	pub(super) type FeeConversionFactor<T: Config> =
@@ -227,11 +592,81 @@ impl<T: Config> Pallet<T> {
 		who: &T::AccountId,
 	) -> BalanceEntry<BlockNumberFor<T>> {
 		let entry = <Balance<T>>::get(community_id, who);
-		Self::apply_demurrage(entry, Self::demurrage(&community_id))
+		Self::apply_demurrage(entry, Self::account_demurrage(&community_id, who))
+	}
+
+	/// Returns the sum of all balances `who` has on hold in `cid`, after demurrage.
+	pub fn held_balance(cid: CommunityIdentifier, who: &T::AccountId) -> BalanceType {
+		let demurrage = Self::account_demurrage(&cid, who);
+		<Holds<T>>::get(cid, who)
+			.into_iter()
+			.fold(BalanceType::from_num(0), |total, hold| {
+				total.saturating_add(Self::apply_demurrage(hold.amount, demurrage).principal)
+			})
+	}
+
+	/// Returns the frozen part of the total balance of `who` in `cid`. As freezes overlap, this
+	/// is the largest of them.
+	pub fn frozen_balance(cid: CommunityIdentifier, who: &T::AccountId) -> BalanceType {
+		<Freezes<T>>::get(cid, who)
+			.into_iter()
+			.map(|freeze| freeze.amount)
+			.max()
+			.unwrap_or_else(|| BalanceType::from_num(0))
+	}
+
+	/// Returns the part of the free balance of `who` in `cid` which must not be spent because
+	/// of freezes. Balances on hold count towards the freezes.
+	fn untouchable_balance(cid: CommunityIdentifier, who: &T::AccountId) -> BalanceType {
+		Self::frozen_balance(cid, who)
+			.saturating_sub(Self::held_balance(cid, who))
+			.max(BalanceType::from_num(0))
+	}
+
+	/// Returns the part of the free balance of `who` in `cid` which is not frozen.
+	pub fn spendable_balance(cid: CommunityIdentifier, who: &T::AccountId) -> BalanceType {
+		Self::balance(cid, who)
+			.saturating_sub(Self::untouchable_balance(cid, who))
+			.max(BalanceType::from_num(0))
 	}
 
 	pub fn total_issuance(community_id: CommunityIdentifier) -> BalanceType {
-		Self::total_issuance_entry_updated(community_id).principal
+		Self::total_issuance_entry_updated(community_id)
+			.principal
+			.saturating_add(<ExemptIssuance<T>>::get(community_id))
+	}
+
+	/// Returns the balance of `who` in `cid` with demurrage applied up to `block`, assuming no
+	/// transfers happen until then. Returns `None` if `block` precedes the last update, as past
+	/// balances can not be derived from the current entry.
+	pub fn balance_at_block(
+		cid: CommunityIdentifier,
+		who: &T::AccountId,
+		block: BlockNumberFor<T>,
+	) -> Option<BalanceType> {
+		let entry = <Balance<T>>::get(cid, who);
+		if block < entry.last_update {
+			return None;
+		}
+		Some(entry.apply_demurrage(Self::account_demurrage(&cid, who), block).principal)
+	}
+
+	/// Returns the total issuance of `cid` with demurrage applied up to `block`, analogous to
+	/// `balance_at_block`.
+	pub fn total_issuance_at(
+		cid: CommunityIdentifier,
+		block: BlockNumberFor<T>,
+	) -> Option<BalanceType> {
+		let entry = <TotalIssuance<T>>::get(cid);
+		if block < entry.last_update {
+			return None;
+		}
+		Some(
+			entry
+				.apply_demurrage(Self::demurrage(&cid), block)
+				.principal
+				.saturating_add(<ExemptIssuance<T>>::get(cid)),
+		)
 	}
 
 	/// get total_issuance and apply demurrage. This is not a noop! It changes state.
@@ -255,20 +690,64 @@ impl<T: Config> Pallet<T> {
 		entry.apply_demurrage(demurrage, current_block)
 	}
 
+	/// Writes the balance entry of `who` in `cid` and records its principal in the balance
+	/// history.
+	pub(crate) fn insert_balance_entry(
+		cid: CommunityIdentifier,
+		who: &T::AccountId,
+		entry: BalanceEntry<BlockNumberFor<T>>,
+	) {
+		let previous_update =
+			<Balance<T>>::try_get(cid, who).ok().map(|previous| previous.last_update);
+		Self::record_balance_history(cid, who, previous_update, entry.principal);
+		<Balance<T>>::insert(cid, who, entry);
+	}
+
+	/// Records `principal` as the balance of `who` in `cid` in the offchain index, linked to the
+	/// block of the previous record, to serve the balance history RPC. Removed accounts are
+	/// recorded with a zero principal.
+	///
+	/// The offchain index is written on import of every block, including blocks of forks which
+	/// are abandoned later, and is not reverted on reorgs. As records are keyed by block number,
+	/// records above the finalized block may thus stem from a non-canonical block.
+	fn record_balance_history(
+		cid: CommunityIdentifier,
+		who: &T::AccountId,
+		previous_update: Option<BlockNumberFor<T>>,
+		principal: BalanceType,
+	) {
+		let now = frame_system::Pallet::<T>::block_number();
+		// the link has been recorded with the first change in this block
+		if previous_update != Some(now) {
+			sp_io::offchain_index::set(
+				&balance_history_link_key(&cid, who, &now),
+				&previous_update.encode(),
+			);
+		}
+		sp_io::offchain_index::set(&balance_history_key(&cid, who, &now), &principal.encode());
+		sp_io::offchain_index::set(&balance_history_head_key(&cid, who), &now.encode());
+	}
+
 	/// Create a new account on-chain if it does not exist.
 	fn new_account(who: &T::AccountId) -> DispatchResult {
 		frame_system::Pallet::<T>::inc_sufficients(who);
 		Ok(())
 	}
 
-	/// Remove an account from a community
+	/// Remove an account from a community. Its remaining balance is removed from the total
+	/// issuance.
 	fn remove_account(cid: CommunityIdentifier, who: &T::AccountId) -> DispatchResult {
 		ensure!(Balance::<T>::contains_key(cid, who), Error::<T>::NoAccount);
-		ensure!(
-			Self::balance(cid, who) < T::ExistentialDeposit::get(),
-			Error::<T>::ExistentialDeposit
-		);
-		<Balance<T>>::remove(cid, who);
+		let dust = Self::balance(cid, who);
+		ensure!(dust < Self::existential_deposit(cid), Error::<T>::ExistentialDeposit);
+		let mut entry_tot = Self::total_issuance_entry_updated(cid);
+		entry_tot.principal = entry_tot.principal.saturating_sub(dust);
+		<TotalIssuance<T>>::insert(cid, entry_tot);
+		if Self::is_demurrage_exempt(cid, who) {
+			Self::shift_exempt_issuance(cid, dust, false);
+		}
+		let entry = <Balance<T>>::take(cid, who);
+		Self::record_balance_history(cid, who, Some(entry.last_update), BalanceType::from_num(0));
 		frame_system::Pallet::<T>::dec_sufficients(who);
 		Ok(())
 	}
@@ -278,10 +757,22 @@ impl<T: Config> Pallet<T> {
 		source: &T::AccountId,
 		dest: &T::AccountId,
 		amount: BalanceType,
+	) -> Result<BalanceType, DispatchError> {
+		Self::do_transfer_with_reference(cid, source, dest, amount, None)
+	}
+
+	/// Like `do_transfer`, but additionally deposits `TransferredWithReference` if a `reference`
+	/// is given.
+	pub fn do_transfer_with_reference(
+		cid: CommunityIdentifier,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: BalanceType,
+		reference: Option<PaymentReference>,
 	) -> Result<BalanceType, DispatchError> {
 		// Early exist if no-op.
 		if amount == 0u128 {
-			Self::deposit_event(Event::Transferred(cid, source.clone(), dest.clone(), amount));
+			Self::deposit_transferred_event(cid, source, dest, amount, reference);
 			return Ok(amount);
 		}
 
@@ -290,37 +781,340 @@ impl<T: Config> Pallet<T> {
 		let mut entry_from = Self::balance_entry_updated(cid, source);
 
 		ensure!(entry_from.principal >= amount, Error::<T>::BalanceTooLow);
+		ensure!(
+			entry_from.principal.saturating_sub(amount) >= Self::untouchable_balance(cid, source),
+			Error::<T>::Frozen
+		);
 
 		if source == dest {
-			<Balance<T>>::insert(cid, source, entry_from);
+			Self::insert_balance_entry(cid, source, entry_from);
 			return Ok(amount);
 		}
 
+		Self::credit_transfer(cid, source, dest, amount)?;
+
+		entry_from.principal = entry_from.principal.saturating_sub(amount);
+		Self::insert_balance_entry(cid, source, entry_from);
+
+		Self::deposit_transferred_event(cid, source, dest, amount, reference);
+
+		// remove account if it falls beloe existential deposit, unless it still has funds on hold
+		entry_from = Self::balance_entry_updated(cid, source);
+		if entry_from.principal < Self::existential_deposit(cid) &&
+			!<Holds<T>>::contains_key(cid, source)
+		{
+			Self::remove_account(cid, source)?;
+		}
+
+		Ok(amount)
+	}
+
+	fn deposit_transferred_event(
+		cid: CommunityIdentifier,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: BalanceType,
+		reference: Option<PaymentReference>,
+	) {
+		Self::deposit_event(Event::Transferred(cid, source.clone(), dest.clone(), amount));
+		if let Some(reference) = reference {
+			Self::deposit_event(Event::TransferredWithReference(
+				cid,
+				source.clone(),
+				dest.clone(),
+				amount,
+				reference,
+			));
+		}
+	}
+
+	/// Transfer to several destinations, applying demurrage to `source` only once and
+	/// checking the total amount up front. Either all transfers succeed or none.
+	pub fn do_transfer_many(
+		cid: CommunityIdentifier,
+		source: &T::AccountId,
+		transfers: &[(T::AccountId, BalanceType)],
+	) -> DispatchResult {
+		with_storage_layer(|| {
+			ensure!(Balance::<T>::contains_key(cid, source), Error::<T>::NoAccount);
+
+			// transfers to `source` itself are no-ops, like in `do_transfer`
+			let total = transfers
+				.iter()
+				.filter(|(dest, _)| dest != source)
+				.try_fold(BalanceType::from_num(0), |total, (_, amount)| total.checked_add(*amount))
+				.ok_or(ArithmeticError::Overflow)?;
+
+			let mut entry_from = Self::balance_entry_updated(cid, source);
+
+			ensure!(entry_from.principal >= total, Error::<T>::BalanceTooLow);
+			ensure!(
+				entry_from.principal.saturating_sub(total) >=
+					Self::untouchable_balance(cid, source),
+				Error::<T>::Frozen
+			);
+
+			for (dest, amount) in transfers {
+				if dest == source {
+					continue;
+				}
+				if *amount > 0u128 {
+					Self::credit_transfer(cid, source, dest, *amount)?;
+					entry_from.principal = entry_from.principal.saturating_sub(*amount);
+				}
+				Self::deposit_event(Event::Transferred(cid, source.clone(), dest.clone(), *amount));
+			}
+
+			Self::insert_balance_entry(cid, source, entry_from);
+
+			// remove account if it falls below existential deposit, unless it still has funds on
+			// hold
+			if entry_from.principal < Self::existential_deposit(cid) &&
+				!<Holds<T>>::contains_key(cid, source)
+			{
+				Self::remove_account(cid, source)?;
+			}
+			Ok(())
+		})
+	}
+
+	/// Credit `amount` to `dest` as part of a transfer from `source`, endowing `dest` if needed.
+	/// Debiting `source` is left to the caller.
+	fn credit_transfer(
+		cid: CommunityIdentifier,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: BalanceType,
+	) -> DispatchResult {
 		if !Balance::<T>::contains_key(cid, dest) {
-			ensure!(amount > T::ExistentialDeposit::get(), Error::<T>::ExistentialDeposit);
+			ensure!(amount > Self::existential_deposit(cid), Error::<T>::ExistentialDeposit);
 			Self::new_account(dest)?;
 			Self::deposit_event(Event::Endowed { cid, who: dest.clone(), balance: amount });
 		}
 
 		let mut entry_to = Self::balance_entry_updated(cid, dest);
-
-		entry_from.principal = entry_from.principal.saturating_sub(amount);
 		entry_to.principal = entry_to.principal.saturating_add(amount);
+		Self::insert_balance_entry(cid, dest, entry_to);
 
-		<Balance<T>>::insert(cid, source, entry_from);
-		<Balance<T>>::insert(cid, dest, entry_to);
+		match (Self::is_demurrage_exempt(cid, source), Self::is_demurrage_exempt(cid, dest)) {
+			(true, false) => Self::shift_exempt_issuance(cid, amount, false),
+			(false, true) => Self::shift_exempt_issuance(cid, amount, true),
+			_ => (),
+		}
+		Ok(())
+	}
 
-		Self::deposit_event(Event::Transferred(cid, source.clone(), dest.clone(), amount));
+	/// Returns the amount `spender` may still transfer from the balance of `owner` in `cid`.
+	pub fn allowance(
+		cid: CommunityIdentifier,
+		owner: &T::AccountId,
+		spender: &T::AccountId,
+	) -> BalanceType {
+		match <Approvals<T>>::get(cid, (owner, spender)) {
+			Some(allowance)
+				if allowance
+					.expiry
+					.is_none_or(|expiry| expiry >= frame_system::Pallet::<T>::block_number()) =>
+				Self::apply_demurrage(allowance.amount, Self::account_demurrage(&cid, owner))
+					.principal,
+			_ => BalanceType::from_num(0),
+		}
+	}
 
-		// remove account if it falls beloe existential deposit
-		entry_from = Self::balance_entry_updated(cid, source);
-		if entry_from.principal < T::ExistentialDeposit::get() {
-			Self::remove_account(cid, source)?;
+	pub fn do_approve(
+		cid: CommunityIdentifier,
+		owner: &T::AccountId,
+		spender: &T::AccountId,
+		amount: BalanceType,
+		expiry: Option<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		if amount == 0u128 {
+			Self::remove_approval(cid, owner, spender);
+		} else {
+			let deposit = match <Approvals<T>>::get(cid, (owner, spender)) {
+				Some(allowance) => allowance.deposit,
+				None => {
+					let deposit = T::ApprovalDeposit::get();
+					T::DepositCurrency::reserve(owner, deposit)?;
+					deposit
+				},
+			};
+			let current_block = frame_system::Pallet::<T>::block_number();
+			<Approvals<T>>::insert(
+				cid,
+				(owner, spender),
+				Allowance { amount: BalanceEntry::new(amount, current_block), expiry, deposit },
+			);
+		}
+		Self::deposit_event(Event::Approved {
+			cid,
+			owner: owner.clone(),
+			spender: spender.clone(),
+			amount,
+			expiry,
+		});
+		Ok(())
+	}
+
+	/// Removes the allowance `owner` has granted `spender` and returns the owner's deposit.
+	fn remove_approval(cid: CommunityIdentifier, owner: &T::AccountId, spender: &T::AccountId) {
+		if let Some(allowance) = <Approvals<T>>::take(cid, (owner, spender)) {
+			T::DepositCurrency::unreserve(owner, allowance.deposit);
+		}
+	}
+
+	pub fn do_transfer_from(
+		cid: CommunityIdentifier,
+		owner: &T::AccountId,
+		spender: &T::AccountId,
+		dest: &T::AccountId,
+		amount: BalanceType,
+	) -> Result<BalanceType, DispatchError> {
+		let allowance =
+			<Approvals<T>>::get(cid, (owner, spender)).ok_or(Error::<T>::AllowanceExceeded)?;
+		if let Some(expiry) = allowance.expiry {
+			ensure!(
+				expiry >= frame_system::Pallet::<T>::block_number(),
+				Error::<T>::AllowanceExpired
+			);
 		}
+		let mut remaining =
+			Self::apply_demurrage(allowance.amount, Self::account_demurrage(&cid, owner));
+		remaining.principal =
+			remaining.principal.checked_sub(amount).ok_or(Error::<T>::AllowanceExceeded)?;
 
+		Self::do_transfer(cid, owner, dest, amount)?;
+
+		if remaining.principal == 0u128 {
+			Self::remove_approval(cid, owner, spender);
+		} else {
+			<Approvals<T>>::insert(
+				cid,
+				(owner, spender),
+				Allowance { amount: remaining, ..allowance },
+			);
+		}
 		Ok(amount)
 	}
 
+	/// Returns when a standing order with `period` is due next, counting from block `from`.
+	fn next_standing_order_due(
+		period: StandingOrderPeriod<BlockNumberFor<T>>,
+		from: BlockNumberFor<T>,
+	) -> StandingOrderDue<BlockNumberFor<T>> {
+		match period {
+			StandingOrderPeriod::Blocks(blocks) =>
+				StandingOrderDue::Block(from.saturating_add(blocks)),
+			StandingOrderPeriod::CeremonyCycle =>
+				StandingOrderDue::Ceremony(T::CurrentCeremonyIndex::get().saturating_add(1)),
+		}
+	}
+
+	/// (Re-)inserts standing order `id` into `StandingOrderQueue` at the position of
+	/// `maybe_due`, or removes it if `None`.
+	fn index_standing_order(
+		id: StandingOrderIdType,
+		maybe_due: Option<StandingOrderDue<BlockNumberFor<T>>>,
+	) -> Result<(), Error<T>> {
+		<StandingOrderQueue<T>>::try_mutate(|queue| {
+			queue.retain(|(_, queued)| *queued != id);
+			if let Some(due) = maybe_due {
+				let index = queue.partition_point(|(at, _)| *at <= due);
+				queue
+					.try_insert(index, (due, id))
+					.map_err(|_| Error::<T>::TooManyStandingOrders)?;
+			}
+			Ok(())
+		})
+	}
+
+	fn remove_standing_order(
+		id: StandingOrderIdType,
+		order: &StandingOrder<T::AccountId, BlockNumberFor<T>, DepositBalanceOf<T>>,
+	) {
+		<StandingOrders<T>>::remove(id);
+		T::DepositCurrency::unreserve(&order.payer, order.deposit);
+		<StandingOrdersOf<T>>::mutate_exists(&order.payer, |maybe_ids| {
+			if let Some(ids) = maybe_ids {
+				ids.retain(|queued| *queued != id);
+				if ids.is_empty() {
+					*maybe_ids = None;
+				}
+			}
+		});
+		let _ = Self::index_standing_order(id, None);
+	}
+
+	/// Executes due standing orders as long as `remaining_weight` allows.
+	///
+	/// Returns the consumed weight.
+	pub fn execute_due_standing_orders(mut remaining_weight: Weight) -> Weight {
+		let mut consumed = T::DbWeight::get().reads(1);
+		remaining_weight = remaining_weight.saturating_sub(consumed);
+		let now = frame_system::Pallet::<T>::block_number();
+		let cindex = T::CurrentCeremonyIndex::get();
+
+		loop {
+			let queue = Self::standing_order_queue();
+			// executing an order decodes and re-encodes the whole queue.
+			let step_weight = <T as Config>::WeightInfo::execute_standing_order(queue.len() as u32);
+			if remaining_weight.any_lt(step_weight) {
+				break;
+			}
+			// block orders sort before ceremony orders, so due ones of either kind come first.
+			let Some((due, id)) = queue.into_iter().find(|(due, _)| match due {
+				StandingOrderDue::Block(block) => *block <= now,
+				StandingOrderDue::Ceremony(at_cindex) => *at_cindex <= cindex,
+			}) else {
+				break;
+			};
+			Self::execute_standing_order(id, due);
+
+			consumed.saturating_accrue(step_weight);
+			remaining_weight.saturating_reduce(step_weight);
+		}
+		consumed
+	}
+
+	/// Makes the payment of standing order `id`, which was due at `due`, and schedules the next
+	/// one. Drops the order if the payment fails.
+	pub(crate) fn execute_standing_order(
+		id: StandingOrderIdType,
+		due: StandingOrderDue<BlockNumberFor<T>>,
+	) {
+		let Some(order) = Self::standing_orders(id) else {
+			let _ = Self::index_standing_order(id, None);
+			return;
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+		if order.end.is_some_and(|end| end < now) {
+			Self::remove_standing_order(id, &order);
+			Self::deposit_event(Event::StandingOrderCompleted { id });
+			return;
+		}
+
+		match with_storage_layer(|| {
+			Self::do_transfer(order.cid, &order.payer, &order.recipient, order.amount)
+		}) {
+			Ok(_) => {
+				let from = match due {
+					StandingOrderDue::Block(block) => block,
+					StandingOrderDue::Ceremony(_) => now,
+				};
+				let _ = Self::index_standing_order(
+					id,
+					Some(Self::next_standing_order_due(order.period, from)),
+				);
+				Self::deposit_event(Event::StandingOrderExecuted { id });
+			},
+			Err(error) => {
+				info!(target: LOG, "dropping standing order {id}: {error:?}");
+				Self::remove_standing_order(id, &order);
+				Self::deposit_event(Event::StandingOrderDropped { id, error });
+			},
+		}
+	}
+
 	pub fn issue(
 		community_id: CommunityIdentifier,
 		who: &T::AccountId,
@@ -338,7 +1132,10 @@ impl<T: Config> Pallet<T> {
 		entry_who.principal += amount;
 		entry_tot.principal += amount;
 		<TotalIssuance<T>>::insert(community_id, entry_tot);
-		<Balance<T>>::insert(community_id, who, entry_who);
+		Self::insert_balance_entry(community_id, who, entry_who);
+		if Self::is_demurrage_exempt(community_id, who) {
+			Self::shift_exempt_issuance(community_id, amount, true);
+		}
 
 		Self::deposit_event(Event::Issued(community_id, who.clone(), amount));
 		debug!(target: LOG, "issue {amount:?} for {who:?}");
@@ -358,21 +1155,109 @@ impl<T: Config> Pallet<T> {
 		} else {
 			return Err(Error::<T>::BalanceTooLow.into());
 		};
+		ensure!(
+			entry_who.principal >= Self::untouchable_balance(community_id, who),
+			Error::<T>::Frozen
+		);
 		entry_tot.principal = entry_tot.principal.saturating_sub(amount);
 		//FIXME: delete account if it falls below existential deposit
 
 		<TotalIssuance<T>>::insert(community_id, entry_tot);
-		<Balance<T>>::insert(community_id, who, entry_who);
+		Self::insert_balance_entry(community_id, who, entry_who);
+		if Self::is_demurrage_exempt(community_id, who) {
+			Self::shift_exempt_issuance(community_id, amount, false);
+		}
 		Self::deposit_event(Event::Burned(community_id, who.clone(), amount));
 		Ok(())
 	}
 
 	/// Returns the community-specific demurrage if it is set. Otherwise returns the
 	/// the demurrage defined in the genesis config
-	fn demurrage(cid: &CommunityIdentifier) -> Demurrage {
+	pub fn demurrage(cid: &CommunityIdentifier) -> Demurrage {
 		<DemurragePerBlock<T>>::try_get(cid).unwrap_or_else(|_| T::DefaultDemurrage::get())
 	}
 
+	/// Returns the community-specific existential deposit if it is set. Otherwise returns the
+	/// default defined in the config.
+	pub fn existential_deposit(cid: CommunityIdentifier) -> BalanceType {
+		<ExistentialDeposits<T>>::get(cid).unwrap_or_else(T::ExistentialDeposit::get)
+	}
+
+	/// Returns the demurrage applying to the balances of `who` in `cid`.
+	fn account_demurrage(cid: &CommunityIdentifier, who: &T::AccountId) -> Demurrage {
+		if Self::is_demurrage_exempt(*cid, who) {
+			Demurrage::from_num(0)
+		} else {
+			Self::demurrage(cid)
+		}
+	}
+
+	pub fn is_demurrage_exempt(cid: CommunityIdentifier, who: &T::AccountId) -> bool {
+		<DemurrageExempt<T>>::contains_key(cid, who)
+	}
+
+	/// Exempts the free and held balances of `who` in `cid` from demurrage, or subjects them to
+	/// demurrage again. The demurrage accrued so far is settled first.
+	pub fn set_demurrage_exempt(cid: CommunityIdentifier, who: &T::AccountId, exempt: bool) {
+		if Self::is_demurrage_exempt(cid, who) == exempt {
+			return;
+		}
+
+		let mut amount = BalanceType::from_num(0);
+		if <Balance<T>>::contains_key(cid, who) {
+			let entry = Self::balance_entry_updated(cid, who);
+			amount = amount.saturating_add(entry.principal);
+			Self::insert_balance_entry(cid, who, entry);
+		}
+		let demurrage = Self::account_demurrage(&cid, who);
+		<Holds<T>>::mutate_exists(cid, who, |maybe_holds| {
+			for hold in maybe_holds.iter_mut().flat_map(|holds| holds.iter_mut()) {
+				hold.amount = Self::apply_demurrage(hold.amount, demurrage);
+				amount = amount.saturating_add(hold.amount.principal);
+			}
+		});
+
+		if exempt {
+			<DemurrageExempt<T>>::insert(cid, who, ());
+			Self::shift_exempt_issuance(cid, amount, true);
+		} else {
+			<DemurrageExempt<T>>::remove(cid, who);
+			Self::shift_exempt_issuance(cid, amount, false);
+		}
+		info!(target: LOG, "set demurrage exemption of {who:?} in {cid:?} to {exempt}");
+	}
+
+	/// Moves `amount` of the total issuance of `cid` from the part which is subject to demurrage
+	/// to the exempt part, or back if `into_exempt` is false.
+	pub(crate) fn shift_exempt_issuance(
+		cid: CommunityIdentifier,
+		amount: BalanceType,
+		into_exempt: bool,
+	) {
+		let mut entry_tot = Self::total_issuance_entry_updated(cid);
+		if into_exempt {
+			entry_tot.principal = entry_tot.principal.saturating_sub(amount);
+			<ExemptIssuance<T>>::mutate(cid, |exempt| *exempt = exempt.saturating_add(amount));
+		} else {
+			entry_tot.principal = entry_tot.principal.saturating_add(amount);
+			<ExemptIssuance<T>>::mutate(cid, |exempt| *exempt = exempt.saturating_sub(amount));
+		}
+		<TotalIssuance<T>>::insert(cid, entry_tot);
+	}
+
+	/// Accounts for an exempt balance of `cid` changing from `old` to `new`.
+	pub(crate) fn shift_exempt_issuance_by_change(
+		cid: CommunityIdentifier,
+		old: BalanceType,
+		new: BalanceType,
+	) {
+		if new >= old {
+			Self::shift_exempt_issuance(cid, new - old, true);
+		} else {
+			Self::shift_exempt_issuance(cid, old - new, false);
+		}
+	}
+
 	pub fn set_demurrage(
 		cid: &CommunityIdentifier,
 		demurrage: Demurrage,
@@ -382,10 +1267,31 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub fn set_existential_deposit(cid: CommunityIdentifier, existential_deposit: BalanceType) {
+		<ExistentialDeposits<T>>::insert(cid, existential_deposit);
+	}
+
 	pub fn purge_balances(cid: CommunityIdentifier) {
+		for (who, entry) in <Balance<T>>::drain_prefix(cid) {
+			Self::record_balance_history(
+				cid,
+				&who,
+				Some(entry.last_update),
+				BalanceType::from_num(0),
+			);
+		}
+		#[allow(deprecated)]
+		<Holds<T>>::remove_prefix(cid, None);
 		#[allow(deprecated)]
-		<Balance<T>>::remove_prefix(cid, None);
+		<Freezes<T>>::remove_prefix(cid, None);
+		#[allow(deprecated)]
+		<DemurrageExempt<T>>::remove_prefix(cid, None);
+		for ((owner, _), allowance) in <Approvals<T>>::drain_prefix(cid) {
+			T::DepositCurrency::unreserve(&owner, allowance.deposit);
+		}
 		<TotalIssuance<T>>::remove(cid);
+		<ExemptIssuance<T>>::remove(cid);
 		<DemurragePerBlock<T>>::remove(cid);
+		<ExistentialDeposits<T>>::remove(cid);
 	}
 }
@@ -18,6 +18,8 @@
 
 use crate as dut;
 use encointer_primitives::balances::{BalanceType, Demurrage};
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use sp_runtime::BuildStorage;
 use test_utils::*;
 
@@ -40,10 +42,39 @@ frame_support::construct_runtime!(
 	}
 );
 
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum TestId {
+	Foo,
+	Bar,
+	Baz,
+}
+
 impl dut::Config for TestRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type DefaultDemurrage = DefaultDemurrage;
 	type ExistentialDeposit = ExistentialDeposit;
+	type RuntimeHoldReason = TestId;
+	type RuntimeFreezeReason = TestId;
+	type MaxHolds = frame_support::traits::ConstU32<2>;
+	type MaxFreezes = frame_support::traits::ConstU32<2>;
+	type DepositCurrency = Balances;
+	type ApprovalDeposit = frame_support::traits::ConstU128<10>;
+	type StandingOrderDeposit = frame_support::traits::ConstU128<10>;
+	type CurrentCeremonyIndex = pallet_encointer_scheduler::CurrentCeremonyIndexGetter<TestRuntime>;
+	type MaxStandingOrders = frame_support::traits::ConstU32<4>;
+	type MaxStandingOrdersPerPayer = frame_support::traits::ConstU32<2>;
+	type MaxTransfersPerBatch = frame_support::traits::ConstU32<10>;
 	type WeightInfo = ();
 	type CeremonyMaster = EnsureAlice;
 }
@@ -17,22 +17,30 @@
 //! Unit tests for the encointer_balances module.
 
 use super::{Balance as EncointerBalanceStorage, *};
-use crate::mock::{Balances, DefaultDemurrage};
+use crate::mock::{Balances, DefaultDemurrage, ExistentialDeposit};
 use approx::{assert_abs_diff_eq, assert_relative_eq};
 use encointer_primitives::{
-	balances::to_U64F64,
+	balances::{
+		balance_history_head_key, balance_history_key, balance_history_link_key, to_U64F64,
+	},
 	communities::CommunityIdentifier,
 	fixed::{traits::LossyInto, transcendental::exp},
+	storage::current_ceremony_index_key,
 };
 use frame_support::{
 	assert_err, assert_noop, assert_ok,
-	traits::{tokens::fungibles::Unbalanced, Currency, OnInitialize},
+	traits::{tokens::fungibles::Unbalanced, Currency, OnIdle, OnInitialize},
+	weights::{RuntimeDbWeight, Weight},
+	BoundedVec,
 };
-use mock::{master, new_test_ext, EncointerBalances, RuntimeOrigin, System, TestRuntime};
+use mock::{
+	master, new_test_ext, EncointerBalances, EncointerScheduler, RuntimeOrigin, System, TestRuntime,
+};
+use parity_scale_codec::{Decode, Encode};
 use sp_runtime::{app_crypto::Pair, testing::sr25519, AccountId32, DispatchError};
 use sp_std::str::FromStr;
 use test_utils::{
-	helpers::{almost_eq, assert_dispatch_err, events, last_event},
+	helpers::{almost_eq, assert_dispatch_err, event_deposited, events, last_event},
 	AccountKeyring,
 };
 
@@ -162,6 +170,47 @@ fn transfer_should_create_new_account() {
 	});
 }
 
+#[test]
+fn transfer_with_reference_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1);
+		System::on_initialize(System::block_number());
+
+		let cid = CommunityIdentifier::default();
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let reference = PaymentReference::truncate_from(b"invoice-42".to_vec());
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50u128)));
+
+		assert_ok!(EncointerBalances::transfer_with_reference(
+			Some(alice.clone()).into(),
+			bob.clone(),
+			cid,
+			BalanceType::from_num(10),
+			reference.clone()
+		));
+
+		let balance: f64 = EncointerBalances::balance(cid, &bob).lossy_into();
+		assert_relative_eq!(balance, 10.0, epsilon = 1.0e-9);
+		assert!(event_deposited::<TestRuntime>(
+			Event::Transferred(cid, alice.clone(), bob.clone(), BalanceType::from_num(10)).into()
+		));
+		assert_eq!(
+			last_event::<TestRuntime>(),
+			Some(
+				Event::TransferredWithReference(
+					cid,
+					alice,
+					bob,
+					BalanceType::from_num(10),
+					reference
+				)
+				.into()
+			)
+		);
+	});
+}
+
 #[test]
 fn transfer_does_not_create_new_account_if_below_ed() {
 	new_test_ext().execute_with(|| {
@@ -184,6 +233,116 @@ fn transfer_does_not_create_new_account_if_below_ed() {
 	});
 }
 
+#[test]
+fn transfer_many_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1);
+		System::on_initialize(System::block_number());
+
+		let cid = CommunityIdentifier::default();
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let charlie = AccountKeyring::Charlie.to_account_id();
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50u128)));
+		assert_ok!(EncointerBalances::issue(cid, &bob, BalanceType::from_num(1u128)));
+
+		assert_ok!(EncointerBalances::transfer_many(
+			Some(alice.clone()).into(),
+			cid,
+			BoundedVec::truncate_from(vec![
+				(bob.clone(), BalanceType::from_num(10)),
+				(charlie.clone(), BalanceType::from_num(20)),
+			])
+		));
+
+		let balance: f64 = EncointerBalances::balance(cid, &alice).lossy_into();
+		assert_relative_eq!(balance, 20.0, epsilon = 1.0e-9);
+		let balance: f64 = EncointerBalances::balance(cid, &bob).lossy_into();
+		assert_relative_eq!(balance, 11.0, epsilon = 1.0e-9);
+		let balance: f64 = EncointerBalances::balance(cid, &charlie).lossy_into();
+		assert_relative_eq!(balance, 20.0, epsilon = 1.0e-9);
+
+		assert!(event_deposited::<TestRuntime>(
+			Event::Endowed { cid, who: charlie.clone(), balance: BalanceType::from_num(20) }.into()
+		));
+		assert_eq!(
+			last_event::<TestRuntime>(),
+			Some(Event::Transferred(cid, alice, charlie, BalanceType::from_num(20)).into())
+		);
+	});
+}
+
+#[test]
+fn transfer_many_ignores_transfers_to_the_sender() {
+	new_test_ext().execute_with(|| {
+		let cid = CommunityIdentifier::default();
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10u128)));
+
+		assert_ok!(EncointerBalances::transfer_many(
+			Some(alice.clone()).into(),
+			cid,
+			BoundedVec::truncate_from(vec![
+				(alice.clone(), BalanceType::from_num(100)),
+				(bob.clone(), BalanceType::from_num(5)),
+			])
+		));
+
+		let balance: f64 = EncointerBalances::balance(cid, &alice).lossy_into();
+		assert_relative_eq!(balance, 5.0, epsilon = 1.0e-9);
+		let balance: f64 = EncointerBalances::balance(cid, &bob).lossy_into();
+		assert_relative_eq!(balance, 5.0, epsilon = 1.0e-9);
+	});
+}
+
+#[test]
+fn transfer_many_fails_if_total_exceeds_balance() {
+	new_test_ext().execute_with(|| {
+		let cid = CommunityIdentifier::default();
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let charlie = AccountKeyring::Charlie.to_account_id();
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50u128)));
+
+		assert_noop!(
+			EncointerBalances::transfer_many(
+				Some(alice).into(),
+				cid,
+				BoundedVec::truncate_from(vec![
+					(bob, BalanceType::from_num(30)),
+					(charlie, BalanceType::from_num(30)),
+				])
+			),
+			Error::<TestRuntime>::BalanceTooLow,
+		);
+	});
+}
+
+#[test]
+fn transfer_many_is_atomic_if_a_destination_is_below_ed() {
+	new_test_ext().execute_with(|| {
+		let cid = CommunityIdentifier::default();
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let charlie = AccountKeyring::Charlie.to_account_id();
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50u128)));
+
+		assert_noop!(
+			EncointerBalances::transfer_many(
+				Some(alice).into(),
+				cid,
+				BoundedVec::truncate_from(vec![
+					(bob.clone(), BalanceType::from_num(10)),
+					(charlie, BalanceType::from_num(0.0000000001)),
+				])
+			),
+			Error::<TestRuntime>::ExistentialDeposit,
+		);
+		assert!(!EncointerBalanceStorage::<TestRuntime>::contains_key(cid, &bob));
+	});
+}
+
 #[test]
 fn if_account_does_not_exist_in_community_transfer_errs_with_no_account_error() {
 	new_test_ext().execute_with(|| {
@@ -226,6 +385,127 @@ fn demurrage_should_work() {
 	});
 }
 
+#[test]
+fn balance_at_block_and_total_issuance_at_project_demurrage() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountKeyring::Alice.to_account_id();
+		let cid = CommunityIdentifier::from_str("aaaaaaaaaa").unwrap();
+		System::set_block_number(10);
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(1)));
+
+		//one year later
+		let result: f64 = EncointerBalances::balance_at_block(cid, &alice, 10 + 86400 / 5 * 356)
+			.unwrap()
+			.lossy_into();
+		assert_abs_diff_eq!(result, 0.5, epsilon = 1.0e-12);
+		let result: f64 = EncointerBalances::total_issuance_at(cid, 10 + 86400 / 5 * 356)
+			.unwrap()
+			.lossy_into();
+		assert_abs_diff_eq!(result, 0.5, epsilon = 1.0e-12);
+
+		// blocks before the last update can not be projected
+		assert_eq!(EncointerBalances::balance_at_block(cid, &alice, 9), None);
+		assert_eq!(EncointerBalances::total_issuance_at(cid, 9), None);
+		assert_eq!(
+			EncointerBalances::balance_at_block(cid, &alice, 10),
+			Some(BalanceType::from_num(1))
+		);
+		// projecting does not change state
+		assert_eq!(EncointerBalances::balance(cid, &alice), BalanceType::from_num(1));
+	});
+}
+
+#[test]
+fn balance_changes_are_offchain_indexed() {
+	let alice = AccountKeyring::Alice.to_account_id();
+	let bob = AccountKeyring::Bob.to_account_id();
+	let cid = CommunityIdentifier::default();
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
+		System::set_block_number(3);
+		assert_ok!(EncointerBalances::transfer(
+			Some(alice.clone()).into(),
+			bob.clone(),
+			cid,
+			BalanceType::from_num(10)
+		));
+		assert_ok!(EncointerBalances::transfer(
+			Some(alice.clone()).into(),
+			bob.clone(),
+			cid,
+			BalanceType::from_num(10)
+		));
+	});
+	ext.persist_offchain_overlay();
+	let db = ext.offchain_db();
+	let get = |key: Vec<u8>| db.get(&key);
+
+	assert_eq!(get(balance_history_head_key(&cid, &alice)), Some(3u64.encode()));
+	let principal: f64 =
+		BalanceType::decode(&mut get(balance_history_key(&cid, &alice, &3u64)).unwrap().as_slice())
+			.unwrap()
+			.lossy_into();
+	assert_abs_diff_eq!(principal, 30.0, epsilon = 1.0e-3);
+	// the second transfer in block 3 must not overwrite the link to block 1
+	assert_eq!(get(balance_history_link_key(&cid, &alice, &3u64)), Some(Some(1u64).encode()));
+	assert_eq!(
+		get(balance_history_key(&cid, &alice, &1u64)),
+		Some(BalanceType::from_num(50).encode())
+	);
+	assert_eq!(get(balance_history_link_key(&cid, &alice, &1u64)), Some(None::<u64>.encode()));
+
+	assert_eq!(get(balance_history_head_key(&cid, &bob)), Some(3u64.encode()));
+	assert_eq!(
+		get(balance_history_key(&cid, &bob, &3u64)),
+		Some(BalanceType::from_num(20).encode())
+	);
+	assert_eq!(get(balance_history_link_key(&cid, &bob, &3u64)), Some(None::<u64>.encode()));
+}
+
+#[test]
+fn removed_accounts_are_offchain_indexed_with_zero_principal() {
+	let alice = AccountKeyring::Alice.to_account_id();
+	let bob = AccountKeyring::Bob.to_account_id();
+	let cid = CommunityIdentifier::default();
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(1)));
+		assert_ok!(EncointerBalances::issue(cid, &bob, BalanceType::from_num(10)));
+		EncointerBalances::set_existential_deposit(cid, BalanceType::from_num(2));
+		System::set_block_number(3);
+		assert_ok!(EncointerBalances::transfer(
+			Some(alice.clone()).into(),
+			bob.clone(),
+			cid,
+			BalanceType::from_num(0.5)
+		));
+		System::set_block_number(5);
+		EncointerBalances::purge_balances(cid);
+	});
+	ext.persist_offchain_overlay();
+	let db = ext.offchain_db();
+	let get = |key: Vec<u8>| db.get(&key);
+
+	// removed on transfer
+	assert_eq!(get(balance_history_head_key(&cid, &alice)), Some(3u64.encode()));
+	assert_eq!(
+		get(balance_history_key(&cid, &alice, &3u64)),
+		Some(BalanceType::from_num(0).encode())
+	);
+	assert_eq!(get(balance_history_link_key(&cid, &alice, &3u64)), Some(Some(1u64).encode()));
+
+	// removed on purge
+	assert_eq!(get(balance_history_head_key(&cid, &bob)), Some(5u64.encode()));
+	assert_eq!(
+		get(balance_history_key(&cid, &bob, &5u64)),
+		Some(BalanceType::from_num(0).encode())
+	);
+	assert_eq!(get(balance_history_link_key(&cid, &bob, &5u64)), Some(Some(3u64).encode()));
+}
+
 #[test]
 fn transfer_with_demurrage_exceeding_amount_should_fail() {
 	let alice = AccountKeyring::Alice.to_account_id();
@@ -378,6 +658,75 @@ fn transfer_removes_account_if_source_below_existential_deposit() {
 	})
 }
 
+#[test]
+fn raising_existential_deposit_removes_dust_of_small_accounts_from_total_issuance() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let charlie = AccountKeyring::Charlie.to_account_id();
+		let cid = CommunityIdentifier::default();
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(1)));
+		assert_ok!(EncointerBalances::issue(cid, &bob, BalanceType::from_num(10)));
+		EncointerBalances::set_demurrage_exempt(cid, &charlie, true);
+		assert_ok!(EncointerBalances::issue(cid, &charlie, BalanceType::from_num(1)));
+
+		EncointerBalances::set_existential_deposit(cid, BalanceType::from_num(2));
+		// small accounts are kept until they transfer
+		assert_eq!(EncointerBalances::balance(cid, &alice), BalanceType::from_num(1));
+
+		assert_ok!(EncointerBalances::transfer(
+			Some(alice.clone()).into(),
+			bob.clone(),
+			cid,
+			BalanceType::from_num(0.5)
+		));
+		assert_ok!(EncointerBalances::transfer(
+			Some(charlie.clone()).into(),
+			bob.clone(),
+			cid,
+			BalanceType::from_num(0.5)
+		));
+		assert!(!EncointerBalanceStorage::<TestRuntime>::contains_key(cid, &alice));
+		assert!(!EncointerBalanceStorage::<TestRuntime>::contains_key(cid, &charlie));
+		assert_eq!(EncointerBalances::balance(cid, &bob), BalanceType::from_num(11));
+		assert_eq!(EncointerBalances::total_issuance(cid), BalanceType::from_num(11));
+		assert_eq!(EncointerBalances::exempt_issuance(cid), BalanceType::from_num(0));
+	})
+}
+
+#[test]
+fn community_existential_deposit_applies_to_transfers() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let cid = CommunityIdentifier::default();
+		assert_eq!(EncointerBalances::existential_deposit(cid), ExistentialDeposit::get());
+		EncointerBalances::set_existential_deposit(cid, BalanceType::from_num(1));
+		assert_eq!(EncointerBalances::existential_deposit(cid), BalanceType::from_num(1));
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
+
+		assert_noop!(
+			EncointerBalances::transfer(
+				Some(alice.clone()).into(),
+				bob.clone(),
+				cid,
+				BalanceType::from_num(0.5)
+			),
+			Error::<TestRuntime>::ExistentialDeposit,
+		);
+		assert_ok!(EncointerBalances::transfer(
+			Some(alice.clone()).into(),
+			bob,
+			cid,
+			BalanceType::from_num(9.5)
+		));
+		assert!(!EncointerBalanceStorage::<TestRuntime>::contains_key(cid, alice));
+
+		EncointerBalances::purge_balances(cid);
+		assert_eq!(EncointerBalances::existential_deposit(cid), ExistentialDeposit::get());
+	})
+}
+
 #[test]
 fn transfer_all_native_wont_remove_account_with_remaining_community_balance() {
 	new_test_ext().execute_with(|| {
@@ -436,180 +785,1583 @@ fn transfer_all_native_wont_remove_account_with_remaining_community_balance() {
 	})
 }
 
-mod impl_fungibles {
-	use super::*;
-	use crate::impl_fungibles::{
-		fungible, DepositConsequence, Fortitude, Preservation, Provenance, WithdrawConsequence,
-	};
-	use fungibles::Inspect;
-
-	type AccountId = <TestRuntime as frame_system::Config>::AccountId;
+#[test]
+fn demurrage_exempt_account_keeps_its_principal() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let cid = CommunityIdentifier::from_str("aaaaaaaaaa").unwrap();
+		System::set_block_number(0);
+		EncointerBalances::set_demurrage_exempt(cid, &alice, true);
+		assert!(EncointerBalances::is_demurrage_exempt(cid, &alice));
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(1)));
+		assert_ok!(EncointerBalances::issue(cid, &bob, BalanceType::from_num(1)));
+		assert_eq!(EncointerBalances::exempt_issuance(cid), BalanceType::from_num(1));
 
-	#[test]
-	fn total_issuance_and_balance_works() {
-		new_test_ext().execute_with(|| {
-			let cid = CommunityIdentifier::default();
-			let alice = AccountKeyring::Alice.to_account_id();
-			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50.1)));
-			assert!(almost_eq(
-				<EncointerBalances as Inspect<AccountId>>::balance(cid, &alice),
-				50_100_000_000_000_000_000u128,
-				10000
-			));
+		//one year later
+		System::set_block_number(86400 / 5 * 356);
+		assert_eq!(EncointerBalances::balance(cid, &alice), BalanceType::from_num(1));
+		let result: f64 = EncointerBalances::balance(cid, &bob).lossy_into();
+		assert_abs_diff_eq!(result, 0.5, epsilon = 1.0e-12);
+		let result: f64 = EncointerBalances::total_issuance(cid).lossy_into();
+		assert_abs_diff_eq!(result, 1.5, epsilon = 1.0e-12);
 
-			assert!(almost_eq(
-				<EncointerBalances as Inspect<AccountId>>::reducible_balance(
-					cid,
-					&alice,
-					Preservation::Expendable,
-					Fortitude::Force
-				),
-				50_100_000_000_000_000_000u128,
-				10000
-			));
+		assert_ok!(EncointerBalances::burn(cid, &alice, BalanceType::from_num(0.5)));
+		assert_eq!(EncointerBalances::exempt_issuance(cid), BalanceType::from_num(0.5));
+		let result: f64 = EncointerBalances::total_issuance(cid).lossy_into();
+		assert_abs_diff_eq!(result, 1.0, epsilon = 1.0e-12);
+	});
+}
 
-			assert!(almost_eq(
-				<EncointerBalances as Inspect<AccountId>>::total_issuance(cid),
-				50_100_000_000_000_000_000u128,
-				10000
-			));
-		})
-	}
+#[test]
+fn setting_demurrage_exemption_settles_accrued_demurrage() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountKeyring::Alice.to_account_id();
+		let cid = CommunityIdentifier::from_str("aaaaaaaaaa").unwrap();
+		System::set_block_number(0);
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(1)));
 
-	#[test]
-	fn minimum_balance_works() {
-		new_test_ext().execute_with(|| {
-			let cid = CommunityIdentifier::default();
-			assert_eq!(Pallet::<TestRuntime>::minimum_balance(cid), 0);
-		})
-	}
+		System::set_block_number(86400 / 5 * 356);
+		EncointerBalances::set_demurrage_exempt(cid, &alice, true);
+		let result: f64 = EncointerBalances::exempt_issuance(cid).lossy_into();
+		assert_abs_diff_eq!(result, 0.5, epsilon = 1.0e-12);
+
+		// no demurrage while exempt
+		System::set_block_number(2 * 86400 / 5 * 356);
+		let result: f64 = EncointerBalances::balance(cid, &alice).lossy_into();
+		assert_abs_diff_eq!(result, 0.5, epsilon = 1.0e-12);
+
+		// demurrage applies again from the moment the exemption is lifted
+		EncointerBalances::set_demurrage_exempt(cid, &alice, false);
+		assert!(!EncointerBalances::is_demurrage_exempt(cid, &alice));
+		assert_eq!(EncointerBalances::exempt_issuance(cid), BalanceType::from_num(0));
+		System::set_block_number(3 * 86400 / 5 * 356);
+		let result: f64 = EncointerBalances::balance(cid, &alice).lossy_into();
+		assert_abs_diff_eq!(result, 0.25, epsilon = 1.0e-12);
+		let result: f64 = EncointerBalances::total_issuance(cid).lossy_into();
+		assert_abs_diff_eq!(result, 0.25, epsilon = 1.0e-12);
+	});
+}
+
+#[test]
+fn transfers_between_exempt_and_other_accounts_keep_total_issuance_consistent() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let cid = CommunityIdentifier::from_str("aaaaaaaaaa").unwrap();
+		System::set_block_number(0);
+		EncointerBalances::set_demurrage_exempt(cid, &alice, true);
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
+		assert_ok!(EncointerBalances::issue(cid, &bob, BalanceType::from_num(10)));
+
+		assert_ok!(EncointerBalances::transfer(
+			Some(alice.clone()).into(),
+			bob.clone(),
+			cid,
+			BalanceType::from_num(4)
+		));
+		assert_eq!(EncointerBalances::exempt_issuance(cid), BalanceType::from_num(6));
+		assert_ok!(EncointerBalances::transfer(
+			Some(bob.clone()).into(),
+			alice.clone(),
+			cid,
+			BalanceType::from_num(2)
+		));
+		assert_eq!(EncointerBalances::exempt_issuance(cid), BalanceType::from_num(8));
+
+		System::set_block_number(86400 / 5 * 356);
+		let balances: f64 = EncointerBalances::balance(cid, &alice)
+			.saturating_add(EncointerBalances::balance(cid, &bob))
+			.lossy_into();
+		let result: f64 = EncointerBalances::total_issuance(cid).lossy_into();
+		assert_abs_diff_eq!(result, balances, epsilon = 1.0e-12);
+		assert_abs_diff_eq!(result, 8.0 + 6.0, epsilon = 1.0e-12);
+	});
+}
+
+#[test]
+fn purge_balances_removes_demurrage_exemptions() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountKeyring::Alice.to_account_id();
+		let cid = CommunityIdentifier::from_str("aaaaaaaaaa").unwrap();
+		EncointerBalances::set_demurrage_exempt(cid, &alice, true);
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
+
+		EncointerBalances::purge_balances(cid);
+		assert!(!EncointerBalances::is_demurrage_exempt(cid, &alice));
+		assert_eq!(EncointerBalances::exempt_issuance(cid), BalanceType::from_num(0));
+	});
+}
+
+#[test]
+fn approve_and_transfer_from_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let charlie = AccountKeyring::Charlie.to_account_id();
+		let cid = CommunityIdentifier::default();
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
+		Balances::make_free_balance_be(&alice, 100);
+
+		assert_ok!(EncointerBalances::approve(
+			Some(alice.clone()).into(),
+			cid,
+			bob.clone(),
+			BalanceType::from_num(5),
+			None
+		));
+		assert_eq!(
+			last_event::<TestRuntime>(),
+			Some(
+				Event::Approved {
+					cid,
+					owner: alice.clone(),
+					spender: bob.clone(),
+					amount: BalanceType::from_num(5),
+					expiry: None
+				}
+				.into()
+			)
+		);
+
+		assert_ok!(EncointerBalances::transfer_from(
+			Some(bob.clone()).into(),
+			cid,
+			alice.clone(),
+			charlie.clone(),
+			BalanceType::from_num(3)
+		));
+		assert_eq!(EncointerBalances::balance(cid, &alice), BalanceType::from_num(7));
+		assert_eq!(EncointerBalances::balance(cid, &charlie), BalanceType::from_num(3));
+		assert_eq!(EncointerBalances::allowance(cid, &alice, &bob), BalanceType::from_num(2));
+
+		assert_ok!(EncointerBalances::transfer_from(
+			Some(bob.clone()).into(),
+			cid,
+			alice.clone(),
+			bob.clone(),
+			BalanceType::from_num(2)
+		));
+		assert!(!Approvals::<TestRuntime>::contains_key(cid, (&alice, &bob)));
+	});
+}
+
+#[test]
+fn transfer_from_exceeding_allowance_fails() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let cid = CommunityIdentifier::default();
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
+		Balances::make_free_balance_be(&alice, 100);
+
+		assert_noop!(
+			EncointerBalances::transfer_from(
+				Some(bob.clone()).into(),
+				cid,
+				alice.clone(),
+				bob.clone(),
+				BalanceType::from_num(1)
+			),
+			Error::<TestRuntime>::AllowanceExceeded,
+		);
+
+		assert_ok!(EncointerBalances::approve(
+			Some(alice.clone()).into(),
+			cid,
+			bob.clone(),
+			BalanceType::from_num(20),
+			None
+		));
+		assert_noop!(
+			EncointerBalances::transfer_from(
+				Some(bob.clone()).into(),
+				cid,
+				alice.clone(),
+				bob.clone(),
+				BalanceType::from_num(21)
+			),
+			Error::<TestRuntime>::AllowanceExceeded,
+		);
+		// the allowance does not lift the owner's balance constraints
+		assert_noop!(
+			EncointerBalances::transfer_from(
+				Some(bob.clone()).into(),
+				cid,
+				alice,
+				bob,
+				BalanceType::from_num(11)
+			),
+			Error::<TestRuntime>::BalanceTooLow,
+		);
+	});
+}
+
+#[test]
+fn allowance_expires() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(2);
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let cid = CommunityIdentifier::default();
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
+		Balances::make_free_balance_be(&alice, 100);
+
+		assert_noop!(
+			EncointerBalances::approve(
+				Some(alice.clone()).into(),
+				cid,
+				bob.clone(),
+				BalanceType::from_num(5),
+				Some(1)
+			),
+			Error::<TestRuntime>::AllowanceExpired,
+		);
+		assert_ok!(EncointerBalances::approve(
+			Some(alice.clone()).into(),
+			cid,
+			bob.clone(),
+			BalanceType::from_num(5),
+			Some(3)
+		));
+
+		System::set_block_number(3);
+		let allowance: f64 = EncointerBalances::allowance(cid, &alice, &bob).lossy_into();
+		assert_abs_diff_eq!(allowance, 5.0, epsilon = 1.0e-5);
+
+		System::set_block_number(4);
+		assert_eq!(EncointerBalances::allowance(cid, &alice, &bob), BalanceType::from_num(0));
+		assert_noop!(
+			EncointerBalances::transfer_from(
+				Some(bob.clone()).into(),
+				cid,
+				alice,
+				bob,
+				BalanceType::from_num(1)
+			),
+			Error::<TestRuntime>::AllowanceExpired,
+		);
+	});
+}
+
+#[test]
+fn allowance_is_subject_to_demurrage_of_owner() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let charlie = AccountKeyring::Charlie.to_account_id();
+		let cid = CommunityIdentifier::default();
+		System::set_block_number(0);
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
+		Balances::make_free_balance_be(&alice, 100);
+		Balances::make_free_balance_be(&charlie, 100);
+		assert_ok!(EncointerBalances::do_approve(
+			cid,
+			&alice,
+			&bob,
+			BalanceType::from_num(10),
+			None
+		));
+		EncointerBalances::set_demurrage_exempt(cid, &charlie, true);
+		assert_ok!(EncointerBalances::do_approve(
+			cid,
+			&charlie,
+			&bob,
+			BalanceType::from_num(10),
+			None
+		));
+
+		System::set_block_number(86400 / 5 * 356);
+		let result: f64 = EncointerBalances::allowance(cid, &alice, &bob).lossy_into();
+		assert_abs_diff_eq!(result, 5.0, epsilon = 1.0e-12);
+		assert_eq!(EncointerBalances::allowance(cid, &charlie, &bob), BalanceType::from_num(10));
+
+		// the whole balance can still be pulled
+		let balance = EncointerBalances::balance(cid, &alice);
+		assert_ok!(EncointerBalances::do_transfer_from(cid, &alice, &bob, &bob, balance));
+
+		// approving zero revokes
+		assert_ok!(EncointerBalances::do_approve(
+			cid,
+			&charlie,
+			&bob,
+			BalanceType::from_num(0),
+			None
+		));
+		assert!(!Approvals::<TestRuntime>::contains_key(cid, (&charlie, &bob)));
+	});
+}
+
+fn approval_deposit() -> u128 {
+	<TestRuntime as Config>::ApprovalDeposit::get()
+}
+
+#[test]
+fn approve_reserves_deposit_until_allowance_is_removed() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let charlie = AccountKeyring::Charlie.to_account_id();
+		let cid = CommunityIdentifier::default();
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
+		Balances::make_free_balance_be(&alice, 100);
+
+		assert_ok!(EncointerBalances::approve(
+			Some(alice.clone()).into(),
+			cid,
+			bob.clone(),
+			BalanceType::from_num(5),
+			None
+		));
+		assert_eq!(Balances::reserved_balance(&alice), approval_deposit());
+		// replacing the allowance keeps the deposit
+		assert_ok!(EncointerBalances::approve(
+			Some(alice.clone()).into(),
+			cid,
+			bob.clone(),
+			BalanceType::from_num(3),
+			None
+		));
+		assert_eq!(Balances::reserved_balance(&alice), approval_deposit());
+
+		// revoking returns the deposit
+		assert_ok!(EncointerBalances::approve(
+			Some(alice.clone()).into(),
+			cid,
+			bob.clone(),
+			BalanceType::from_num(0),
+			None
+		));
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+
+		// using up the allowance returns the deposit
+		assert_ok!(EncointerBalances::approve(
+			Some(alice.clone()).into(),
+			cid,
+			bob.clone(),
+			BalanceType::from_num(3),
+			None
+		));
+		assert_ok!(EncointerBalances::transfer_from(
+			Some(bob.clone()).into(),
+			cid,
+			alice.clone(),
+			bob.clone(),
+			BalanceType::from_num(3)
+		));
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+
+		// purging the community returns the deposit
+		assert_ok!(EncointerBalances::approve(
+			Some(alice.clone()).into(),
+			cid,
+			charlie,
+			BalanceType::from_num(3),
+			None
+		));
+		assert_eq!(Balances::reserved_balance(&alice), approval_deposit());
+		EncointerBalances::purge_balances(cid);
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+	});
+}
+
+#[test]
+fn approve_fails_if_deposit_cannot_be_reserved() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let cid = CommunityIdentifier::default();
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
+
+		assert_noop!(
+			EncointerBalances::approve(
+				Some(alice.clone()).into(),
+				cid,
+				bob,
+				BalanceType::from_num(5),
+				None
+			),
+			test_utils::pallet_balances::Error::<TestRuntime>::InsufficientBalance
+		);
+	});
+}
+
+fn standing_order_deposit() -> u128 {
+	<TestRuntime as Config>::StandingOrderDeposit::get()
+}
+
+fn create_standing_order(
+	payer: &AccountId32,
+	recipient: &AccountId32,
+	cid: CommunityIdentifier,
+	amount: f64,
+	period: StandingOrderPeriod<u64>,
+	end: Option<u64>,
+) -> StandingOrderIdType {
+	let id = EncointerBalances::standing_order_count();
+	assert_ok!(EncointerBalances::create_standing_order(
+		Some(payer.clone()).into(),
+		cid,
+		recipient.clone(),
+		BalanceType::from_num(amount),
+		period,
+		end
+	));
+	id
+}
+
+fn run_to_block(n: u64) {
+	System::set_block_number(n);
+	EncointerBalances::on_idle(n, Weight::MAX);
+}
+
+#[test]
+fn standing_order_pays_every_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let cid = CommunityIdentifier::default();
+		EncointerBalances::set_demurrage_exempt(cid, &alice, true);
+		EncointerBalances::set_demurrage_exempt(cid, &bob, true);
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(100)));
+		Balances::make_free_balance_be(&alice, 100);
+		let id =
+			create_standing_order(&alice, &bob, cid, 10.0, StandingOrderPeriod::Blocks(10), None);
+		assert_eq!(EncointerBalances::standing_orders_of(&alice).to_vec(), vec![id]);
+		assert_eq!(Balances::reserved_balance(&alice), standing_order_deposit());
+
+		run_to_block(10);
+		assert_eq!(EncointerBalances::balance(cid, &bob), BalanceType::from_num(0));
+
+		run_to_block(11);
+		assert_eq!(EncointerBalances::balance(cid, &bob), BalanceType::from_num(10));
+		assert_eq!(last_event::<TestRuntime>(), Some(Event::StandingOrderExecuted { id }.into()));
+
+		// missed periods are caught up
+		run_to_block(35);
+		assert_eq!(EncointerBalances::balance(cid, &bob), BalanceType::from_num(30));
+		assert_eq!(EncointerBalances::balance(cid, &alice), BalanceType::from_num(70));
+
+		assert_ok!(EncointerBalances::cancel_standing_order(Some(alice.clone()).into(), id));
+		assert_eq!(last_event::<TestRuntime>(), Some(Event::StandingOrderCancelled { id }.into()));
+		assert!(EncointerBalances::standing_orders(id).is_none());
+		assert!(EncointerBalances::standing_orders_of(&alice).is_empty());
+		assert!(EncointerBalances::standing_order_queue().is_empty());
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+	});
+}
+
+#[test]
+fn standing_order_pays_once_per_ceremony_cycle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let cid = CommunityIdentifier::default();
+		EncointerBalances::set_demurrage_exempt(cid, &alice, true);
+		EncointerBalances::set_demurrage_exempt(cid, &bob, true);
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(100)));
+		Balances::make_free_balance_be(&alice, 100);
+		create_standing_order(&alice, &bob, cid, 10.0, StandingOrderPeriod::CeremonyCycle, None);
+
+		run_to_block(100);
+		assert_eq!(EncointerBalances::balance(cid, &bob), BalanceType::from_num(0));
+
+		frame_support::storage::unhashed::put(
+			&current_ceremony_index_key(),
+			&(EncointerScheduler::current_ceremony_index() + 1),
+		);
+		run_to_block(101);
+		run_to_block(200);
+		assert_eq!(EncointerBalances::balance(cid, &bob), BalanceType::from_num(10));
+	});
+}
+
+#[test]
+fn standing_order_is_dropped_if_payment_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let charlie = AccountKeyring::Charlie.to_account_id();
+		let cid = CommunityIdentifier::default();
+		EncointerBalances::set_demurrage_exempt(cid, &alice, true);
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(15)));
+		Balances::make_free_balance_be(&alice, 100);
+		let too_high =
+			create_standing_order(&alice, &bob, cid, 10.0, StandingOrderPeriod::Blocks(5), None);
+		let below_ed = create_standing_order(
+			&alice,
+			&charlie,
+			cid,
+			0.000001,
+			StandingOrderPeriod::Blocks(5),
+			None,
+		);
+
+		run_to_block(6);
+		assert_eq!(EncointerBalances::balance(cid, &alice), BalanceType::from_num(5));
+		assert!(events::<TestRuntime>().contains(
+			&Event::StandingOrderDropped {
+				id: below_ed,
+				error: Error::<TestRuntime>::ExistentialDeposit.into()
+			}
+			.into()
+		));
+
+		run_to_block(11);
+		assert_eq!(
+			last_event::<TestRuntime>(),
+			Some(
+				Event::StandingOrderDropped {
+					id: too_high,
+					error: Error::<TestRuntime>::BalanceTooLow.into()
+				}
+				.into()
+			)
+		);
+		assert_eq!(EncointerBalances::balance(cid, &alice), BalanceType::from_num(5));
+		assert!(EncointerBalances::standing_order_queue().is_empty());
+		assert!(EncointerBalances::standing_orders_of(&alice).is_empty());
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+	});
+}
+
+#[test]
+fn standing_order_completes_after_end() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let cid = CommunityIdentifier::default();
+		EncointerBalances::set_demurrage_exempt(cid, &alice, true);
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(100)));
+		Balances::make_free_balance_be(&alice, 100);
+		let id = create_standing_order(
+			&alice,
+			&bob,
+			cid,
+			10.0,
+			StandingOrderPeriod::Blocks(10),
+			Some(15),
+		);
+
+		run_to_block(11);
+		run_to_block(21);
+		assert_eq!(EncointerBalances::balance(cid, &alice), BalanceType::from_num(90));
+		assert_eq!(last_event::<TestRuntime>(), Some(Event::StandingOrderCompleted { id }.into()));
+		assert!(EncointerBalances::standing_orders(id).is_none());
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+	});
+}
+
+#[test]
+fn standing_orders_are_executed_within_weight_limit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let cid = CommunityIdentifier::default();
+		EncointerBalances::set_demurrage_exempt(cid, &alice, true);
+		EncointerBalances::set_demurrage_exempt(cid, &bob, true);
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(100)));
+		Balances::make_free_balance_be(&alice, 100);
+		create_standing_order(&alice, &bob, cid, 10.0, StandingOrderPeriod::Blocks(5), None);
+		create_standing_order(&alice, &bob, cid, 10.0, StandingOrderPeriod::Blocks(5), None);
+
+		System::set_block_number(6);
+		let db_weight: RuntimeDbWeight = <TestRuntime as frame_system::Config>::DbWeight::get();
+		// the step weight depends on the length of the queue
+		let one_step = <TestRuntime as Config>::WeightInfo::execute_standing_order(2)
+			.saturating_add(db_weight.reads(1));
+		assert_eq!(EncointerBalances::on_idle(6, one_step), one_step);
+		assert_eq!(EncointerBalances::balance(cid, &bob), BalanceType::from_num(10));
+
+		EncointerBalances::on_idle(6, one_step);
+		assert_eq!(EncointerBalances::balance(cid, &bob), BalanceType::from_num(20));
+	});
+}
+
+#[test]
+fn create_and_cancel_standing_order_errs() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		let cid = CommunityIdentifier::default();
+		assert_noop!(
+			EncointerBalances::create_standing_order(
+				Some(alice.clone()).into(),
+				cid,
+				bob.clone(),
+				BalanceType::from_num(1),
+				StandingOrderPeriod::Blocks(5),
+				None
+			),
+			Error::<TestRuntime>::NoAccount,
+		);
+
+		assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
+		assert_noop!(
+			EncointerBalances::create_standing_order(
+				Some(alice.clone()).into(),
+				cid,
+				bob.clone(),
+				BalanceType::from_num(0),
+				StandingOrderPeriod::Blocks(5),
+				None
+			),
+			Error::<TestRuntime>::InvalidStandingOrderAmount,
+		);
+		assert_noop!(
+			EncointerBalances::create_standing_order(
+				Some(alice.clone()).into(),
+				cid,
+				bob.clone(),
+				BalanceType::from_num(1),
+				StandingOrderPeriod::Blocks(0),
+				None
+			),
+			Error::<TestRuntime>::InvalidStandingOrderPeriod,
+		);
+		assert_noop!(
+			EncointerBalances::create_standing_order(
+				Some(alice.clone()).into(),
+				cid,
+				bob.clone(),
+				BalanceType::from_num(1),
+				StandingOrderPeriod::Blocks(5),
+				None
+			),
+			test_utils::pallet_balances::Error::<TestRuntime>::InsufficientBalance,
+		);
+
+		Balances::make_free_balance_be(&alice, 100);
+
+		let id =
+			create_standing_order(&alice, &bob, cid, 1.0, StandingOrderPeriod::Blocks(5), None);
+		create_standing_order(&alice, &bob, cid, 1.0, StandingOrderPeriod::Blocks(5), None);
+		assert_noop!(
+			EncointerBalances::create_standing_order(
+				Some(alice.clone()).into(),
+				cid,
+				bob.clone(),
+				BalanceType::from_num(1),
+				StandingOrderPeriod::Blocks(5),
+				None
+			),
+			Error::<TestRuntime>::TooManyStandingOrders,
+		);
+
+		assert_noop!(
+			EncointerBalances::cancel_standing_order(Some(bob).into(), id),
+			Error::<TestRuntime>::NotStandingOrderPayer,
+		);
+		assert_noop!(
+			EncointerBalances::cancel_standing_order(Some(alice).into(), 42),
+			Error::<TestRuntime>::StandingOrderInexistent,
+		);
+	});
+}
+
+mod impl_fungibles {
+	use super::*;
+	use crate::impl_fungibles::{
+		fungible, DepositConsequence, Fortitude, Preservation, Provenance, WithdrawConsequence,
+	};
+	use fungibles::Inspect;
+
+	type AccountId = <TestRuntime as frame_system::Config>::AccountId;
+
+	#[test]
+	fn total_issuance_and_balance_works() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50.1)));
+			assert!(almost_eq(
+				<EncointerBalances as Inspect<AccountId>>::balance(cid, &alice),
+				50_100_000_000_000_000_000u128,
+				10000
+			));
+
+			assert!(almost_eq(
+				<EncointerBalances as Inspect<AccountId>>::reducible_balance(
+					cid,
+					&alice,
+					Preservation::Expendable,
+					Fortitude::Force
+				),
+				50_100_000_000_000_000_000u128,
+				10000
+			));
+
+			assert!(almost_eq(
+				<EncointerBalances as Inspect<AccountId>>::total_issuance(cid),
+				50_100_000_000_000_000_000u128,
+				10000
+			));
+		})
+	}
+
+	#[test]
+	fn minimum_balance_works() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			assert_eq!(
+				Pallet::<TestRuntime>::minimum_balance(cid),
+				fungible(ExistentialDeposit::get())
+			);
+			EncointerBalances::set_existential_deposit(cid, BalanceType::from_num(1));
+			assert_eq!(
+				Pallet::<TestRuntime>::minimum_balance(cid),
+				fungible(BalanceType::from_num(1))
+			);
+		})
+	}
+
+	#[test]
+	fn can_deposit_works() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let wrong_cid = CommunityIdentifier::from_str("aaaaaaaaaa").unwrap();
+			let alice = AccountKeyring::Alice.to_account_id();
+			let bob = AccountKeyring::Bob.to_account_id();
+			let ferdie = AccountKeyring::Ferdie.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
+
+			assert_eq!(
+				EncointerBalances::can_deposit(wrong_cid, &alice, 10, Provenance::Minted),
+				DepositConsequence::UnknownAsset
+			);
+
+			assert_ok!(EncointerBalances::issue(
+				cid,
+				&alice,
+				// u64::Max is uneven, so subtract 1 to be explicit about the result.
+				BalanceType::from_num((u64::MAX - 1) / 2)
+			));
+
+			assert_ok!(EncointerBalances::issue(
+				cid,
+				&bob,
+				// Subtract the 50 we issued to Alice above.
+				BalanceType::from_num((u64::MAX - 1) / 2 - 50)
+			));
+
+			assert_eq!(
+				EncointerBalances::can_deposit(
+					cid,
+					&ferdie,
+					fungible(BalanceType::from_num(2)),
+					Provenance::Minted,
+				),
+				DepositConsequence::Overflow
+			);
+
+			assert_eq!(
+				EncointerBalances::can_deposit(
+					cid,
+					&alice,
+					fungible(BalanceType::from_num(1)),
+					Provenance::Minted
+				),
+				DepositConsequence::Success
+			);
+
+			EncointerBalances::set_existential_deposit(cid, BalanceType::from_num(1));
+			assert_eq!(
+				EncointerBalances::can_deposit(
+					cid,
+					&ferdie,
+					fungible(BalanceType::from_num(1)),
+					Provenance::Extant
+				),
+				DepositConsequence::BelowMinimum
+			);
+		})
+	}
+
+	#[test]
+	fn can_withdraw_works() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let wrong_cid = CommunityIdentifier::from_str("aaaaaaaaaa").unwrap();
+			let alice = AccountKeyring::Alice.to_account_id();
+			let bob = AccountKeyring::Bob.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
+			assert_ok!(EncointerBalances::issue(cid, &bob, BalanceType::from_num(1)));
+
+			assert_eq!(
+				EncointerBalances::can_withdraw(wrong_cid, &alice, 10),
+				WithdrawConsequence::UnknownAsset
+			);
+
+			assert_eq!(
+				EncointerBalances::can_withdraw(cid, &bob, fungible(BalanceType::from_num(12))),
+				WithdrawConsequence::Underflow
+			);
+
+			assert_eq!(
+				EncointerBalances::can_withdraw(cid, &bob, fungible(BalanceType::from_num(0))),
+				WithdrawConsequence::Success
+			);
+
+			assert_eq!(
+				EncointerBalances::can_withdraw(cid, &bob, fungible(BalanceType::from_num(2))),
+				WithdrawConsequence::BalanceLow
+			);
+
+			assert_eq!(
+				EncointerBalances::can_withdraw(cid, &bob, fungible(BalanceType::from_num(1))),
+				WithdrawConsequence::Success
+			);
+		})
+	}
+
+	#[test]
+	fn set_balance_and_set_total_issuance_works() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
+
+			assert!(almost_eq(
+				<EncointerBalances as Inspect<AccountId>>::balance(cid, &alice),
+				10_000_000_000_000_000_000u128,
+				10000
+			));
+
+			assert_ok!(EncointerBalances::write_balance(
+				cid,
+				&alice,
+				20_000_000_000_000_000_000u128
+			));
+
+			assert!(almost_eq(
+				<EncointerBalances as Inspect<AccountId>>::balance(cid, &alice),
+				20_000_000_000_000_000_000u128,
+				10000
+			));
+
+			assert!(almost_eq(
+				<EncointerBalances as Inspect<AccountId>>::total_issuance(cid),
+				10_000_000_000_000_000_000u128,
+				10000
+			));
+
+			EncointerBalances::set_total_issuance(cid, 30_000_000_000_000_000_000u128);
+
+			assert!(almost_eq(
+				<EncointerBalances as Inspect<AccountId>>::total_issuance(cid),
+				30_000_000_000_000_000_000u128,
+				10000
+			));
+		})
+	}
+}
+
+mod impl_fungibles_approvals {
+	use super::*;
+	use crate::impl_fungibles::fungible;
+	use fungibles::{
+		approvals::{Inspect as ApprovalsInspect, Mutate as ApprovalsMutate},
+		Inspect,
+	};
+
+	type AccountId = <TestRuntime as frame_system::Config>::AccountId;
+
+	#[test]
+	fn approve_and_transfer_from_works() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			let bob = AccountKeyring::Bob.to_account_id();
+			let charlie = AccountKeyring::Charlie.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
+			Balances::make_free_balance_be(&alice, 100);
+
+			assert_ok!(<EncointerBalances as ApprovalsMutate<AccountId>>::approve(
+				cid,
+				&alice,
+				&bob,
+				fungible(BalanceType::from_num(5))
+			));
+			assert!(almost_eq(
+				<EncointerBalances as ApprovalsInspect<AccountId>>::allowance(cid, &alice, &bob),
+				fungible(BalanceType::from_num(5)),
+				10000
+			));
+
+			assert_ok!(<EncointerBalances as ApprovalsMutate<AccountId>>::transfer_from(
+				cid,
+				&alice,
+				&bob,
+				&charlie,
+				fungible(BalanceType::from_num(3))
+			));
+			assert!(almost_eq(
+				<EncointerBalances as Inspect<AccountId>>::balance(cid, &charlie),
+				fungible(BalanceType::from_num(3)),
+				10000
+			));
+			assert!(almost_eq(
+				<EncointerBalances as ApprovalsInspect<AccountId>>::allowance(cid, &alice, &bob),
+				fungible(BalanceType::from_num(2)),
+				10000
+			));
+
+			assert_noop!(
+				<EncointerBalances as ApprovalsMutate<AccountId>>::transfer_from(
+					cid,
+					&alice,
+					&bob,
+					&charlie,
+					fungible(BalanceType::from_num(3))
+				),
+				Error::<TestRuntime>::AllowanceExceeded
+			);
+		})
+	}
+
+	#[test]
+	fn approve_adds_to_the_allowance() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			let bob = AccountKeyring::Bob.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
+			Balances::make_free_balance_be(&alice, 100);
+			assert_ok!(EncointerBalances::do_approve(
+				cid,
+				&alice,
+				&bob,
+				BalanceType::from_num(5),
+				Some(10)
+			));
+
+			assert_ok!(<EncointerBalances as ApprovalsMutate<AccountId>>::approve(
+				cid,
+				&alice,
+				&bob,
+				fungible(BalanceType::from_num(3))
+			));
+			assert!(almost_eq(
+				<EncointerBalances as ApprovalsInspect<AccountId>>::allowance(cid, &alice, &bob),
+				fungible(BalanceType::from_num(8)),
+				10000
+			));
+			assert_eq!(
+				Approvals::<TestRuntime>::get(cid, (&alice, &bob)).unwrap().expiry,
+				Some(10)
+			);
+
+			// an expired allowance is replaced by one which does not expire
+			System::set_block_number(11);
+			assert_ok!(<EncointerBalances as ApprovalsMutate<AccountId>>::approve(
+				cid,
+				&alice,
+				&bob,
+				fungible(BalanceType::from_num(2))
+			));
+			assert!(almost_eq(
+				<EncointerBalances as ApprovalsInspect<AccountId>>::allowance(cid, &alice, &bob),
+				fungible(BalanceType::from_num(2)),
+				10000
+			));
+			assert_eq!(Approvals::<TestRuntime>::get(cid, (&alice, &bob)).unwrap().expiry, None);
+			assert_eq!(Balances::reserved_balance(&alice), approval_deposit());
+		})
+	}
+}
+
+mod impl_fungibles_hold_and_freeze {
+	use super::*;
+	use crate::{
+		impl_fungibles::{fungible, Fortitude, Preservation, WithdrawConsequence},
+		mock::TestId,
+	};
+	use frame_support::traits::tokens::{Precision, Restriction};
+	use fungibles::{Inspect, InspectFreeze, InspectHold, MutateFreeze, MutateHold};
+	use sp_runtime::TokenError;
+
+	type AccountId = <TestRuntime as frame_system::Config>::AccountId;
+
+	fn cc(amount: u32) -> u128 {
+		fungible(BalanceType::from_num(amount))
+	}
+
+	fn free(cid: CommunityIdentifier, who: &AccountId) -> u128 {
+		<EncointerBalances as Inspect<AccountId>>::balance(cid, who)
+	}
+
+	fn spendable(cid: CommunityIdentifier, who: &AccountId) -> u128 {
+		EncointerBalances::reducible_balance(cid, who, Preservation::Expendable, Fortitude::Polite)
+	}
+
+	#[test]
+	fn hold_and_release_works() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
+
+			assert_ok!(EncointerBalances::hold(cid, &TestId::Foo, &alice, cc(20)));
+			assert!(almost_eq(free(cid, &alice), cc(30), 10000));
+			assert!(almost_eq(
+				EncointerBalances::balance_on_hold(cid, &TestId::Foo, &alice),
+				cc(20),
+				10000
+			));
+			assert_eq!(EncointerBalances::balance_on_hold(cid, &TestId::Bar, &alice), 0);
+			assert!(almost_eq(EncointerBalances::total_balance(cid, &alice), cc(50), 10000));
+			assert!(almost_eq(
+				<EncointerBalances as Inspect<AccountId>>::total_issuance(cid),
+				cc(50),
+				10000
+			));
+
+			let held = EncointerBalances::balance_on_hold(cid, &TestId::Foo, &alice);
+			assert_ok!(EncointerBalances::release(
+				cid,
+				&TestId::Foo,
+				&alice,
+				held,
+				Precision::Exact
+			));
+			assert!(almost_eq(free(cid, &alice), cc(50), 10000));
+			assert_eq!(EncointerBalances::total_balance_on_hold(cid, &alice), 0);
+			assert!(!Holds::<TestRuntime>::contains_key(cid, &alice));
+		})
+	}
+
+	#[test]
+	fn hold_fails_if_free_balance_is_too_low() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			let bob = AccountKeyring::Bob.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
+
+			assert_noop!(
+				EncointerBalances::hold(cid, &TestId::Foo, &alice, cc(60)),
+				TokenError::FundsUnavailable
+			);
+			// bob has no account in this community
+			assert_noop!(
+				EncointerBalances::hold(cid, &TestId::Foo, &bob, 0),
+				TokenError::CannotCreateHold
+			);
+		})
+	}
+
+	#[test]
+	fn number_of_holds_is_bounded() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
+
+			assert_ok!(EncointerBalances::hold(cid, &TestId::Foo, &alice, cc(10)));
+			assert_ok!(EncointerBalances::hold(cid, &TestId::Bar, &alice, cc(10)));
+			assert!(!EncointerBalances::hold_available(cid, &TestId::Baz, &alice));
+			assert_noop!(
+				EncointerBalances::hold(cid, &TestId::Baz, &alice, cc(10)),
+				TokenError::CannotCreateHold
+			);
+
+			// existing holds can still be increased
+			assert_ok!(EncointerBalances::hold(cid, &TestId::Foo, &alice, cc(10)));
+			assert!(almost_eq(
+				EncointerBalances::balance_on_hold(cid, &TestId::Foo, &alice),
+				cc(20),
+				10000
+			));
+			assert!(almost_eq(
+				EncointerBalances::total_balance_on_hold(cid, &alice),
+				cc(30),
+				10000
+			));
+		})
+	}
 
 	#[test]
-	fn can_deposit_works() {
+	fn burn_held_reduces_total_issuance() {
 		new_test_ext().execute_with(|| {
 			let cid = CommunityIdentifier::default();
-			let wrong_cid = CommunityIdentifier::from_str("aaaaaaaaaa").unwrap();
 			let alice = AccountKeyring::Alice.to_account_id();
-			let bob = AccountKeyring::Bob.to_account_id();
-			let ferdie = AccountKeyring::Ferdie.to_account_id();
 			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
+			assert_ok!(EncointerBalances::hold(cid, &TestId::Foo, &alice, cc(20)));
 
-			assert_eq!(
-				EncointerBalances::can_deposit(wrong_cid, &alice, 10, Provenance::Minted),
-				DepositConsequence::UnknownAsset
-			);
+			assert_ok!(EncointerBalances::burn_held(
+				cid,
+				&TestId::Foo,
+				&alice,
+				cc(5),
+				Precision::Exact,
+				Fortitude::Polite
+			));
+			assert!(almost_eq(
+				EncointerBalances::balance_on_hold(cid, &TestId::Foo, &alice),
+				cc(15),
+				10000
+			));
+			assert!(almost_eq(free(cid, &alice), cc(30), 10000));
+			assert!(almost_eq(
+				<EncointerBalances as Inspect<AccountId>>::total_issuance(cid),
+				cc(45),
+				10000
+			));
+		})
+	}
 
-			assert_ok!(EncointerBalances::issue(
+	#[test]
+	fn transfer_on_hold_works() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			let bob = AccountKeyring::Bob.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
+			assert_ok!(EncointerBalances::issue(cid, &bob, BalanceType::from_num(1)));
+			assert_ok!(EncointerBalances::hold(cid, &TestId::Foo, &alice, cc(20)));
+
+			assert_ok!(EncointerBalances::transfer_on_hold(
 				cid,
+				&TestId::Foo,
 				&alice,
-				// u64::Max is uneven, so subtract 1 to be explicit about the result.
-				BalanceType::from_num((u64::MAX - 1) / 2)
+				&bob,
+				cc(5),
+				Precision::Exact,
+				Restriction::Free,
+				Fortitude::Polite
 			));
+			assert!(almost_eq(free(cid, &bob), cc(6), 10000));
 
-			assert_ok!(EncointerBalances::issue(
+			assert_ok!(EncointerBalances::transfer_on_hold(
 				cid,
+				&TestId::Foo,
+				&alice,
 				&bob,
-				// Subtract the 50 we issued to Alice above.
-				BalanceType::from_num((u64::MAX - 1) / 2 - 50)
+				cc(5),
+				Precision::Exact,
+				Restriction::OnHold,
+				Fortitude::Polite
+			));
+			assert!(almost_eq(
+				EncointerBalances::balance_on_hold(cid, &TestId::Foo, &bob),
+				cc(5),
+				10000
+			));
+			assert!(almost_eq(
+				EncointerBalances::balance_on_hold(cid, &TestId::Foo, &alice),
+				cc(10),
+				10000
+			));
+			assert!(almost_eq(
+				<EncointerBalances as Inspect<AccountId>>::total_issuance(cid),
+				cc(51),
+				10000
+			));
+		})
+	}
+
+	#[test]
+	fn held_balance_is_subject_to_demurrage() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			System::set_block_number(0);
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(100)));
+			assert_ok!(EncointerBalances::hold(cid, &TestId::Foo, &alice, cc(50)));
+
+			System::set_block_number(86400 / 5 * 365);
+			let held = EncointerBalances::balance_on_hold(cid, &TestId::Foo, &alice);
+			assert!(held < cc(50));
+			assert!(almost_eq(held, free(cid, &alice), 1_000_000));
+			assert!(almost_eq(
+				EncointerBalances::total_balance(cid, &alice),
+				<EncointerBalances as Inspect<AccountId>>::total_issuance(cid),
+				1_000_000
 			));
+		})
+	}
+
+	#[test]
+	fn held_balance_of_demurrage_exempt_account_does_not_decay() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			System::set_block_number(0);
+			EncointerBalances::set_demurrage_exempt(cid, &alice, true);
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(100)));
+			assert_ok!(EncointerBalances::hold(cid, &TestId::Foo, &alice, cc(50)));
+
+			System::set_block_number(86400 / 5 * 365);
+			assert!(almost_eq(
+				EncointerBalances::balance_on_hold(cid, &TestId::Foo, &alice),
+				cc(50),
+				10000
+			));
+			assert!(almost_eq(
+				<EncointerBalances as Inspect<AccountId>>::total_issuance(cid),
+				cc(100),
+				10000
+			));
+
+			let held = EncointerBalances::balance_on_hold(cid, &TestId::Foo, &alice);
+			assert_ok!(EncointerBalances::burn_held(
+				cid,
+				&TestId::Foo,
+				&alice,
+				held,
+				Precision::Exact,
+				Fortitude::Polite
+			));
+			assert!(almost_eq(
+				<EncointerBalances as Inspect<AccountId>>::total_issuance(cid),
+				cc(50),
+				10000
+			));
+			assert!(almost_eq(fungible(EncointerBalances::exempt_issuance(cid)), cc(50), 10000));
+		})
+	}
+
+	#[test]
+	fn freeze_restricts_spending() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			let bob = AccountKeyring::Bob.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
 
+			assert_ok!(EncointerBalances::set_freeze(cid, &TestId::Foo, &alice, cc(30)));
+			assert!(almost_eq(
+				EncointerBalances::balance_frozen(cid, &TestId::Foo, &alice),
+				cc(30),
+				10000
+			));
+			assert!(almost_eq(spendable(cid, &alice), cc(20), 10000));
 			assert_eq!(
-				EncointerBalances::can_deposit(
+				EncointerBalances::can_withdraw(cid, &alice, cc(25)),
+				WithdrawConsequence::Frozen
+			);
+			assert_noop!(
+				EncointerBalances::transfer(
+					RuntimeOrigin::signed(alice.clone()),
+					bob.clone(),
 					cid,
-					&ferdie,
-					fungible(BalanceType::from_num(2)),
-					Provenance::Minted,
+					BalanceType::from_num(25)
 				),
-				DepositConsequence::Overflow
+				Error::<TestRuntime>::Frozen
+			);
+			assert_noop!(
+				EncointerBalances::burn(cid, &alice, BalanceType::from_num(25)),
+				Error::<TestRuntime>::Frozen
 			);
+			assert_ok!(EncointerBalances::transfer(
+				RuntimeOrigin::signed(alice.clone()),
+				bob.clone(),
+				cid,
+				BalanceType::from_num(15)
+			));
 
-			assert_eq!(
-				EncointerBalances::can_deposit(
+			assert_ok!(EncointerBalances::thaw(cid, &TestId::Foo, &alice));
+			assert!(!Freezes::<TestRuntime>::contains_key(cid, &alice));
+			assert_ok!(EncointerBalances::transfer(
+				RuntimeOrigin::signed(alice.clone()),
+				bob,
+				cid,
+				BalanceType::from_num(30)
+			));
+		})
+	}
+
+	#[test]
+	fn held_balance_counts_towards_freeze() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
+			assert_ok!(EncointerBalances::set_freeze(cid, &TestId::Foo, &alice, cc(30)));
+			assert_ok!(EncointerBalances::hold(cid, &TestId::Bar, &alice, cc(20)));
+
+			// 30 free, of which 10 are frozen
+			assert!(almost_eq(spendable(cid, &alice), cc(20), 10000));
+			assert!(almost_eq(
+				EncointerBalances::reducible_total_balance_on_hold(cid, &alice, Fortitude::Polite),
+				cc(20),
+				10000
+			));
+
+			assert_ok!(EncointerBalances::burn(cid, &alice, BalanceType::from_num(15)));
+			// 15 free, so 15 of the held balance are frozen
+			assert!(almost_eq(
+				EncointerBalances::reducible_total_balance_on_hold(cid, &alice, Fortitude::Polite),
+				cc(5),
+				10000
+			));
+			assert!(almost_eq(
+				EncointerBalances::reducible_total_balance_on_hold(cid, &alice, Fortitude::Force),
+				cc(20),
+				10000
+			));
+			assert_noop!(
+				EncointerBalances::burn_held(
 					cid,
+					&TestId::Bar,
 					&alice,
-					fungible(BalanceType::from_num(1)),
-					Provenance::Minted
+					cc(10),
+					Precision::Exact,
+					Fortitude::Polite
 				),
-				DepositConsequence::Success
+				TokenError::Frozen
 			);
 		})
 	}
 
 	#[test]
-	fn can_withdraw_works() {
+	fn freezes_overlap_and_can_be_extended() {
 		new_test_ext().execute_with(|| {
 			let cid = CommunityIdentifier::default();
-			let wrong_cid = CommunityIdentifier::from_str("aaaaaaaaaa").unwrap();
 			let alice = AccountKeyring::Alice.to_account_id();
-			let bob = AccountKeyring::Bob.to_account_id();
-			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
-			assert_ok!(EncointerBalances::issue(cid, &bob, BalanceType::from_num(1)));
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
 
-			assert_eq!(
-				EncointerBalances::can_withdraw(wrong_cid, &alice, 10),
-				WithdrawConsequence::UnknownAsset
-			);
+			assert_ok!(EncointerBalances::set_freeze(cid, &TestId::Foo, &alice, cc(10)));
+			assert_ok!(EncointerBalances::set_freeze(cid, &TestId::Bar, &alice, cc(20)));
+			assert!(almost_eq(spendable(cid, &alice), cc(30), 10000));
 
-			assert_eq!(
-				EncointerBalances::can_withdraw(cid, &bob, fungible(BalanceType::from_num(12))),
-				WithdrawConsequence::Underflow
-			);
+			assert_ok!(EncointerBalances::extend_freeze(cid, &TestId::Foo, &alice, cc(25)));
+			assert!(almost_eq(spendable(cid, &alice), cc(25), 10000));
+			assert_ok!(EncointerBalances::extend_freeze(cid, &TestId::Foo, &alice, cc(5)));
+			assert!(almost_eq(
+				EncointerBalances::balance_frozen(cid, &TestId::Foo, &alice),
+				cc(25),
+				10000
+			));
 
-			assert_eq!(
-				EncointerBalances::can_withdraw(cid, &bob, fungible(BalanceType::from_num(0))),
-				WithdrawConsequence::Success
+			assert!(!EncointerBalances::can_freeze(cid, &TestId::Baz, &alice));
+			assert_noop!(
+				EncointerBalances::set_freeze(cid, &TestId::Baz, &alice, cc(10)),
+				Error::<TestRuntime>::TooManyFreezes
 			);
 
-			assert_eq!(
-				EncointerBalances::can_withdraw(cid, &bob, fungible(BalanceType::from_num(2))),
-				WithdrawConsequence::BalanceLow
-			);
+			assert_ok!(EncointerBalances::set_freeze(cid, &TestId::Foo, &alice, 0));
+			assert!(almost_eq(spendable(cid, &alice), cc(30), 10000));
+			assert_ok!(EncointerBalances::thaw(cid, &TestId::Bar, &alice));
+			assert!(!Freezes::<TestRuntime>::contains_key(cid, &alice));
+		})
+	}
 
+	#[test]
+	fn account_with_holds_is_not_removed() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			let bob = AccountKeyring::Bob.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
+			assert_ok!(EncointerBalances::hold(cid, &TestId::Foo, &alice, cc(30)));
+
+			assert_ok!(EncointerBalances::transfer_all(
+				RuntimeOrigin::signed(alice.clone()),
+				bob,
+				cid
+			));
+			assert!(EncointerBalanceStorage::<TestRuntime>::contains_key(cid, &alice));
+			assert_eq!(System::account(&alice).sufficients, 1);
+
+			let held = EncointerBalances::balance_on_hold(cid, &TestId::Foo, &alice);
+			assert_ok!(EncointerBalances::release(
+				cid,
+				&TestId::Foo,
+				&alice,
+				held,
+				Precision::Exact
+			));
+			assert!(almost_eq(free(cid, &alice), cc(30), 10000));
+		})
+	}
+
+	#[test]
+	fn release_best_effort_releases_at_most_the_held_amount() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
+			assert_ok!(EncointerBalances::hold(cid, &TestId::Foo, &alice, cc(20)));
+
+			assert_noop!(
+				EncointerBalances::release(cid, &TestId::Foo, &alice, cc(30), Precision::Exact),
+				TokenError::FundsUnavailable
+			);
+			let released = EncointerBalances::release(
+				cid,
+				&TestId::Foo,
+				&alice,
+				cc(30),
+				Precision::BestEffort,
+			)
+			.unwrap();
+			assert!(almost_eq(released, cc(20), 10000));
+			assert!(almost_eq(free(cid, &alice), cc(50), 10000));
+			assert!(!Holds::<TestRuntime>::contains_key(cid, &alice));
+			// releasing from an empty hold is a no-op
 			assert_eq!(
-				EncointerBalances::can_withdraw(cid, &bob, fungible(BalanceType::from_num(1))),
-				WithdrawConsequence::Success
+				EncointerBalances::release(cid, &TestId::Foo, &alice, cc(5), Precision::BestEffort),
+				Ok(0)
 			);
 		})
 	}
 
 	#[test]
-	fn set_balance_and_set_total_issuance_works() {
+	fn burn_held_best_effort_burns_at_most_the_reducible_held_amount() {
 		new_test_ext().execute_with(|| {
 			let cid = CommunityIdentifier::default();
 			let alice = AccountKeyring::Alice.to_account_id();
-			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(10)));
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
+			assert_ok!(EncointerBalances::hold(cid, &TestId::Foo, &alice, cc(20)));
+			// 30 free, so 10 of the held balance are frozen
+			assert_ok!(EncointerBalances::set_freeze(cid, &TestId::Foo, &alice, cc(40)));
 
+			let burnt = EncointerBalances::burn_held(
+				cid,
+				&TestId::Foo,
+				&alice,
+				cc(30),
+				Precision::BestEffort,
+				Fortitude::Polite,
+			)
+			.unwrap();
+			assert!(almost_eq(burnt, cc(10), 10000));
 			assert!(almost_eq(
-				<EncointerBalances as Inspect<AccountId>>::balance(cid, &alice),
-				10_000_000_000_000_000_000u128,
+				EncointerBalances::balance_on_hold(cid, &TestId::Foo, &alice),
+				cc(10),
+				10000
+			));
+			assert!(almost_eq(
+				<EncointerBalances as Inspect<AccountId>>::total_issuance(cid),
+				cc(40),
 				10000
 			));
 
-			assert_ok!(EncointerBalances::write_balance(
+			let burnt = EncointerBalances::burn_held(
 				cid,
+				&TestId::Foo,
 				&alice,
-				20_000_000_000_000_000_000u128
-			));
-
+				cc(30),
+				Precision::BestEffort,
+				Fortitude::Force,
+			)
+			.unwrap();
+			assert!(almost_eq(burnt, cc(10), 10000));
+			assert_eq!(EncointerBalances::total_balance_on_hold(cid, &alice), 0);
+			assert!(almost_eq(free(cid, &alice), cc(30), 10000));
 			assert!(almost_eq(
-				<EncointerBalances as Inspect<AccountId>>::balance(cid, &alice),
-				20_000_000_000_000_000_000u128,
+				<EncointerBalances as Inspect<AccountId>>::total_issuance(cid),
+				cc(30),
 				10000
 			));
+		})
+	}
+
+	#[test]
+	fn transfer_on_hold_free_endows_new_account() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			let bob = AccountKeyring::Bob.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
+			assert_ok!(EncointerBalances::hold(cid, &TestId::Foo, &alice, cc(20)));
 
+			let transferred = EncointerBalances::transfer_on_hold(
+				cid,
+				&TestId::Foo,
+				&alice,
+				&bob,
+				cc(30),
+				Precision::BestEffort,
+				Restriction::Free,
+				Fortitude::Polite,
+			)
+			.unwrap();
+			assert!(almost_eq(transferred, cc(20), 10000));
+			assert!(almost_eq(free(cid, &bob), cc(20), 10000));
+			assert_eq!(EncointerBalances::total_balance_on_hold(cid, &bob), 0);
+			assert_eq!(EncointerBalances::total_balance_on_hold(cid, &alice), 0);
+			assert!(almost_eq(free(cid, &alice), cc(30), 10000));
 			assert!(almost_eq(
 				<EncointerBalances as Inspect<AccountId>>::total_issuance(cid),
-				10_000_000_000_000_000_000u128,
+				cc(50),
 				10000
 			));
+		})
+	}
 
-			EncointerBalances::set_total_issuance(cid, 30_000_000_000_000_000_000u128);
+	#[test]
+	fn freeze_may_exceed_the_balance() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			let bob = AccountKeyring::Bob.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
 
+			assert_ok!(EncointerBalances::set_freeze(cid, &TestId::Foo, &alice, cc(80)));
+			assert_eq!(spendable(cid, &alice), 0);
 			assert!(almost_eq(
-				<EncointerBalances as Inspect<AccountId>>::total_issuance(cid),
-				30_000_000_000_000_000_000u128,
+				EncointerBalances::reducible_balance(
+					cid,
+					&alice,
+					Preservation::Expendable,
+					Fortitude::Force
+				),
+				cc(50),
 				10000
 			));
+			assert_eq!(
+				EncointerBalances::can_withdraw(cid, &alice, cc(1)),
+				WithdrawConsequence::Frozen
+			);
+			assert_noop!(
+				EncointerBalances::transfer(
+					RuntimeOrigin::signed(alice.clone()),
+					bob.clone(),
+					cid,
+					BalanceType::from_num(1)
+				),
+				Error::<TestRuntime>::Frozen
+			);
+
+			// frozen funds may still be put on hold, but not be released from it politely
+			assert_ok!(EncointerBalances::hold(cid, &TestId::Bar, &alice, cc(20)));
+			assert_eq!(
+				EncointerBalances::reducible_total_balance_on_hold(cid, &alice, Fortitude::Polite),
+				0
+			);
+
+			// new income is frozen as well until the freeze is covered
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(20)));
+			assert_eq!(spendable(cid, &alice), 0);
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(20)));
+			assert!(almost_eq(spendable(cid, &alice), cc(10), 10000));
+
+			assert_ok!(EncointerBalances::thaw(cid, &TestId::Foo, &alice));
+			assert!(almost_eq(spendable(cid, &alice), cc(70), 10000));
+		})
+	}
+
+	#[test]
+	fn purge_balances_removes_holds_and_freezes() {
+		new_test_ext().execute_with(|| {
+			let cid = CommunityIdentifier::default();
+			let alice = AccountKeyring::Alice.to_account_id();
+			assert_ok!(EncointerBalances::issue(cid, &alice, BalanceType::from_num(50)));
+			assert_ok!(EncointerBalances::hold(cid, &TestId::Foo, &alice, cc(20)));
+			assert_ok!(EncointerBalances::set_freeze(cid, &TestId::Foo, &alice, cc(10)));
+
+			EncointerBalances::purge_balances(cid);
+			assert!(!Holds::<TestRuntime>::contains_key(cid, &alice));
+			assert!(!Freezes::<TestRuntime>::contains_key(cid, &alice));
 		})
 	}
 }
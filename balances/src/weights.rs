@@ -51,8 +51,15 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_encointer_balances.
 pub trait WeightInfo {
 	fn transfer() -> Weight;
+	fn transfer_with_reference() -> Weight;
+	fn transfer_many(n: u32) -> Weight;
 	fn transfer_all() -> Weight;
 	fn set_fee_conversion_factor() -> Weight;
+	fn approve() -> Weight;
+	fn transfer_from() -> Weight;
+	fn create_standing_order(n: u32) -> Weight;
+	fn cancel_standing_order(n: u32) -> Weight;
+	fn execute_standing_order(n: u32) -> Weight;
 }
 
 /// Weights for pallet_encointer_balances using the Encointer solo chain node and recommended
@@ -60,35 +67,117 @@ pub trait WeightInfo {
 pub struct EncointerWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for EncointerWeight<T> {
 	fn transfer() -> Weight {
-		Weight::from_parts(88_000_000, 0)
-			.saturating_add(T::DbWeight::get().reads(4))
+		Weight::from_parts(154_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn transfer_with_reference() -> Weight {
+		Weight::from_parts(154_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(9))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	fn transfer_many(n: u32) -> Weight {
+		Weight::from_parts(58_000_000, 0)
+			.saturating_add(Weight::from_parts(90_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
 	fn transfer_all() -> Weight {
-		Weight::from_parts(111_000_000, 0)
-			.saturating_add(T::DbWeight::get().reads(5))
+		Weight::from_parts(222_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(10))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 
 	fn set_fee_conversion_factor() -> Weight {
 		Weight::from_parts(16_300_000, 0).saturating_add(T::DbWeight::get().writes(1))
 	}
+	fn approve() -> Weight {
+		Weight::from_parts(51_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn transfer_from() -> Weight {
+		Weight::from_parts(178_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn create_standing_order(n: u32) -> Weight {
+		Weight::from_parts(69_000_000, 0)
+			.saturating_add(Weight::from_parts(121_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	fn cancel_standing_order(n: u32) -> Weight {
+		Weight::from_parts(54_000_000, 0)
+			.saturating_add(Weight::from_parts(114_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn execute_standing_order(n: u32) -> Weight {
+		Weight::from_parts(170_000_000, 0)
+			.saturating_add(Weight::from_parts(194_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
 
 // For tests
 impl WeightInfo for () {
 	fn transfer() -> Weight {
-		Weight::from_parts(88_000_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(4))
+		Weight::from_parts(154_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	fn transfer_with_reference() -> Weight {
+		Weight::from_parts(154_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(9))
 			.saturating_add(RocksDbWeight::get().writes(3))
 	}
+	fn transfer_many(n: u32) -> Weight {
+		Weight::from_parts(58_000_000, 0)
+			.saturating_add(Weight::from_parts(90_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
 	fn transfer_all() -> Weight {
-		Weight::from_parts(111_000_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(5))
+		Weight::from_parts(222_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(10))
 			.saturating_add(RocksDbWeight::get().writes(4))
 	}
 
 	fn set_fee_conversion_factor() -> Weight {
 		Weight::from_parts(16_300_000, 0).saturating_add(RocksDbWeight::get().writes(1))
 	}
+	fn approve() -> Weight {
+		Weight::from_parts(51_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	fn transfer_from() -> Weight {
+		Weight::from_parts(178_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(10))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	fn create_standing_order(n: u32) -> Weight {
+		Weight::from_parts(69_000_000, 0)
+			.saturating_add(Weight::from_parts(121_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	fn cancel_standing_order(n: u32) -> Weight {
+		Weight::from_parts(54_000_000, 0)
+			.saturating_add(Weight::from_parts(114_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	fn execute_standing_order(n: u32) -> Weight {
+		Weight::from_parts(170_000_000, 0)
+			.saturating_add(Weight::from_parts(194_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
 }
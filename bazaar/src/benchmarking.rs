@@ -35,6 +35,7 @@ fn create_community<T: Config>() -> CommunityIdentifier {
 		community_meta.clone(),
 		None,
 		None,
+		None,
 	)
 	.ok();
 
@@ -25,6 +25,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		EncointerScheduler: pallet_encointer_scheduler::{Pallet, Call, Storage, Config<T>, Event},
 		EncointerCommunities: pallet_encointer_communities::{Pallet, Call, Storage, Event<T>},
 		EncointerBalances: pallet_encointer_balances::{Pallet, Call, Storage, Event<T>},
@@ -40,6 +41,7 @@ impl dut::Config for TestRuntime {
 // boilerplate
 impl_frame_system!(TestRuntime);
 impl_timestamp!(TestRuntime, EncointerScheduler);
+impl_balances!(TestRuntime, System);
 impl_encointer_balances!(TestRuntime);
 impl_encointer_communities!(TestRuntime);
 impl_encointer_scheduler!(TestRuntime);
@@ -132,6 +132,21 @@ pub fn assignment_fn_inverse(
 	Some(result)
 }
 
+/// Closes the gaps that `moved` participants leave behind in a group of `count` participants.
+///
+/// The participants which are not moved shall keep their index if it is below the size of the
+/// remaining group. Everybody else takes over the index of a moved participant. Returns these
+/// `(index, new_index)` pairs. `moved` must be sorted and free of duplicates.
+pub fn compact_indices(
+	moved: &[ParticipantIndexType],
+	count: ParticipantIndexType,
+) -> Vec<(ParticipantIndexType, ParticipantIndexType)> {
+	let remaining = count.saturating_sub(moved.len() as u64);
+	let gaps = moved.iter().copied().filter(|i| *i < remaining);
+	let displaced = (remaining..count).filter(|i| moved.binary_search(i).is_err());
+	displaced.zip(gaps).collect()
+}
+
 fn t3(
 	n: u64,
 	current_index: u64,
@@ -277,6 +292,29 @@ mod tests {
 		check_assignment(num_participants, assignment_params, n);
 	}
 
+	#[test]
+	fn compact_indices_works() {
+		assert_eq!(compact_indices(&[], 5), vec![]);
+		assert_eq!(compact_indices(&[3, 4], 5), vec![]);
+		assert_eq!(compact_indices(&[0, 2], 5), vec![(3, 0), (4, 2)]);
+		assert_eq!(compact_indices(&[1, 4], 5), vec![(3, 1)]);
+		assert_eq!(compact_indices(&[0, 1, 2, 3, 4], 5), vec![]);
+	}
+
+	#[test]
+	fn compact_indices_yields_contiguous_indices() {
+		let count = 50u64;
+		let moved: Vec<u64> = (0..count).filter(|i| i % 3 == 0 || i % 7 == 0).collect();
+		let remap = compact_indices(&moved, count);
+
+		let mut new_indices: Vec<u64> = (0..count)
+			.filter(|i| moved.binary_search(i).is_err())
+			.map(|i| remap.iter().find(|(from, _)| *from == i).map_or(i, |(_, to)| *to))
+			.collect();
+		new_indices.sort();
+		assert_eq!(new_indices, (0..count - moved.len() as u64).collect::<Vec<_>>());
+	}
+
 	fn check_assignment(num_participants: u64, assignment_params: AssignmentParams, n: u64) {
 		let mut locations: Vec<u64> = vec![0; num_participants as usize];
 
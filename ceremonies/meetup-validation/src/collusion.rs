@@ -0,0 +1,137 @@
+// Copyright (c) 2019 Alain Brenzikofer
+// This file is part of Encointer
+//
+// Encointer is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Encointer is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Encointer.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Detects groups of accounts that keep meeting each other across ceremonies.
+//!
+//! Meetup validation only looks at a single meetup. Here we build the graph of mutual
+//! attestations over the attestation history of a community and compare how often two accounts
+//! met with how often random meetup assignment would have put them together. Pairs that met far
+//! more often than expected are reported, together with the clusters they form.
+
+use encointer_primitives::{
+	balances::BalanceType,
+	ceremonies::{CeremonyAttestations, CollusionDetectionParams, CollusionReport, SuspiciousPair},
+};
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	vec::Vec,
+};
+
+pub fn detect_collusion<AccountId: Ord + Clone>(
+	history: &[CeremonyAttestations<AccountId>],
+	params: &CollusionDetectionParams,
+) -> CollusionReport<AccountId> {
+	// for every account, the positions in `history` of the ceremonies it attended
+	let mut attendance: BTreeMap<&AccountId, Vec<usize>> = BTreeMap::new();
+	let mut co_attendances: BTreeMap<(&AccountId, &AccountId), u32> = BTreeMap::new();
+
+	for (c, ceremony) in history.iter().enumerate() {
+		let mut attendees: BTreeSet<&AccountId> = BTreeSet::new();
+		let mut attested: BTreeSet<(&AccountId, &AccountId)> = BTreeSet::new();
+		for (attester, attestees) in ceremony.attestations.iter() {
+			attendees.insert(attester);
+			for attestee in attestees.iter().filter(|a| *a != attester) {
+				attendees.insert(attestee);
+				attested.insert((attester, attestee));
+			}
+		}
+		for a in attendees {
+			attendance.entry(a).or_default().push(c);
+		}
+		// only mutual attestations prove that two accounts met
+		for (a, b) in attested.iter().filter(|(a, b)| a < b && attested.contains(&(*b, *a))) {
+			*co_attendances.entry((*a, *b)).or_default() += 1;
+		}
+	}
+
+	let mut suspicious_pairs: Vec<SuspiciousPair<AccountId>> = Vec::new();
+	for ((a, b), count) in co_attendances {
+		if count < params.min_co_attendances {
+			continue;
+		}
+		let attended_b = &attendance[b];
+		let shared: Vec<usize> =
+			attendance[a].iter().filter(|c| attended_b.contains(c)).copied().collect();
+		let expected = shared.iter().fold(BalanceType::from_num(0), |acc, c| {
+			acc.saturating_add(co_attendance_probability(&history[*c]))
+		});
+		if BalanceType::from_num(count) < params.min_excess_ratio.saturating_mul(expected) {
+			continue;
+		}
+		suspicious_pairs.push(SuspiciousPair {
+			accounts: (a.clone(), b.clone()),
+			co_attendances: count,
+			shared_ceremonies: shared.len() as u32,
+			expected_co_attendances: expected,
+		});
+	}
+
+	let clusters = clusters(&suspicious_pairs);
+	CollusionReport {
+		analyzed_ceremonies: history.iter().map(|c| c.ceremony_index).collect(),
+		suspicious_pairs,
+		clusters,
+	}
+}
+
+/// Probability that random assignment puts two attendees of `ceremony` into the same meetup.
+fn co_attendance_probability<AccountId>(ceremony: &CeremonyAttestations<AccountId>) -> BalanceType {
+	BalanceType::from_num(1) / BalanceType::from_num(ceremony.meetup_count.max(1))
+}
+
+/// Groups the accounts of `pairs` into connected components.
+fn clusters<AccountId: Ord + Clone>(pairs: &[SuspiciousPair<AccountId>]) -> Vec<Vec<AccountId>> {
+	let mut cluster_of: BTreeMap<&AccountId, usize> = BTreeMap::new();
+	let mut clusters: Vec<BTreeSet<&AccountId>> = Vec::new();
+
+	for pair in pairs {
+		let (a, b) = (&pair.accounts.0, &pair.accounts.1);
+		match (cluster_of.get(a).copied(), cluster_of.get(b).copied()) {
+			(Some(i), Some(j)) if i == j => {},
+			(Some(i), Some(j)) => {
+				// merge the smaller cluster into the larger one
+				let (keep, merge) =
+					if clusters[i].len() < clusters[j].len() { (j, i) } else { (i, j) };
+				let merged = core::mem::take(&mut clusters[merge]);
+				for account in merged.iter() {
+					cluster_of.insert(account, keep);
+				}
+				clusters[keep].extend(merged);
+			},
+			(Some(i), None) => {
+				clusters[i].insert(b);
+				cluster_of.insert(b, i);
+			},
+			(None, Some(j)) => {
+				clusters[j].insert(a);
+				cluster_of.insert(a, j);
+			},
+			(None, None) => {
+				cluster_of.insert(a, clusters.len());
+				cluster_of.insert(b, clusters.len());
+				clusters.push([a, b].into_iter().collect());
+			},
+		}
+	}
+
+	let mut clusters: Vec<Vec<AccountId>> = clusters
+		.into_iter()
+		.filter(|c| !c.is_empty())
+		.map(|c| c.into_iter().cloned().collect())
+		.collect();
+	clusters.sort();
+	clusters
+}
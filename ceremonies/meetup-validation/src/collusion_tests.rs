@@ -0,0 +1,132 @@
+// Copyright (c) 2019 Alain Brenzikofer
+// This file is part of Encointer
+//
+// Encointer is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Encointer is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Encointer.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::collusion::*;
+use encointer_primitives::{
+	balances::BalanceType,
+	ceremonies::{CeremonyAttestations, CollusionDetectionParams},
+};
+use rstest::*;
+
+/// A ceremony in which all attendees of a meetup attested each other.
+fn ceremony(
+	ceremony_index: u32,
+	meetup_count: u64,
+	meetups: &[&[u32]],
+) -> CeremonyAttestations<u32> {
+	let attestations = meetups
+		.iter()
+		.flat_map(|meetup| {
+			meetup
+				.iter()
+				.map(|a| (*a, meetup.iter().filter(|b| *b != a).copied().collect()))
+		})
+		.collect();
+	CeremonyAttestations { ceremony_index, meetup_count, attestations }
+}
+
+#[test]
+fn group_meeting_at_every_ceremony_is_detected() {
+	let history: Vec<CeremonyAttestations<u32>> = (1..=5)
+		.map(|cindex| {
+			// the others meet with changing partners
+			ceremony(cindex, 8, &[&[1, 2, 3], &[10 + cindex, 20 + cindex]])
+		})
+		.collect();
+
+	let report = detect_collusion(&history, &CollusionDetectionParams::default());
+
+	assert_eq!(report.analyzed_ceremonies, vec![1, 2, 3, 4, 5]);
+	let pairs: Vec<(u32, u32)> = report.suspicious_pairs.iter().map(|p| p.accounts).collect();
+	assert_eq!(pairs, vec![(1, 2), (1, 3), (2, 3)]);
+	let pair = &report.suspicious_pairs[0];
+	assert_eq!(pair.co_attendances, 5);
+	assert_eq!(pair.shared_ceremonies, 5);
+	assert_eq!(pair.expected_co_attendances, BalanceType::from_num(0.625));
+	assert_eq!(report.clusters, vec![vec![1, 2, 3]]);
+}
+
+#[rstest(
+	meetup_count,
+	ceremonies,
+	min_co_attendances,
+	suspicious,
+	case(10, 5, 3, true),
+	case(10, 2, 3, false),
+	case(1, 5, 3, false),
+	case(2, 5, 3, false),
+	case(4, 5, 3, true),
+	case(4, 5, 6, false)
+)]
+fn co_attendance_is_compared_with_random_assignment(
+	meetup_count: u64,
+	ceremonies: u32,
+	min_co_attendances: u32,
+	suspicious: bool,
+) {
+	let history: Vec<CeremonyAttestations<u32>> = (1..=ceremonies)
+		.map(|cindex| ceremony(cindex, meetup_count, &[&[1, 2]]))
+		.collect();
+	let params =
+		CollusionDetectionParams { min_co_attendances, min_excess_ratio: BalanceType::from_num(3) };
+
+	let report = detect_collusion(&history, &params);
+
+	assert_eq!(report.suspicious_pairs.is_empty(), !suspicious);
+	assert_eq!(report.clusters.is_empty(), !suspicious);
+}
+
+#[test]
+fn one_sided_attestations_are_no_co_attendance() {
+	let history: Vec<CeremonyAttestations<u32>> = (1..=5)
+		.map(|cindex| CeremonyAttestations {
+			ceremony_index: cindex,
+			meetup_count: 10,
+			attestations: vec![(1, vec![2]), (2, vec![3]), (3, vec![2])],
+		})
+		.collect();
+
+	let report = detect_collusion(&history, &CollusionDetectionParams::default());
+
+	let pairs: Vec<(u32, u32)> = report.suspicious_pairs.iter().map(|p| p.accounts).collect();
+	assert_eq!(pairs, vec![(2, 3)]);
+}
+
+#[test]
+fn expected_co_attendances_only_count_shared_ceremonies() {
+	let mut history: Vec<CeremonyAttestations<u32>> =
+		(1..=4).map(|cindex| ceremony(cindex, 4, &[&[1, 2]])).collect();
+	// 2 did not attend the last ceremony, so it does not add to the expected co-attendances
+	history.push(ceremony(5, 4, &[&[1, 3]]));
+
+	let report = detect_collusion(&history, &CollusionDetectionParams::default());
+
+	let pair = report.suspicious_pairs.iter().find(|p| p.accounts == (1, 2)).unwrap();
+	assert_eq!(pair.shared_ceremonies, 4);
+	assert_eq!(pair.expected_co_attendances, BalanceType::from_num(1));
+}
+
+#[test]
+fn clusters_connected_by_suspicious_pairs_are_merged() {
+	let history: Vec<CeremonyAttestations<u32>> = (1..=3)
+		.map(|cindex| ceremony(cindex, 20, &[&[1, 4], &[2, 3], &[5, 6]]))
+		.chain((4..=6).map(|cindex| ceremony(cindex, 20, &[&[3, 4], &[5, 6]])))
+		.collect();
+
+	let report = detect_collusion(&history, &CollusionDetectionParams::default());
+
+	assert_eq!(report.clusters, vec![vec![1, 2, 3, 4], vec![5, 6]]);
+}
@@ -25,7 +25,9 @@
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
-use sp_std::{vec, vec::Vec};
+use sp_std::{cmp::max, vec, vec::Vec};
+
+pub mod collusion;
 
 type ParticipantIndex = usize;
 type Participants = Vec<ParticipantIndex>;
@@ -34,11 +36,118 @@ type Attestations = Vec<Vec<ParticipantIndex>>;
 //			b are the participants that have those number of attestations
 type ParticipantGroup = (usize, Participants);
 
+/// The vote of a single participant never weighs more than this, so that a single veteran can't
+/// outvote several other participants.
+pub const MAX_VOTE_WEIGHT: u32 = 2;
+
+/// The rules by which the votes and attestations of a meetup are judged.
+pub trait AttestationPolicy {
+	/// Returns the minimal number of incoming and outgoing attestations a participant needs,
+	/// given the number of participants which are still considered legit.
+	fn attestation_threshold(&self, num_participants: usize) -> usize;
+
+	/// Returns true if a vote which `vote_count` out of `num_voters` participants agree on may be
+	/// used as the confirmed number of participants.
+	fn vote_is_dependable(&self, vote_count: u32, _num_voters: usize) -> bool {
+		vote_count >= 3
+	}
+}
+
+/// Any threshold function can serve as a policy with the default majority rules.
+impl AttestationPolicy for fn(usize) -> usize {
+	fn attestation_threshold(&self, num_participants: usize) -> usize {
+		self(num_participants)
+	}
+}
+
+/// Tolerates a missing attestation or two, depending on the meetup size.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct DefaultAttestationPolicy;
+
+impl AttestationPolicy for DefaultAttestationPolicy {
+	fn attestation_threshold(&self, num_participants: usize) -> usize {
+		let n = num_participants;
+		max(if n > 5 { n.saturating_sub(2) } else { n.saturating_sub(1) }, 1)
+	}
+}
+
+/// Requires the attestation graph among legit participants to be complete.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct FullGraphAttestationPolicy;
+
+impl AttestationPolicy for FullGraphAttestationPolicy {
+	fn attestation_threshold(&self, num_participants: usize) -> usize {
+		max(num_participants.saturating_sub(1), 1)
+	}
+}
+
+/// Only requires attestations from half of the other participants. Meant for meetups which take
+/// place online, where participants may lose their connection before attesting everybody.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct LenientAttestationPolicy;
+
+impl AttestationPolicy for LenientAttestationPolicy {
+	fn attestation_threshold(&self, num_participants: usize) -> usize {
+		max(num_participants / 2, 1)
+	}
+}
+
+/// Selects one of the attestation policies, e.g. to configure it per community.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum AttestationPolicyKind {
+	Default,
+	FullGraph,
+	Lenient,
+}
+
+impl AttestationPolicyKind {
+	pub fn policy(&self) -> &'static dyn AttestationPolicy {
+		match self {
+			AttestationPolicyKind::Default => &DefaultAttestationPolicy,
+			AttestationPolicyKind::FullGraph => &FullGraphAttestationPolicy,
+			AttestationPolicyKind::Lenient => &LenientAttestationPolicy,
+		}
+	}
+}
+
 pub fn get_participant_judgements(
 	participants: &Participants,
 	participant_votes: &Vec<u32>,
 	participant_attestations: &Attestations,
 	attestation_threshold_fn: fn(usize) -> usize,
+) -> Result<ParticipantJudgements, MeetupValidationError> {
+	get_weighted_participant_judgements(
+		participants,
+		participant_votes,
+		&vec![1; participant_votes.len()],
+		participant_attestations,
+		&attestation_threshold_fn,
+	)
+}
+
+/// Like `get_participant_judgements`, but the votes of the participants are weighted by
+/// `participant_vote_weights`, capped at `MAX_VOTE_WEIGHT`, when finding the majority vote, and
+/// the meetup is judged by `policy`.
+pub fn get_weighted_participant_judgements(
+	participants: &Participants,
+	participant_votes: &Vec<u32>,
+	participant_vote_weights: &Vec<u32>,
+	participant_attestations: &Attestations,
+	policy: &dyn AttestationPolicy,
 ) -> Result<ParticipantJudgements, MeetupValidationError> {
 	let mut participant_judgements = ParticipantJudgements {
 		legit: participants.clone(),
@@ -50,8 +159,12 @@ pub fn get_participant_judgements(
 		participant_votes,
 	)?);
 
-	let (n_confirmed, _num_votes, vote_is_unanimous) =
-		find_majority_vote(&participant_judgements.legit, participant_votes)?;
+	let (n_confirmed, _num_votes, vote_is_unanimous) = find_majority_vote(
+		&participant_judgements.legit,
+		participant_votes,
+		participant_vote_weights,
+		policy,
+	)?;
 
 	participant_judgements.exclude_participants(get_excluded_participants_wrong_vote(
 		&participant_judgements.legit,
@@ -71,7 +184,7 @@ pub fn get_participant_judgements(
 	participant_judgements.exclude_participants(get_excluded_participants_num_attestations(
 		&participant_judgements.legit,
 		participant_attestations.clone(),
-		attestation_threshold_fn,
+		policy,
 	)?);
 
 	participant_judgements.early_rewards_possible = early_rewards_possible;
@@ -174,7 +287,7 @@ fn get_excluded_participants_wrong_vote(
 fn get_excluded_participants_num_attestations(
 	participants: &Participants,
 	participant_attestations: Attestations,
-	threshold_fn: fn(usize) -> usize,
+	policy: &dyn AttestationPolicy,
 ) -> Result<Vec<(usize, ExclusionReason)>, MeetupValidationError> {
 	let mut relevant_attestations = filter_attestations(participants, participant_attestations);
 
@@ -212,13 +325,17 @@ fn get_excluded_participants_num_attestations(
 			ExclusionReason,
 		)> = None;
 		if min_num_incoming_attestations < min_num_outgoing_attestations {
-			if min_num_incoming_attestations < threshold_fn(participants_to_process.len()) {
+			if min_num_incoming_attestations <
+				policy.attestation_threshold(participants_to_process.len())
+			{
 				maybe_participants_to_exclude_with_reason = Some((
 					&participants_grouped_by_incoming_attestations.get_or_err(0)?.1,
 					ExclusionReason::TooFewIncomingAttestations,
 				));
 			}
-		} else if min_num_outgoing_attestations < threshold_fn(participants_to_process.len()) {
+		} else if min_num_outgoing_attestations <
+			policy.attestation_threshold(participants_to_process.len())
+		{
 			maybe_participants_to_exclude_with_reason = Some((
 				&participants_grouped_by_outgoing_attestations.get_or_err(0)?.1,
 				ExclusionReason::TooFewOutgoingAttestations,
@@ -245,29 +362,39 @@ fn get_excluded_participants_num_attestations(
 	Ok(excluded_participants)
 }
 
+/// Finds the vote with the highest total weight among the votes which `policy` considers
+/// dependable.
 fn find_majority_vote(
 	participants: &Participants,
 	participant_votes: &Vec<u32>,
+	participant_vote_weights: &Vec<u32>,
+	policy: &dyn AttestationPolicy,
 ) -> Result<(u32, u32, bool), MeetupValidationError> {
-	let mut n_vote_candidates: Vec<(u32, u32)> = vec![];
+	// (vote, count, total weight)
+	let mut n_vote_candidates: Vec<(u32, u32, u32)> = vec![];
 	for i in participants {
 		let this_vote = participant_votes.get_or_err(*i)?;
-		match n_vote_candidates.iter().position(|&(n, _c)| n == *this_vote) {
-			Some(idx) => n_vote_candidates[idx].1 = n_vote_candidates.get_or_err(idx)?.1 + 1,
-			_ => n_vote_candidates.insert(0, (*this_vote, 1)),
+		let this_weight = participant_vote_weights.get_or_err(*i)?.min(&MAX_VOTE_WEIGHT);
+		match n_vote_candidates.iter().position(|&(n, _c, _w)| n == *this_vote) {
+			Some(idx) => {
+				let (_, count, weight) = n_vote_candidates.get_or_err(idx)?;
+				n_vote_candidates[idx] =
+					(*this_vote, count + 1, weight.saturating_add(*this_weight));
+			},
+			_ => n_vote_candidates.insert(0, (*this_vote, 1, *this_weight)),
 		};
 	}
 
 	if n_vote_candidates.is_empty() {
 		return Err(MeetupValidationError::BallotEmpty);
 	}
-	// sort by descending vote count
-	n_vote_candidates.sort_by(|a, b| b.1.cmp(&a.1));
-	if n_vote_candidates.get_or_err(0)?.1 < 3 {
-		return Err(MeetupValidationError::NoDependableVote);
-	}
-	let (n_confirmed, vote_count) = n_vote_candidates.get_or_err(0)?;
 	let vote_is_unanimous = n_vote_candidates.len() == 1;
+	// a vote is only dependable if enough participants agree on it, no matter their weight
+	n_vote_candidates.retain(|c| policy.vote_is_dependable(c.1, participants.len()));
+	// sort by descending vote weight and count
+	n_vote_candidates.sort_by(|a, b| b.2.cmp(&a.2).then(b.1.cmp(&a.1)));
+	let (n_confirmed, vote_count, _) =
+		n_vote_candidates.first().ok_or(MeetupValidationError::NoDependableVote)?;
 	Ok((*n_confirmed, *vote_count, vote_is_unanimous))
 }
 
@@ -424,3 +551,6 @@ mod tests;
 
 #[cfg(test)]
 mod meetup_scenario_tests;
+
+#[cfg(test)]
+mod collusion_tests;
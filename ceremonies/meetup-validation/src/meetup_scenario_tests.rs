@@ -388,3 +388,117 @@ fn n_honest_participants_dont_have_internet_and_n_attackers_try_to_provoke_early
 
 	assert!(!early_rewards_possible);
 }
+
+#[rstest(
+	policy,
+	num_legit_participants,
+	case(&DefaultAttestationPolicy, 8),
+	case(&FullGraphAttestationPolicy, 7),
+	case(&LenientAttestationPolicy, 8)
+)]
+fn one_participant_forgets_to_attest_another_one(
+	policy: &dyn AttestationPolicy,
+	num_legit_participants: usize,
+) {
+	let meetup_size = 8;
+	let participants: Participants = (0..meetup_size).collect();
+	let participant_votes = vec![meetup_size as u32; meetup_size];
+	let participant_attestations: Attestations = (0..meetup_size)
+		.map(|i| (0..meetup_size).filter(|&j| j != i && !(i == 0 && j == 1)).collect())
+		.collect();
+
+	let legit_participants = get_weighted_participant_judgements(
+		&participants,
+		&participant_votes,
+		&vec![1; meetup_size],
+		&participant_attestations,
+		policy,
+	)
+	.unwrap()
+	.legit;
+	assert_eq!(legit_participants.len(), num_legit_participants);
+	// only the forgetful participant may be excluded
+	assert!(legit_participants.contains(&1));
+}
+
+#[rstest(
+	policy,
+	num_legit_participants,
+	case(&DefaultAttestationPolicy, 0),
+	case(&FullGraphAttestationPolicy, 0),
+	case(&LenientAttestationPolicy, 8)
+)]
+fn online_participants_attest_half_of_the_meetup(
+	policy: &dyn AttestationPolicy,
+	num_legit_participants: usize,
+) {
+	let meetup_size = 8;
+	let participants: Participants = (0..meetup_size).collect();
+	let participant_votes = vec![meetup_size as u32; meetup_size];
+	// everybody attests the next four participants
+	let participant_attestations: Attestations = (0..meetup_size)
+		.map(|i| (1..=4).map(|k| (i + k) % meetup_size).collect())
+		.collect();
+
+	let legit_participants = get_weighted_participant_judgements(
+		&participants,
+		&participant_votes,
+		&vec![1; meetup_size],
+		&participant_attestations,
+		policy,
+	)
+	.unwrap()
+	.legit;
+	assert_eq!(legit_participants.len(), num_legit_participants);
+}
+
+#[rstest(
+	policy,
+	case(&DefaultAttestationPolicy),
+	case(&FullGraphAttestationPolicy),
+	case(&LenientAttestationPolicy)
+)]
+fn confirmed_vote_is_backed_by_a_minority(policy: &dyn AttestationPolicy) {
+	let meetup_size = 8;
+	let participants: Participants = (0..meetup_size).collect();
+	let participant_votes = vec![3, 3, 3, 2, 2, 1, 1, 0];
+	let participant_attestations: Attestations = (0..meetup_size)
+		.map(|i| (0..meetup_size - 1).filter(|&j| j != i).collect())
+		.collect();
+
+	let result = get_weighted_participant_judgements(
+		&participants,
+		&participant_votes,
+		&vec![1; meetup_size],
+		&participant_attestations,
+		policy,
+	);
+	// the plurality vote suffices, as long as at least three participants agree on it
+	assert!(result.is_ok());
+}
+
+#[test]
+fn lenient_policy_excludes_participants_attested_by_less_than_half_of_the_meetup() {
+	let meetup_size = 8;
+	let participants: Participants = (0..meetup_size).collect();
+	let participant_votes = vec![meetup_size as u32; meetup_size];
+	// participant 7 only attests and is attested by participants 0, 1 and 2
+	let participant_attestations: Attestations = (0..meetup_size)
+		.map(|i| match i {
+			7 => vec![0, 1, 2],
+			0..=2 => (0..meetup_size).filter(|&j| j != i).collect(),
+			_ => (0..meetup_size - 1).filter(|&j| j != i).collect(),
+		})
+		.collect();
+
+	let legit_participants = get_weighted_participant_judgements(
+		&participants,
+		&participant_votes,
+		&vec![1; meetup_size],
+		&participant_attestations,
+		AttestationPolicyKind::Lenient.policy(),
+	)
+	.unwrap()
+	.legit;
+	assert_eq!(legit_participants, (0..meetup_size - 1).collect::<Participants>());
+}
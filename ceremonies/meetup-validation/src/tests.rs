@@ -100,11 +100,12 @@ fn get_excluded_participants_num_attestations_works() {
 		(3, ExclusionReason::TooFewIncomingAttestations),
 		(1, ExclusionReason::TooFewOutgoingAttestations),
 	];
+	let attestation_threshold_fn: fn(usize) -> usize = |n| n - 1;
 	assert_eq!(
 		get_excluded_participants_num_attestations(
 			&participants,
 			participant_attestations,
-			|n| n - 1
+			&attestation_threshold_fn
 		)
 		.unwrap(),
 		excluded_participants
@@ -115,14 +116,88 @@ fn get_excluded_participants_num_attestations_works() {
 fn find_majority_vote_works() {
 	let participants: Participants = vec![0, 1, 2, 3, 4];
 	let participant_votes: Vec<u32> = vec![1, 1, 2, 3, 1];
-	assert_eq!(find_majority_vote(&participants, &participant_votes), Ok((1u32, 3u32, false)));
+	assert_eq!(
+		find_majority_vote(
+			&participants,
+			&participant_votes,
+			&vec![1; 5],
+			&DefaultAttestationPolicy
+		),
+		Ok((1u32, 3u32, false))
+	);
 }
 
 #[test]
 fn find_majority_vote_works_with_unanimous_vote() {
 	let participants: Participants = vec![0, 1, 2, 3, 4];
 	let participant_votes: Vec<u32> = vec![1, 1, 1, 1, 1];
-	assert_eq!(find_majority_vote(&participants, &participant_votes), Ok((1u32, 5u32, true)));
+	assert_eq!(
+		find_majority_vote(
+			&participants,
+			&participant_votes,
+			&vec![1; 5],
+			&DefaultAttestationPolicy
+		),
+		Ok((1u32, 5u32, true))
+	);
+}
+
+#[test]
+fn find_majority_vote_prefers_heavier_vote() {
+	let participants: Participants = (0..7).collect();
+	let participant_votes: Vec<u32> = vec![3, 3, 3, 4, 4, 4, 4];
+	let participant_vote_weights: Vec<u32> = vec![3, 2, 2, 1, 1, 1, 1];
+	assert_eq!(
+		find_majority_vote(
+			&participants,
+			&participant_votes,
+			&participant_vote_weights,
+			&DefaultAttestationPolicy
+		),
+		Ok((3u32, 3u32, false))
+	);
+}
+
+#[test]
+fn find_majority_vote_caps_vote_weight() {
+	let participants: Participants = (0..7).collect();
+	let participant_votes: Vec<u32> = vec![3, 3, 3, 4, 4, 4, 4];
+	let participant_vote_weights: Vec<u32> = vec![10, 1, 1, 1, 1, 1, 1];
+	// the heavy voter weighs as much as two light ones, so the vote of more participants wins
+	assert_eq!(
+		find_majority_vote(
+			&participants,
+			&participant_votes,
+			&participant_vote_weights,
+			&DefaultAttestationPolicy
+		),
+		Ok((4u32, 4u32, false))
+	);
+}
+
+#[test]
+fn find_majority_vote_ignores_weight_of_undependable_vote() {
+	let participants: Participants = (0..6).collect();
+	let participant_votes: Vec<u32> = vec![2, 2, 4, 4, 4, 4];
+	let participant_vote_weights: Vec<u32> = vec![5, 5, 1, 1, 1, 1];
+	assert_eq!(
+		find_majority_vote(
+			&participants,
+			&participant_votes,
+			&participant_vote_weights,
+			&DefaultAttestationPolicy
+		),
+		Ok((4u32, 4u32, false))
+	);
+	assert_eq!(
+		find_majority_vote(
+			&participants[..4].to_vec(),
+			&participant_votes,
+			&participant_vote_weights,
+			&DefaultAttestationPolicy
+		),
+		Err(MeetupValidationError::NoDependableVote)
+	);
 }
 
 #[test]
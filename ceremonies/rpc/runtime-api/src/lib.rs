@@ -22,19 +22,32 @@
 use sp_std::vec::Vec;
 
 use encointer_primitives::{
-	ceremonies::{AggregatedAccountData, CeremonyIndexType, CeremonyInfo, CommunityReputation},
+	ceremonies::{
+		AggregatedAccountData, CeremonyIndexType, CeremonyInfo, CeremonyStats,
+		CollusionDetectionParams, CollusionReport, CommunityReputation, MeetupAssignment,
+		MeetupIndexType,
+	},
 	communities::CommunityIdentifier,
 };
 use parity_scale_codec::{Decode, Encode};
 
 sp_api::decl_runtime_apis! {
+	/// Version 2 adds `get_meetup_assignment`, `get_meetup_participants`, `get_collusion_report`
+	/// and `get_ceremony_stats`.
+	#[api_version(2)]
 	pub trait CeremoniesApi<AccountId, Moment>
 	where AccountId: Encode + Decode,
-	encointer_primitives::ceremonies::AggregatedAccountData<AccountId, Moment>: Decode
+	encointer_primitives::ceremonies::AggregatedAccountData<AccountId, Moment>: Decode,
+	encointer_primitives::ceremonies::MeetupAssignment<AccountId, Moment>: Decode,
+	encointer_primitives::ceremonies::CollusionReport<AccountId>: Decode
 
 	{
 		fn get_reputations(account: &AccountId) -> Vec<(CeremonyIndexType, CommunityReputation)>;
 		fn get_aggregated_account_data(cid:CommunityIdentifier, account: &AccountId) -> AggregatedAccountData<AccountId, Moment>;
 		fn get_ceremony_info() -> CeremonyInfo;
+		fn get_meetup_assignment(cid: CommunityIdentifier, account: &AccountId) -> Option<MeetupAssignment<AccountId, Moment>>;
+		fn get_meetup_participants(cid: CommunityIdentifier, meetup_index: MeetupIndexType) -> Vec<AccountId>;
+		fn get_collusion_report(cid: CommunityIdentifier, from_cindex: CeremonyIndexType, to_cindex: CeremonyIndexType, params: CollusionDetectionParams) -> CollusionReport<AccountId>;
+		fn get_ceremony_stats(cid: CommunityIdentifier, cindex: CeremonyIndexType) -> Option<CeremonyStats>;
 	}
 }
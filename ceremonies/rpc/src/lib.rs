@@ -27,7 +27,8 @@ use std::sync::Arc;
 use encointer_primitives::{
 	ceremonies::{
 		reputation_cache_dirty_key, reputation_cache_key, AggregatedAccountData, CeremonyInfo,
-		CommunityReputation, ReputationCacheValue,
+		CeremonyStats, CollusionDetectionParams, CollusionReport, CommunityReputation,
+		MeetupAssignment, MeetupIndexType, ReputationCacheValue,
 	},
 	communities::CommunityIdentifier,
 	scheduler::CeremonyIndexType,
@@ -57,6 +58,40 @@ where
 		account: AccountId,
 		at: Option<BlockHash>,
 	) -> RpcResult<AggregatedAccountData<AccountId, Moment>>;
+
+	#[method(name = "encointer_getMeetupAssignment", blocking)]
+	fn get_meetup_assignment(
+		&self,
+		cid: CommunityIdentifier,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<MeetupAssignment<AccountId, Moment>>>;
+
+	#[method(name = "encointer_getMeetupParticipants", blocking)]
+	fn get_meetup_participants(
+		&self,
+		cid: CommunityIdentifier,
+		meetup_index: MeetupIndexType,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<AccountId>>;
+
+	#[method(name = "encointer_getCollusionReport", blocking)]
+	fn get_collusion_report(
+		&self,
+		cid: CommunityIdentifier,
+		from_cindex: CeremonyIndexType,
+		to_cindex: CeremonyIndexType,
+		params: Option<CollusionDetectionParams>,
+		at: Option<BlockHash>,
+	) -> RpcResult<CollusionReport<AccountId>>;
+
+	#[method(name = "encointer_getCeremonyStats", blocking)]
+	fn get_ceremony_stats(
+		&self,
+		cid: CommunityIdentifier,
+		cindex: CeremonyIndexType,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<CeremonyStats>>;
 }
 
 pub struct CeremoniesRpc<Client, Block, AccountId, Moment, S> {
@@ -76,6 +111,8 @@ where
 	Client: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
 	Client::Api: CeremoniesRuntimeApi<Block, AccountId, Moment>,
 	encointer_primitives::ceremonies::AggregatedAccountData<AccountId, Moment>: Decode,
+	encointer_primitives::ceremonies::MeetupAssignment<AccountId, Moment>: Decode,
+	encointer_primitives::ceremonies::CollusionReport<AccountId>: Decode,
 {
 	/// Create new `Ceremonies` instance with the given reference to the client.
 	pub fn new(client: Arc<Client>, storage: S, offchain_indexing: bool) -> Self {
@@ -147,6 +184,8 @@ where
 	Client::Api: CeremoniesRuntimeApi<Block, AccountId, Moment>,
 	S: 'static + OffchainStorage,
 	encointer_primitives::ceremonies::AggregatedAccountData<AccountId, Moment>: Decode,
+	encointer_primitives::ceremonies::MeetupAssignment<AccountId, Moment>: Decode,
+	encointer_primitives::ceremonies::CollusionReport<AccountId>: Decode,
 {
 	fn get_reputations(
 		&self,
@@ -191,4 +230,56 @@ where
 			.get_aggregated_account_data(at, cid, &account)
 			.map_err(|e| Error::Runtime(e.into()))?)
 	}
+
+	fn get_meetup_assignment(
+		&self,
+		cid: CommunityIdentifier,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<MeetupAssignment<AccountId, Moment>>> {
+		let api = self.client.runtime_api();
+		let at = self.resolve_at(at);
+		Ok(api
+			.get_meetup_assignment(at, cid, &account)
+			.map_err(|e| Error::Runtime(e.into()))?)
+	}
+
+	fn get_meetup_participants(
+		&self,
+		cid: CommunityIdentifier,
+		meetup_index: MeetupIndexType,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<AccountId>> {
+		let api = self.client.runtime_api();
+		let at = self.resolve_at(at);
+		Ok(api
+			.get_meetup_participants(at, cid, meetup_index)
+			.map_err(|e| Error::Runtime(e.into()))?)
+	}
+
+	fn get_collusion_report(
+		&self,
+		cid: CommunityIdentifier,
+		from_cindex: CeremonyIndexType,
+		to_cindex: CeremonyIndexType,
+		params: Option<CollusionDetectionParams>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<CollusionReport<AccountId>> {
+		let api = self.client.runtime_api();
+		let at = self.resolve_at(at);
+		Ok(api
+			.get_collusion_report(at, cid, from_cindex, to_cindex, params.unwrap_or_default())
+			.map_err(|e| Error::Runtime(e.into()))?)
+	}
+
+	fn get_ceremony_stats(
+		&self,
+		cid: CommunityIdentifier,
+		cindex: CeremonyIndexType,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<CeremonyStats>> {
+		let api = self.client.runtime_api();
+		let at = self.resolve_at(at);
+		Ok(api.get_ceremony_stats(at, cid, cindex).map_err(|e| Error::Runtime(e.into()))?)
+	}
 }
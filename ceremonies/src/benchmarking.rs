@@ -83,6 +83,7 @@ fn create_community<T: Config>() -> CommunityIdentifier {
 		CommunityMetadata::default(),
 		None,
 		None,
+		None,
 	)
 	.ok();
 	CommunityIdentifier::new(location, bs).unwrap()
@@ -223,6 +224,50 @@ benchmarks! {
 		);
 	}
 
+	register_participant_with_proofs {
+		let cid = create_community::<T>();
+		let n = T::MaxRegistrationProofs::get().min(Pallet::<T>::reputation_lifetime());
+		// go to a ceremony with enough ceremonies to prove within the reputation lifetime
+		while pallet_encointer_scheduler::Pallet::<T>::current_ceremony_index() <= n {
+			let cindex = pallet_encointer_scheduler::Pallet::<T>::current_ceremony_index();
+			IssuedRewards::<T>::insert((cid, cindex), 0, MeetupResult::Ok);
+			next_phase::<T>();
+			next_phase::<T>();
+			next_phase::<T>();
+		}
+		let cindex = pallet_encointer_scheduler::Pallet::<T>::current_ceremony_index();
+
+		let zoran = generate_pair();
+		let zoran_account = account_id::<T>(&zoran);
+		assert_ok!(pallet_encointer_balances::Pallet::<T>::issue(
+			cid,
+			&zoran_account,
+			NominalIncome::from_num(1)
+		));
+		let proofs: Vec<_> = (1..=n)
+			.map(|i| {
+				Pallet::<T>::fake_reputation(
+					(cid, cindex - i),
+					&zoran_account,
+					Reputation::VerifiedUnlinked
+				);
+				create_proof_of_attendance::<T>(zoran_account.clone(), cid, cindex - i, &zoran)
+			})
+			.collect();
+
+		// worst case: a reputable with fewer proven attendances has to make room on every level
+		for level in 1..n {
+			let reputable: T::AccountId = account("reputable", level, level);
+			assert_ok!(Pallet::<T>::register_with_proven_attendances(cid, cindex, &reputable, level));
+		}
+		assert_eq!(ReputableCount::<T>::get((cid, cindex)), (n - 1) as u64);
+	}: _(RawOrigin::Signed(zoran_account.clone()), cid, BoundedVec::truncate_from(proofs))
+	verify {
+		assert_eq!(ReputableCount::<T>::get((cid, cindex)), n as u64);
+		assert_eq!(ReputableIndex::<T>::get((cid, cindex), &zoran_account), 1);
+		assert_eq!(ProvenAttendances::<T>::get((cid, cindex), zoran_account).len() as u32, n);
+	}
+
 	upgrade_registration {
 		let cid = create_community::<T>();
 
@@ -303,6 +348,70 @@ benchmarks! {
 	}
 
 
+	attest_claims {
+		// the meetup of the attestor has 9 other participants
+		let n in 1 .. 9;
+		let cid = create_community::<T>();
+
+		let attestor = generate_pair();
+		let attestor_account = account_id::<T>(&attestor);
+
+		assert_ok!(Pallet::<T>::register_participant(
+			RawOrigin::Signed(attestor_account.clone()).into(),
+			cid,
+			Some(fake_last_attendance_and_get_proof::<T>(&attestor, cid)))
+		);
+
+		let attestees = register_users::<T>(cid, 2, 7);
+
+		next_phase::<T>();
+		next_phase::<T>();
+
+		let cindex = pallet_encointer_scheduler::Pallet::<T>::current_ceremony_index();
+		let mindex = 1;
+		let location = test_location();
+		let time = crate::Pallet::<T>::get_meetup_time(cid, location).expect("Could not get meetup time");
+
+		let claims = BoundedVec::try_from(attestees.iter().take(n as usize).map(|attestee| {
+			let mut claim = ClaimOfAttendanceOf::<T>::new_unsigned(
+				account_id::<T>(attestee),
+				cindex,
+				cid,
+				mindex,
+				location,
+				time,
+				10,
+			);
+			claim.claimant_signature = Some(T::Signature::from(sign(attestee, &claim.payload_encoded())));
+			claim
+		}).collect::<Vec<_>>()).unwrap();
+
+	}: _(RawOrigin::Signed(attestor_account.clone()), cid, 10, claims)
+	verify {
+		assert_eq!(AttestationCount::<T>::get((cid, cindex)), 1);
+		assert_eq!(MeetupParticipantCountVote::<T>::get((cid, cindex), &attestor_account), 10);
+	}
+
+	set_preferred_region {
+		let cid = create_community::<T>();
+
+		let zoran = generate_pair();
+		let zoran_account = account_id::<T>(&zoran);
+		let proof = fake_last_attendance_and_get_proof::<T>(&zoran, cid);
+		let cindex = pallet_encointer_scheduler::Pallet::<T>::current_ceremony_index();
+
+		assert_ok!(Pallet::<T>::register_participant(
+			RawOrigin::Signed(zoran_account.clone()).into(),
+			cid,
+			Some(proof)
+		));
+		let location = test_location();
+		let region = GeoHash::try_from_params(location.lat, location.lon).unwrap();
+	}: _(RawOrigin::Signed(zoran_account.clone()), cid, Some(region.clone()))
+	verify {
+		assert_eq!(PreferredRegion::<T>::get((cid, cindex), zoran_account), Some(region));
+	}
+
 	endorse_newcomer {
 		let cid = create_community::<T>();
 		let cindex = pallet_encointer_scheduler::Pallet::<T>::current_ceremony_index();
@@ -331,6 +440,53 @@ benchmarks! {
 		assert_eq!(<EndorseesCount<T>>::get((cid, cindex)), 1);
 	}
 
+	delegate_newbie_tickets {
+		let cid = create_community::<T>();
+		let cindex = pallet_encointer_scheduler::Pallet::<T>::current_ceremony_index();
+
+		let zoran = account_id::<T>(&generate_pair());
+		let yuri = account_id::<T>(&generate_pair());
+		Pallet::<T>::fake_reputation((cid, cindex - 1), &zoran, Reputation::VerifiedUnlinked);
+		Pallet::<T>::fake_reputation((cid, cindex - 1), &yuri, Reputation::VerifiedUnlinked);
+	}: _(RawOrigin::Signed(zoran.clone()), cid, yuri.clone(), 1)
+	verify {
+		assert_eq!(<DelegatedNewbieTickets<T>>::get((cid, cindex), (zoran, yuri)), 1);
+	}
+
+	endorse_newcomer_with_delegated_ticket {
+		let cid = create_community::<T>();
+		let cindex = pallet_encointer_scheduler::Pallet::<T>::current_ceremony_index();
+
+		let zoran = account_id::<T>(&generate_pair());
+		let yuri = account_id::<T>(&generate_pair());
+		Pallet::<T>::fake_reputation((cid, cindex - 1), &zoran, Reputation::VerifiedUnlinked);
+		Pallet::<T>::fake_reputation((cid, cindex - 1), &yuri, Reputation::VerifiedUnlinked);
+		assert_ok!(Pallet::<T>::delegate_newbie_tickets(
+			RawOrigin::Signed(zoran.clone()).into(),
+			cid,
+			yuri.clone(),
+			1
+		));
+
+		// issue some income such that newbies are allowed to register
+		assert_ok!(pallet_encointer_balances::Pallet::<T>::issue(
+			cid,
+			&zoran,
+			NominalIncome::from_num(1)
+		));
+
+		let newbie = generate_pair();
+		assert_ok!(Pallet::<T>::register_participant(
+			RawOrigin::Signed(account_id::<T>(&newbie)).into(),
+			cid, None
+		));
+
+		assert_eq!(<EndorseesCount<T>>::get((cid, cindex)), 0);
+	}: _(RawOrigin::Signed(yuri), cid, account_id::<T>(&newbie), zoran)
+	verify {
+		assert_eq!(<EndorseesCount<T>>::get((cid, cindex)), 1);
+	}
+
 	claim_rewards {
 		frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into()); // this is needed to assert events
 		let cid = create_community::<T>();
@@ -341,7 +497,7 @@ benchmarks! {
 
 		let cindex = pallet_encointer_scheduler::Pallet::<T>::current_ceremony_index();
 		let loc = test_location();
-		let time = crate::Pallet::<T>::get_meetup_time(loc).expect("Could not get meetup time");
+		let time = crate::Pallet::<T>::get_meetup_time(cid, loc).expect("Could not get meetup time");
 		let mindex = 1;
 
 		// attest_attendees
@@ -362,6 +518,13 @@ benchmarks! {
 		assert!(IssuedRewards::<T>::contains_key((cid, cindex), mindex));
 	}
 
+	set_attestation_policy {
+		let cid = create_community::<T>();
+	}: _(RawOrigin::Root, cid, Some(AttestationPolicyKind::Lenient))
+	verify {
+		assert_eq!(AttestationPolicyOverride::<T>::get(cid), Some(AttestationPolicyKind::Lenient))
+	}
+
 	set_inactivity_timeout {
 	}: _(RawOrigin::Root, 13)
 	verify {
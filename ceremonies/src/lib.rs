@@ -25,7 +25,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use encointer_ceremonies_assignment::{
-	assignment_fn_inverse, generate_assignment_function_params, get_meetup_location_index,
+	assignment_fn_inverse, compact_indices, generate_assignment_function_params,
 	math::{checked_ceil_division, find_prime_below, find_random_coprime_below},
 	meetup_index, meetup_location, meetup_time,
 };
@@ -33,7 +33,7 @@ use encointer_meetup_validation::*;
 use encointer_primitives::{
 	balances::BalanceType,
 	ceremonies::*,
-	communities::{CommunityIdentifier, Location, NominalIncome},
+	communities::{CommunityIdentifier, CommunityRules, GeoHash, Location, NominalIncome},
 	scheduler::{CeremonyIndexType, CeremonyPhaseType},
 	RandomNumberGenerator,
 };
@@ -41,17 +41,23 @@ use frame_support::{
 	dispatch::{DispatchResult, DispatchResultWithPostInfo, Pays},
 	ensure,
 	traits::{Get, Randomness},
+	weights::Weight,
 	BoundedVec,
 };
 use frame_system::ensure_signed;
 use log::{debug, error, info, trace, warn};
+use pallet_encointer_communities::OnBootstrappersChanged;
 use pallet_encointer_scheduler::OnCeremonyPhaseChange;
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode};
 use scale_info::TypeInfo;
 use sp_core::bounded::BoundedSlice;
-use sp_runtime::traits::{IdentifyAccount, Member, Verify};
+use sp_runtime::{
+	traits::{IdentifyAccount, Member, Saturating, Verify},
+	DispatchError,
+};
 use sp_std::{
 	cmp::{max, min},
+	collections::btree_map::BTreeMap,
 	prelude::*,
 	vec,
 };
@@ -61,6 +67,12 @@ const LOG: &str = "encointer";
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+pub type ClaimOfAttendanceOf<T> = ClaimOfAttendance<
+	<T as Config>::Signature,
+	<T as frame_system::Config>::AccountId,
+	<T as pallet_timestamp::Config>::Moment,
+>;
+
 mod storage_helper;
 #[allow(clippy::unused_unit)]
 #[frame_support::pallet]
@@ -100,13 +112,36 @@ pub mod pallet {
 		// Divisor used to determine the ratio of newbies allowed in relation to other participants
 		#[pallet::constant]
 		type MeetupNewbieLimitDivider: Get<u64>;
+		// Minimum number of meetups the participants preferring a region must fill for the region
+		// to be partitioned off
+		#[pallet::constant]
+		type MinRegionalMeetupCount: Get<MeetupIndexType>;
 
 		#[pallet::constant]
 		type MaxAttestations: Get<u32>;
 
+		// Factor by which the location and time tolerances are widened for `LoCoFlex` communities
+		#[pallet::constant]
+		type LoCoFlexToleranceFactor: Get<u32>;
+
+		// Maximum number of newbie tickets which can be delegated to a reputable per ceremony
+		#[pallet::constant]
+		type MaxDelegatedNewbieTickets: Get<EndorsementTicketsType>;
+
+		// Maximum number of proofs of attendance a participant can register with
+		#[pallet::constant]
+		type MaxRegistrationProofs: Get<u32>;
+
 		type WeightInfo: WeightInfo;
 	}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_block: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::issue_pending_rewards(remaining_weight)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
@@ -117,75 +152,7 @@ pub mod pallet {
 			proof: Option<ProofOfAttendance<T::Signature, T::AccountId>>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
-			let current_phase = <pallet_encointer_scheduler::Pallet<T>>::current_phase();
-			ensure!(
-				CeremonyPhaseType::is_registering_or_attesting(&current_phase),
-				Error::<T>::RegisteringOrAttestationPhaseRequired
-			);
-
-			ensure!(
-				<pallet_encointer_communities::Pallet<T>>::community_identifiers().contains(&cid),
-				Error::<T>::InexistentCommunity
-			);
-
-			let mut cindex = <pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index();
-
-			if current_phase == CeremonyPhaseType::Attesting {
-				cindex += 1
-			};
-
-			if Self::is_registered(cid, cindex, &sender) {
-				return Err(<Error<T>>::ParticipantAlreadyRegistered.into());
-			}
-
-			if let Some(p) = &proof {
-				// we accept proofs from other communities as well. no need to ensure cid
-				ensure!(sender == p.prover_public, Error::<T>::WrongProofSubject);
-				ensure!(p.ceremony_index < cindex, Error::<T>::ProofAcausal);
-				ensure!(
-					p.ceremony_index >= cindex.saturating_sub(Self::reputation_lifetime()),
-					Error::<T>::ProofOutdated
-				);
-				ensure!(
-					Self::participant_reputation(
-						(p.community_identifier, p.ceremony_index),
-						&p.attendee_public
-					)
-					.is_verified_and_unlinked_for_cindex(cindex),
-					Error::<T>::AttendanceUnverifiedOrAlreadyUsed
-				);
-				if <pallet_encointer_communities::Pallet<T>>::bootstrappers(cid)
-					.contains(&p.attendee_public)
-				{
-					ensure!(
-						p.attendee_public == sender,
-						Error::<T>::BootstrapperReputationIsUntransferrable
-					)
-				}
-				ensure!(p.verify_signature(), Error::<T>::BadProofOfAttendanceSignature);
-
-				// this reputation must now be flagged so it can not be used again in the same cycle
-				<ParticipantReputation<T>>::insert(
-					(p.community_identifier, p.ceremony_index),
-					&p.attendee_public,
-					Reputation::VerifiedLinked(cindex),
-				);
-				// register participant as reputable
-				<ParticipantReputation<T>>::insert(
-					(cid, cindex),
-					&sender,
-					Reputation::UnverifiedReputable,
-				);
-			};
-
-			let participant_type = Self::register(cid, cindex, &sender, proof.is_some())?;
-
-			// invalidate reputation cache
-			sp_io::offchain_index::set(&reputation_cache_dirty_key(&sender), &true.encode());
-
-			debug!(target: LOG, "registered participant: {sender:?} as {participant_type:?}");
-			Self::deposit_event(Event::ParticipantRegistered(cid, participant_type, sender));
-
+			Self::do_register_participant(sender, cid, proof.as_slice())?;
 			Ok(().into())
 		}
 
@@ -300,8 +267,21 @@ pub mod pallet {
 						Ok(())
 					},
 				)?;
+				// refund the other reputation the participant proved when registering
+				for cc in <ProvenAttendances<T>>::take((cid, cindex), &sender) {
+					if Self::participant_reputation(cc, &sender) ==
+						Reputation::VerifiedLinked(cindex)
+					{
+						<ParticipantReputation<T>>::insert(
+							cc,
+							&sender,
+							Reputation::VerifiedUnlinked,
+						);
+					}
+				}
 			}
 			Self::remove_participant_from_registry(cid, cindex, &sender)?;
+			<PreferredRegion<T>>::remove((cid, cindex), &sender);
 
 			Ok(().into())
 		}
@@ -324,34 +304,21 @@ pub mod pallet {
 				<pallet_encointer_communities::Pallet<T>>::community_identifiers().contains(&cid),
 				Error::<T>::InexistentCommunity
 			);
-
-			let (cindex, meetup_index, meetup_participants, _meetup_location, _meetup_time) =
-				Self::gather_meetup_data(&cid, &sender)?;
-
 			ensure!(
-				attestations.len() < meetup_participants.len(),
-				Error::<T>::TooManyAttestations
-			);
-
-			debug!(
-				target: LOG,
-				"{:?} attempts to submit {:?} attestations",
-				sender,
-				attestations.len()
+				!Self::community_rules(&cid).requires_signed_claims(),
+				Error::<T>::SignedClaimsRequired
 			);
 
-			<MeetupParticipantCountVote<T>>::insert(
-				(cid, cindex),
-				&sender,
-				number_of_participants_vote,
-			);
+			let (cindex, meetup_index, meetup_participants, _meetup_location_and_time) =
+				Self::gather_meetup_data_for_rules(&cid, &sender)?;
 
-			Self::add_attestations_to_registry(
+			Self::register_attestations(
 				sender,
 				&cid,
 				cindex,
 				meetup_index,
 				&meetup_participants,
+				number_of_participants_vote,
 				&attestations,
 			)?;
 
@@ -386,21 +353,7 @@ pub mod pallet {
 
 			Self::burn_newbie_tickets(cid, cindex, &sender)?;
 
-			<Endorsees<T>>::insert((cid, cindex), newbie.clone(), ());
-			let new_endorsee_count = Self::endorsee_count((cid, cindex))
-				.checked_add(1)
-				.ok_or(<Error<T>>::RegistryOverflow)?;
-			<EndorseesCount<T>>::insert((cid, cindex), new_endorsee_count);
-
-			if <NewbieIndex<T>>::contains_key((cid, cindex), &newbie) {
-				Self::remove_participant_from_registry(cid, cindex, &newbie)?;
-				Self::register(cid, cindex, &newbie, false)?;
-			}
-
-			debug!(target: LOG, "bootstrapper {sender:?} endorsed newbie: {newbie:?}");
-			Self::deposit_event(Event::EndorsedParticipant(cid, sender, newbie));
-
-			Ok(().into())
+			Self::endorse(cid, cindex, sender, newbie)
 		}
 
 		#[pallet::call_index(5)]
@@ -427,9 +380,6 @@ pub mod pallet {
 					.ok_or(<Error<T>>::ParticipantIsNotRegistered)?,
 			};
 
-			if <IssuedRewards<T>>::contains_key((cid, cindex), meetup_index) {
-				return Err(<Error<T>>::RewardsAlreadyIssued.into());
-			}
 			info!(
 				target: LOG,
 				"validating meetup {:?} for cid {:?} triggered by {:?}",
@@ -437,110 +387,7 @@ pub mod pallet {
 				&cid,
 				participant
 			);
-
-			//gather all data
-			let meetup_participants = Self::get_meetup_participants((cid, cindex), meetup_index)?;
-			let (participant_votes, participant_attestations) =
-				Self::gather_meetup_validation_data(cid, cindex, meetup_participants.clone());
-
-			// initialize an array of local participant indices that are eligible for the reward
-			// indices will be deleted in the following based on various rules
-			let mut participants_eligible_for_rewards: Vec<usize> =
-				(0..meetup_participants.len()).collect();
-
-			let attestation_threshold_fn =
-				|i: usize| max(if i > 5 { i.saturating_sub(2) } else { i.saturating_sub(1) }, 1);
-			let participant_judgements = match get_participant_judgements(
-				&participants_eligible_for_rewards,
-				&participant_votes,
-				&participant_attestations,
-				attestation_threshold_fn,
-			) {
-				Ok(participant_judgements) => participant_judgements,
-				// handle errors
-				Err(err) => {
-					let (error, meetup_result) = match err {
-						MeetupValidationError::BallotEmpty => {
-							debug!(
-								target: LOG,
-								"ballot empty for meetup {meetup_index:?}, cid: {cid:?}"
-							);
-							(
-								Err(<Error<T>>::VotesNotDependable.into()),
-								MeetupResult::VotesNotDependable,
-							)
-						},
-						MeetupValidationError::NoDependableVote => {
-							debug!(
-								target: LOG,
-								"ballot doesn't reach dependable majority for meetup {meetup_index:?}, cid: {cid:?}",
-							);
-							(
-								Err(<Error<T>>::VotesNotDependable.into()),
-								MeetupResult::VotesNotDependable,
-							)
-						},
-						MeetupValidationError::IndexOutOfBounds => {
-							debug!(
-								target: LOG,
-								"index out of bounds for meetup {meetup_index:?}, cid: {cid:?}"
-							);
-							(
-								Err(<Error<T>>::MeetupValidationIndexOutOfBounds.into()),
-								MeetupResult::MeetupValidationIndexOutOfBounds,
-							)
-						},
-					};
-					// only mark issuance as complete in registering phase
-					// because in attesting phase there could be a failing early payout attempt
-					if current_phase == CeremonyPhaseType::Registering {
-						info!(target: LOG, "marking issuance as completed for failed meetup.");
-
-						<IssuedRewards<T>>::insert((cid, cindex), meetup_index, meetup_result);
-						Self::deposit_event(Event::MeetupEvaluated(
-							cid,
-							meetup_index,
-							meetup_result,
-						));
-						return Ok(Pays::No.into());
-					} else {
-						return error;
-					}
-				},
-			};
-			if current_phase == CeremonyPhaseType::Attesting &&
-				!participant_judgements.early_rewards_possible
-			{
-				debug!(
-					target: LOG,
-					"early rewards not possible for meetup {meetup_index:?}, cid: {cid:?}"
-				);
-				return Err(<Error<T>>::EarlyRewardsNotPossible.into());
-			}
-			participants_eligible_for_rewards = participant_judgements.legit;
-			// emit events
-			for p in participant_judgements.excluded {
-				let participant = meetup_participants
-					.get(p.index)
-					.ok_or(Error::<T>::MeetupValidationIndexOutOfBounds)?
-					.clone();
-				Self::deposit_event(Event::NoReward {
-					cid,
-					cindex,
-					meetup_index,
-					account: participant,
-					reason: p.reason,
-				});
-			}
-
-			Self::issue_rewards(
-				cid,
-				cindex,
-				meetup_index,
-				meetup_participants,
-				participants_eligible_for_rewards,
-			)?;
-			Ok(Pays::No.into())
+			Self::evaluate_meetup(cid, cindex, meetup_index, current_phase)
 		}
 
 		#[pallet::call_index(6)]
@@ -616,7 +463,7 @@ pub mod pallet {
 			}
 
 			// Meetup time offset needs to be in [-8h, 8h]
-			if meetup_time_offset.abs() > 8 * 3600 * 1000 {
+			if meetup_time_offset.abs() > MAX_MEETUP_TIME_OFFSET_MS {
 				return Err(<Error<T>>::InvalidMeetupTimeOffset.into());
 			}
 
@@ -651,6 +498,7 @@ pub mod pallet {
 			Self::deposit_event(Event::LocationToleranceUpdated(location_tolerance));
 			Ok(().into())
 		}
+
 		#[pallet::call_index(13)]
 		#[pallet::weight((<T as Config>::WeightInfo::purge_community_ceremony(), DispatchClass::Normal, Pays::Yes))]
 		pub fn purge_community_ceremony(
@@ -663,6 +511,262 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Attest meetup participants with their signed `ClaimOfAttendance`.
+		///
+		/// Unlike `attest_attendees`, every attestation has to be backed by the attestee's signed
+		/// claim for the attestor's meetup, taken close enough to the meetup location and time.
+		/// Communities whose rules require signed claims can only be attested this way.
+		#[pallet::call_index(14)]
+		#[pallet::weight((<T as Config>::WeightInfo::attest_claims(claims.len() as u32), DispatchClass::Normal, Pays::Yes))]
+		pub fn attest_claims(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			number_of_participants_vote: u32,
+			claims: BoundedVec<ClaimOfAttendanceOf<T>, T::MaxAttestations>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				<pallet_encointer_scheduler::Pallet<T>>::current_phase() ==
+					CeremonyPhaseType::Attesting,
+				Error::<T>::AttestationPhaseRequired
+			);
+			ensure!(
+				<pallet_encointer_communities::Pallet<T>>::community_identifiers().contains(&cid),
+				Error::<T>::InexistentCommunity
+			);
+
+			let (cindex, meetup_index, meetup_participants, meetup_location_and_time) =
+				Self::gather_meetup_data_for_rules(&cid, &sender)?;
+			// before verifying any signature
+			ensure!(claims.len() < meetup_participants.len(), Error::<T>::TooManyAttestations);
+
+			let mut attestations = Vec::with_capacity(claims.len());
+			for claim in claims.iter() {
+				Self::verify_claim_of_attendance(
+					claim,
+					&cid,
+					cindex,
+					meetup_index,
+					meetup_location_and_time,
+				)?;
+				attestations.push(claim.claimant_public.clone());
+			}
+
+			Self::register_attestations(
+				sender,
+				&cid,
+				cindex,
+				meetup_index,
+				&meetup_participants,
+				number_of_participants_vote,
+				&attestations,
+			)?;
+
+			Ok(().into())
+		}
+
+		/// Prefer to be assigned to a meetup within `region` at the upcoming ceremony.
+		///
+		/// `region` is a geohash bucket containing locations of the community. The preference is
+		/// honored if enough registered participants share it to hold meetups of their own.
+		#[pallet::call_index(15)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_preferred_region(), DispatchClass::Normal, Pays::Yes))]
+		pub fn set_preferred_region(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			region: Option<GeoHash>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let current_phase = <pallet_encointer_scheduler::Pallet<T>>::current_phase();
+			ensure!(
+				CeremonyPhaseType::is_registering_or_attesting(&current_phase),
+				Error::<T>::RegisteringOrAttestationPhaseRequired
+			);
+
+			let mut cindex = <pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index();
+
+			if current_phase == CeremonyPhaseType::Attesting {
+				cindex += 1
+			};
+
+			ensure!(
+				Self::is_registered(cid, cindex, &sender),
+				Error::<T>::ParticipantIsNotRegistered
+			);
+
+			match &region {
+				Some(region) => {
+					ensure!(
+						Self::community_rules(&cid).has_meetup_locations() &&
+							!<pallet_encointer_communities::Pallet<T>>::locations(cid, region)
+								.is_empty(),
+						Error::<T>::NoLocationsInRegion
+					);
+					<PreferredRegion<T>>::insert((cid, cindex), &sender, region);
+				},
+				None => <PreferredRegion<T>>::remove((cid, cindex), &sender),
+			}
+			Self::deposit_event(Event::PreferredRegionSet(cid, sender, region));
+			Ok(().into())
+		}
+
+		/// Delegate `amount` of the sender's newbie tickets for the upcoming ceremony to a
+		/// reputable `delegate`. An `amount` of 0 revokes the delegation.
+		///
+		/// Tickets are only burned from the sender's tickets when the delegate endorses a newbie
+		/// with them. A reputable can be delegated at most `MaxDelegatedNewbieTickets` per
+		/// ceremony.
+		#[pallet::call_index(16)]
+		#[pallet::weight((<T as Config>::WeightInfo::delegate_newbie_tickets(), DispatchClass::Normal, Pays::Yes))]
+		pub fn delegate_newbie_tickets(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			delegate: T::AccountId,
+			amount: EndorsementTicketsType,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				<pallet_encointer_communities::Pallet<T>>::community_identifiers().contains(&cid),
+				Error::<T>::InexistentCommunity
+			);
+			ensure!(sender != delegate, Error::<T>::CannotDelegateToSelf);
+			ensure!(Self::has_reputation(&delegate, &cid), Error::<T>::DelegateMustBeReputable);
+
+			let mut cindex = <pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index();
+			if <pallet_encointer_scheduler::Pallet<T>>::current_phase() !=
+				CeremonyPhaseType::Registering
+			{
+				cindex += 1; //safe; cindex comes from within, will not overflow at +1/d
+			}
+
+			ensure!(
+				amount <= Self::remaining_newbie_tickets(cid, cindex, &sender),
+				Error::<T>::NoMoreNewbieTickets
+			);
+
+			// the delegate's tickets include the ones already used, so the limit holds per ceremony
+			let previous = Self::delegated_newbie_tickets((cid, cindex), (&sender, &delegate));
+			let received = Self::received_delegated_newbie_tickets((cid, cindex), &delegate)
+				.saturating_sub(previous)
+				.checked_add(amount)
+				.filter(|r| *r <= T::MaxDelegatedNewbieTickets::get())
+				.ok_or(Error::<T>::TooManyDelegatedNewbieTickets)?;
+
+			<ReceivedDelegatedNewbieTickets<T>>::insert((cid, cindex), &delegate, received);
+			if amount == 0 {
+				<DelegatedNewbieTickets<T>>::remove((cid, cindex), (&sender, &delegate));
+			} else {
+				<DelegatedNewbieTickets<T>>::insert((cid, cindex), (&sender, &delegate), amount);
+			}
+
+			debug!(target: LOG, "{sender:?} delegated {amount} newbie tickets to {delegate:?}");
+			Self::deposit_event(Event::NewbieTicketsDelegated(cid, sender, delegate, amount));
+			Ok(().into())
+		}
+
+		/// Register as reputable with proofs of the sender's own attendance at several past
+		/// ceremonies within the reputation lifetime.
+		///
+		/// Reputables with more proven attendances are assigned first if there are not enough
+		/// seats for all reputables, and their vote weighs more in meetup validation.
+		#[pallet::call_index(18)]
+		#[pallet::weight((<T as Config>::WeightInfo::register_participant_with_proofs(), DispatchClass::Normal, Pays::Yes))]
+		pub fn register_participant_with_proofs(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			proofs: BoundedVec<
+				ProofOfAttendance<T::Signature, T::AccountId>,
+				T::MaxRegistrationProofs,
+			>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let mut proven: Vec<CommunityCeremony> = Vec::with_capacity(proofs.len());
+			for p in proofs.iter() {
+				ensure!(p.attendee_public == sender, Error::<T>::ProofOfForeignAttendance);
+				ensure!(
+					!proven.iter().any(|cc| cc.1 == p.ceremony_index),
+					Error::<T>::DuplicateProofCeremony
+				);
+				proven.push((p.community_identifier, p.ceremony_index));
+			}
+
+			let cindex = Self::do_register_participant(sender.clone(), cid, &proofs)?;
+			if !proven.is_empty() {
+				<ProvenAttendances<T>>::insert(
+					(cid, cindex),
+					&sender,
+					BoundedVec::<_, T::MaxRegistrationProofs>::truncate_from(proven),
+				);
+			}
+			Ok(().into())
+		}
+
+		/// Endorse `newbie` with a newbie ticket `delegator` has delegated to the sender.
+		#[pallet::call_index(17)]
+		#[pallet::weight((<T as Config>::WeightInfo::endorse_newcomer_with_delegated_ticket(), DispatchClass::Normal, Pays::Yes))]
+		pub fn endorse_newcomer_with_delegated_ticket(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			newbie: T::AccountId,
+			delegator: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				<pallet_encointer_communities::Pallet<T>>::community_identifiers().contains(&cid),
+				Error::<T>::InexistentCommunity
+			);
+
+			let mut cindex = <pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index();
+			if <pallet_encointer_scheduler::Pallet<T>>::current_phase() !=
+				CeremonyPhaseType::Registering
+			{
+				cindex += 1; //safe; cindex comes from within, will not overflow at +1/d
+			}
+
+			let delegated = Self::delegated_newbie_tickets((cid, cindex), (&delegator, &sender));
+			ensure!(delegated > 0, Error::<T>::NoDelegatedNewbieTickets);
+			ensure!(
+				Self::is_endorsed(&newbie, &(cid, cindex)).is_none(),
+				Error::<T>::AlreadyEndorsed
+			);
+
+			Self::burn_newbie_tickets(cid, cindex, &delegator)?;
+			if delegated == 1 {
+				<DelegatedNewbieTickets<T>>::remove((cid, cindex), (&delegator, &sender));
+			} else {
+				<DelegatedNewbieTickets<T>>::insert(
+					(cid, cindex),
+					(&delegator, &sender),
+					delegated - 1,
+				);
+			}
+
+			Self::endorse(cid, cindex, sender, newbie)
+		}
+
+		/// Set the policy by which the meetups of `cid` are validated, e.g. the lenient policy for
+		/// communities meeting online. `None` reverts to the policy of the community's rules.
+		#[pallet::call_index(19)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_attestation_policy(), DispatchClass::Normal, Pays::Yes))]
+		pub fn set_attestation_policy(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			policy: Option<AttestationPolicyKind>,
+		) -> DispatchResultWithPostInfo {
+			<T as pallet::Config>::CeremonyMaster::ensure_origin(origin)?;
+			ensure!(
+				<pallet_encointer_communities::Pallet<T>>::community_identifiers().contains(&cid),
+				Error::<T>::InexistentCommunity
+			);
+			<AttestationPolicyOverride<T>>::set(cid, policy);
+			info!(target: LOG, "set attestation policy of {cid:?} to {policy:?}");
+			Self::deposit_event(Event::AttestationPolicyUpdated(cid, policy));
+			Ok(().into())
+		}
 	}
 
 	#[pallet::event]
@@ -711,6 +815,22 @@ pub mod pallet {
 
 		/// Result of the meetup at the previous ceremony
 		MeetupEvaluated(CommunityIdentifier, MeetupIndexType, MeetupResult),
+
+		/// A participant has set or cleared the region they prefer to meet in
+		PreferredRegionSet(CommunityIdentifier, T::AccountId, Option<GeoHash>),
+
+		/// A participant (first accountid) has delegated N newbie tickets to a reputable (second
+		/// accountid) for the upcoming ceremony
+		NewbieTicketsDelegated(
+			CommunityIdentifier,
+			T::AccountId,
+			T::AccountId,
+			EndorsementTicketsType,
+		),
+
+		/// The attestation policy of a community has been set, or reverted to the policy of its
+		/// rules if `None`
+		AttestationPolicyUpdated(CommunityIdentifier, Option<AttestationPolicyKind>),
 	}
 
 	#[pallet::error]
@@ -790,6 +910,30 @@ pub mod pallet {
 		InvalidMeetupIndex,
 		/// BoundedVec bound reached
 		TooManyAttestationsInBoundedVec,
+		/// the signature of a claim of attendance is missing or invalid
+		BadClaimOfAttendanceSignature,
+		/// a claim of attendance is for another community, ceremony or meetup
+		ClaimOfAttendanceForWrongMeetup,
+		/// a claim of attendance was taken too far away from the meetup location
+		ClaimOfAttendanceTooFarAway,
+		/// a claim of attendance was taken too long before or after the meetup time
+		StaleClaimOfAttendance,
+		/// the community has no meetup locations within the given region
+		NoLocationsInRegion,
+		/// newbie tickets can't be delegated to oneself
+		CannotDelegateToSelf,
+		/// newbie tickets can only be delegated to reputables of the community
+		DelegateMustBeReputable,
+		/// the delegate can't be delegated any more newbie tickets for this ceremony
+		TooManyDelegatedNewbieTickets,
+		/// the delegator hasn't delegated any (more) newbie tickets to the sender
+		NoDelegatedNewbieTickets,
+		/// only proofs of the sender's own attendance can be combined
+		ProofOfForeignAttendance,
+		/// there can only be one proof of attendance per ceremony
+		DuplicateProofCeremony,
+		/// the community rules require attestations with signed claims of attendance
+		SignedClaimsRequired,
 	}
 
 	#[pallet::storage]
@@ -816,36 +960,75 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Newbie tickets delegated per ceremony. Keyed by (delegator, delegate)
 	#[pallet::storage]
-	#[pallet::getter(fn bootstrapper_registry)]
-	pub(super) type BootstrapperRegistry<T: Config> = StorageDoubleMap<
+	#[pallet::getter(fn delegated_newbie_tickets)]
+	pub(super) type DelegatedNewbieTickets<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		CommunityCeremony,
 		Blake2_128Concat,
-		ParticipantIndexType,
-		T::AccountId,
-		OptionQuery,
+		(T::AccountId, T::AccountId),
+		EndorsementTicketsType,
+		ValueQuery,
 	>;
 
+	/// Newbie tickets a delegate has been delegated per ceremony, including the ones used already
 	#[pallet::storage]
-	#[pallet::getter(fn bootstrapper_index)]
-	pub(super) type BootstrapperIndex<T: Config> = StorageDoubleMap<
+	#[pallet::getter(fn received_delegated_newbie_tickets)]
+	pub(super) type ReceivedDelegatedNewbieTickets<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		CommunityCeremony,
 		Blake2_128Concat,
 		T::AccountId,
-		ParticipantIndexType,
+		EndorsementTicketsType,
 		ValueQuery,
 	>;
 
+	/// Past attendances a participant proved when registering with several proofs
+	#[pallet::storage]
+	#[pallet::getter(fn proven_attendances)]
+	pub(super) type ProvenAttendances<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityCeremony,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<CommunityCeremony, T::MaxRegistrationProofs>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bootstrapper_registry)]
+	pub(super) type BootstrapperRegistry<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityCeremony,
+		Blake2_128Concat,
+		ParticipantIndexType,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bootstrapper_index)]
+	pub(super) type BootstrapperIndex<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityCeremony,
+		Blake2_128Concat,
+		T::AccountId,
+		ParticipantIndexType,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bootstrapper_count)]
+	pub(super) type BootstrapperCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, CommunityCeremony, ParticipantIndexType, ValueQuery>;
+
 	#[pallet::storage]
-	#[pallet::getter(fn bootstrapper_count)]
-	pub(super) type BootstrapperCount<T: Config> =
-		StorageMap<_, Blake2_128Concat, CommunityCeremony, ParticipantIndexType, ValueQuery>;
-
-	#[pallet::storage]
 	#[pallet::getter(fn reputable_registry)]
 	pub(super) type ReputableRegistry<T: Config> = StorageDoubleMap<
 		_,
@@ -874,6 +1057,20 @@ pub mod pallet {
 	pub(super) type ReputableCount<T: Config> =
 		StorageMap<_, Blake2_128Concat, CommunityCeremony, ParticipantIndexType, ValueQuery>;
 
+	/// Number of reputables per number of proven attendances, for those who proved more than
+	/// one. The reputable registry is ordered by descending number of proven attendances.
+	#[pallet::storage]
+	#[pallet::getter(fn prioritized_reputable_count)]
+	pub(super) type PrioritizedReputableCount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityCeremony,
+		Blake2_128Concat,
+		u32,
+		ParticipantIndexType,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn endorsee_registry)]
 	pub(super) type EndorseeRegistry<T: Config> = StorageDoubleMap<
@@ -942,6 +1139,52 @@ pub mod pallet {
 	pub(super) type Assignments<T: Config> =
 		StorageMap<_, Blake2_128Concat, CommunityCeremony, Assignment, ValueQuery>;
 
+	/// The region in which a participant would like to meet.
+	#[pallet::storage]
+	#[pallet::getter(fn preferred_region)]
+	pub(super) type PreferredRegion<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityCeremony,
+		Blake2_128Concat,
+		T::AccountId,
+		GeoHash,
+		OptionQuery,
+	>;
+
+	/// Meetup assignments of the participants who have been partitioned off by their preferred
+	/// region. The meetups of all other participants come first and are given by `Assignments`.
+	#[pallet::storage]
+	#[pallet::getter(fn regional_assignments)]
+	pub(super) type RegionalAssignments<T: Config> =
+		StorageMap<_, Blake2_128Concat, CommunityCeremony, Vec<RegionalAssignment>, ValueQuery>;
+
+	/// Region and index within the assignment group for participants whose assignment doesn't
+	/// follow their registry index.
+	#[pallet::storage]
+	#[pallet::getter(fn partitioned_participant_index)]
+	pub(super) type PartitionedParticipantIndex<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityCeremony,
+		Blake2_128Concat,
+		T::AccountId,
+		(Option<RegionIndexType>, ParticipantIndexType),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn partitioned_participant_registry)]
+	pub(super) type PartitionedParticipantRegistry<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityCeremony,
+		Blake2_128Concat,
+		(Option<RegionIndexType>, AssignmentGroup, ParticipantIndexType),
+		T::AccountId,
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn participant_reputation)]
 	pub(super) type ParticipantReputation<T: Config> = StorageDoubleMap<
@@ -1056,6 +1299,39 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Position of the reward issuance sweep: ceremony index, community and the next meetup
+	/// index to be evaluated. Communities are swept in the order of their identifiers.
+	#[pallet::storage]
+	#[pallet::getter(fn reward_issuance_cursor)]
+	pub(super) type RewardIssuanceCursor<T: Config> =
+		StorageValue<_, (CeremonyIndexType, CommunityIdentifier, MeetupIndexType), OptionQuery>;
+
+	/// Nominal income issued as rewards per community ceremony
+	#[pallet::storage]
+	#[pallet::getter(fn issued_nominal_income)]
+	pub(super) type IssuedNominalIncome<T: Config> =
+		StorageMap<_, Blake2_128Concat, CommunityCeremony, NominalIncome, ValueQuery>;
+
+	/// Number of meetups per result recorded in `IssuedRewards`, per community ceremony
+	#[pallet::storage]
+	#[pallet::getter(fn meetup_result_tally)]
+	pub(super) type MeetupResultTally<T: Config> =
+		StorageMap<_, Blake2_128Concat, CommunityCeremony, MeetupResultCounts, ValueQuery>;
+
+	/// Statistics of past community ceremonies. Unlike the registries, these are not purged
+	/// after the reputation lifetime.
+	#[pallet::storage]
+	#[pallet::getter(fn ceremony_stats)]
+	pub(super) type CommunityCeremonyStats<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityIdentifier,
+		Blake2_128Concat,
+		CeremonyIndexType,
+		CeremonyStats,
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn inactivity_counters)]
 	pub(super) type InactivityCounters<T: Config> =
@@ -1089,6 +1365,12 @@ pub mod pallet {
 	#[pallet::getter(fn meetup_time_offset)]
 	pub(super) type MeetupTimeOffset<T: Config> = StorageValue<_, MeetupTimeOffsetType, ValueQuery>;
 
+	/// The attestation policy of a community, if it deviates from the policy of its rules
+	#[pallet::storage]
+	#[pallet::getter(fn attestation_policy_override)]
+	pub(super) type AttestationPolicyOverride<T: Config> =
+		StorageMap<_, Blake2_128Concat, CommunityIdentifier, AttestationPolicyKind, OptionQuery>;
+
 	#[derive(frame_support::DefaultNoBound)]
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config>
@@ -1169,18 +1451,15 @@ impl<T: Config> Pallet<T> {
 			// check if the participant is already assigned to a meetup
 			if let Some(participant_meetup_index) = Self::get_meetup_index((cid, cindex), account) {
 				meetup_index = Some(participant_meetup_index);
-				let locations = <pallet_encointer_communities::Pallet<T>>::get_locations(&cid);
-				let location_assignment_params = Self::assignments((cid, cindex)).locations;
-
-				meetup_location_index = get_meetup_location_index(
-					participant_meetup_index,
-					&locations,
-					location_assignment_params,
-				);
 				if let Some(location) =
 					Self::get_meetup_location((cid, cindex), participant_meetup_index)
 				{
-					meetup_time = Self::get_meetup_time(location);
+					meetup_location_index =
+						<pallet_encointer_communities::Pallet<T>>::get_locations(&cid)
+							.iter()
+							.position(|l| *l == location)
+							.map(|i| i as MeetupIndexType);
+					meetup_time = Self::get_meetup_time(cid, location);
 				}
 
 				meetup_registry =
@@ -1204,6 +1483,78 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Returns the meetup `account` is assigned to at the current ceremony of `cid`, if any.
+	pub fn get_meetup_assignment(
+		cid: CommunityIdentifier,
+		account: &T::AccountId,
+	) -> Option<MeetupAssignment<T::AccountId, T::Moment>> {
+		let cc = (cid, <pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index());
+		let meetup_index = Self::get_meetup_index(cc, account)?;
+		let location = Self::get_meetup_location(cc, meetup_index);
+		Some(MeetupAssignment {
+			meetup_index,
+			participants: Self::get_meetup_participants(cc, meetup_index).unwrap_or_default(),
+			location,
+			time: location.and_then(|l| Self::get_meetup_time(cid, l)),
+		})
+	}
+
+	/// Returns the participants of meetup `meetup_index` at the current ceremony of `cid`.
+	///
+	/// Unknown meetups have no participants.
+	pub fn get_meetup_registry(
+		cid: CommunityIdentifier,
+		meetup_index: MeetupIndexType,
+	) -> Vec<T::AccountId> {
+		let cc = (cid, <pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index());
+		Self::get_meetup_participants(cc, meetup_index).unwrap_or_default()
+	}
+
+	/// Returns the attestations registered at the ceremonies `from..=to` of `cid` that have not
+	/// been purged yet.
+	///
+	/// The range is clamped to the ceremonies within the reputation lifetime, as older ones have
+	/// been purged.
+	pub fn attestation_history(
+		cid: CommunityIdentifier,
+		from: CeremonyIndexType,
+		to: CeremonyIndexType,
+	) -> Vec<CeremonyAttestations<T::AccountId>> {
+		let current_cindex = <pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index();
+		let from = max(from, current_cindex.saturating_sub(Self::reputation_lifetime()));
+		let to = min(to, current_cindex);
+		(from..=to)
+			.map(|cindex| {
+				let cc = (cid, cindex);
+				let mut attesters: Vec<(AttestationIndexType, T::AccountId)> =
+					<AttestationIndex<T>>::iter_prefix(cc).map(|(a, idx)| (idx, a)).collect();
+				attesters.sort_by_key(|(idx, _)| *idx);
+				let attestations = attesters
+					.into_iter()
+					.filter_map(|(idx, attester)| {
+						Self::attestation_registry(cc, idx).map(|a| (attester, a.into_inner()))
+					})
+					.collect();
+				CeremonyAttestations {
+					ceremony_index: cindex,
+					meetup_count: Self::meetup_count(cc),
+					attestations,
+				}
+			})
+			.filter(|c| !c.attestations.is_empty())
+			.collect()
+	}
+
+	/// Analyzes the attestation history of `cid` for accounts that keep meeting each other.
+	pub fn get_collusion_report(
+		cid: CommunityIdentifier,
+		from: CeremonyIndexType,
+		to: CeremonyIndexType,
+		params: CollusionDetectionParams,
+	) -> CollusionReport<T::AccountId> {
+		collusion::detect_collusion(&Self::attestation_history(cid, from, to), &params)
+	}
+
 	pub fn get_ceremony_info() -> CeremonyInfo {
 		CeremonyInfo {
 			ceremony_phase: <pallet_encointer_scheduler::Pallet<T>>::current_phase(),
@@ -1211,11 +1562,105 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Registers `sender` for the upcoming ceremony, as reputable if `proofs` are given.
+	///
+	/// Returns the ceremony index the participant has been registered for.
+	fn do_register_participant(
+		sender: T::AccountId,
+		cid: CommunityIdentifier,
+		proofs: &[ProofOfAttendance<T::Signature, T::AccountId>],
+	) -> Result<CeremonyIndexType, DispatchError> {
+		let current_phase = <pallet_encointer_scheduler::Pallet<T>>::current_phase();
+		ensure!(
+			CeremonyPhaseType::is_registering_or_attesting(&current_phase),
+			Error::<T>::RegisteringOrAttestationPhaseRequired
+		);
+
+		ensure!(
+			<pallet_encointer_communities::Pallet<T>>::community_identifiers().contains(&cid),
+			Error::<T>::InexistentCommunity
+		);
+
+		let mut cindex = <pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index();
+
+		if current_phase == CeremonyPhaseType::Attesting {
+			cindex += 1
+		};
+
+		if Self::is_registered(cid, cindex, &sender) {
+			return Err(<Error<T>>::ParticipantAlreadyRegistered.into());
+		}
+
+		for p in proofs {
+			// we accept proofs from other communities as well. no need to ensure cid
+			ensure!(sender == p.prover_public, Error::<T>::WrongProofSubject);
+			ensure!(p.ceremony_index < cindex, Error::<T>::ProofAcausal);
+			ensure!(
+				p.ceremony_index >= cindex.saturating_sub(Self::reputation_lifetime()),
+				Error::<T>::ProofOutdated
+			);
+			ensure!(
+				Self::participant_reputation(
+					(p.community_identifier, p.ceremony_index),
+					&p.attendee_public
+				)
+				.is_verified_and_unlinked_for_cindex(cindex),
+				Error::<T>::AttendanceUnverifiedOrAlreadyUsed
+			);
+			if <pallet_encointer_communities::Pallet<T>>::bootstrappers(cid)
+				.contains(&p.attendee_public)
+			{
+				ensure!(
+					p.attendee_public == sender,
+					Error::<T>::BootstrapperReputationIsUntransferrable
+				)
+			}
+			ensure!(p.verify_signature(), Error::<T>::BadProofOfAttendanceSignature);
+
+			// this reputation must now be flagged so it can not be used again in the same cycle
+			<ParticipantReputation<T>>::insert(
+				(p.community_identifier, p.ceremony_index),
+				&p.attendee_public,
+				Reputation::VerifiedLinked(cindex),
+			);
+		}
+		if !proofs.is_empty() {
+			// register participant as reputable
+			<ParticipantReputation<T>>::insert(
+				(cid, cindex),
+				&sender,
+				Reputation::UnverifiedReputable,
+			);
+		}
+
+		let participant_type =
+			Self::register_with_proven_attendances(cid, cindex, &sender, proofs.len() as u32)?;
+
+		// invalidate reputation cache
+		sp_io::offchain_index::set(&reputation_cache_dirty_key(&sender), &true.encode());
+
+		debug!(target: LOG, "registered participant: {sender:?} as {participant_type:?}");
+		Self::deposit_event(Event::ParticipantRegistered(cid, participant_type, sender));
+
+		Ok(cindex)
+	}
+
 	fn register(
 		cid: CommunityIdentifier,
 		cindex: CeremonyIndexType,
 		sender: &T::AccountId,
 		is_reputable: bool,
+	) -> Result<ParticipantType, Error<T>> {
+		Self::register_with_proven_attendances(cid, cindex, sender, is_reputable.into())
+	}
+
+	/// Like `register`, where participants who proved attendances are registered as reputables,
+	/// ordered by the number of their proven attendances.
+	fn register_with_proven_attendances(
+		cid: CommunityIdentifier,
+		cindex: CeremonyIndexType,
+		sender: &T::AccountId,
+		proven_attendances: u32,
 	) -> Result<ParticipantType, Error<T>> {
 		let participant_type =
 			if <pallet_encointer_communities::Pallet<T>>::bootstrappers(cid).contains(sender) {
@@ -1228,13 +1673,8 @@ impl<T: Config> Pallet<T> {
 				ParticipantType::Bootstrapper
 			} else if <pallet_encointer_balances::Pallet<T>>::total_issuance(cid) <= 0 {
 				return Err(Error::<T>::OnlyBootstrappers);
-			} else if is_reputable {
-				let participant_index = <ReputableCount<T>>::get((cid, cindex))
-					.checked_add(1)
-					.ok_or(Error::<T>::RegistryOverflow)?;
-				<ReputableRegistry<T>>::insert((cid, cindex), participant_index, sender);
-				<ReputableIndex<T>>::insert((cid, cindex), sender, participant_index);
-				<ReputableCount<T>>::insert((cid, cindex), participant_index);
+			} else if proven_attendances > 0 {
+				Self::insert_reputable((cid, cindex), sender, proven_attendances)?;
 				ParticipantType::Reputable
 			} else if let Some(endorsed_cindex) = Self::is_endorsed(sender, &(cid, cindex)) {
 				let participant_index = <EndorseeCount<T>>::get((cid, cindex))
@@ -1280,14 +1720,7 @@ impl<T: Config> Pallet<T> {
 					T::AccountId,
 				>(cid, cindex, participant);
 			},
-			ParticipantType::Reputable => {
-				storage_helper::remove_participant_from_registry::<
-					ReputableIndex<T>,
-					ReputableRegistry<T>,
-					ReputableCount<T>,
-					T::AccountId,
-				>(cid, cindex, participant);
-			},
+			ParticipantType::Reputable => Self::remove_reputable((cid, cindex), participant),
 			ParticipantType::Endorsee => {
 				storage_helper::remove_participant_from_registry::<
 					EndorseeIndex<T>,
@@ -1309,6 +1742,42 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Removes a former bootstrapper from the bootstrapper registry of the ceremony open for
+	/// registration. If they linked reputation when registering, they stay registered as
+	/// reputable. Meetups that are assigned already are left untouched.
+	fn revoke_bootstrapper_registration(cid: CommunityIdentifier, bootstrapper: &T::AccountId) {
+		let cindex = match <pallet_encointer_scheduler::Pallet<T>>::current_phase() {
+			CeremonyPhaseType::Registering =>
+				<pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index(),
+			CeremonyPhaseType::Attesting =>
+				<pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index() + 1,
+			CeremonyPhaseType::Assigning => return,
+		};
+		if !<BootstrapperIndex<T>>::contains_key((cid, cindex), bootstrapper) {
+			return;
+		}
+		storage_helper::remove_participant_from_registry::<
+			BootstrapperIndex<T>,
+			BootstrapperRegistry<T>,
+			BootstrapperCount<T>,
+			T::AccountId,
+		>(cid, cindex, bootstrapper);
+
+		if Self::participant_reputation((cid, cindex), bootstrapper) ==
+			Reputation::UnverifiedReputable &&
+			Self::register_with_proven_attendances(
+				cid,
+				cindex,
+				bootstrapper,
+				Self::proven_attendance_count((cid, cindex), bootstrapper),
+			)
+			.is_ok()
+		{
+			return;
+		}
+		<PreferredRegion<T>>::remove((cid, cindex), bootstrapper);
+	}
+
 	fn is_registered(
 		cid: CommunityIdentifier,
 		cindex: CeremonyIndexType,
@@ -1350,6 +1819,49 @@ impl<T: Config> Pallet<T> {
 		Err(Error::<T>::NoMoreNewbieTickets)
 	}
 
+	/// The number of newbie tickets `holder` can still burn at `cindex`.
+	fn remaining_newbie_tickets(
+		cid: CommunityIdentifier,
+		cindex: CeremonyIndexType,
+		holder: &T::AccountId,
+	) -> EndorsementTicketsType {
+		let mut remaining: EndorsementTicketsType = 0;
+		if Self::has_reputation(holder, &cid) {
+			remaining = Self::endorsement_tickets_per_reputable()
+				.saturating_sub(<BurnedReputableNewbieTickets<T>>::get((cid, cindex), holder));
+		}
+		if <pallet_encointer_communities::Pallet<T>>::bootstrappers(cid).contains(holder) {
+			remaining = remaining.saturating_add(
+				Self::endorsement_tickets_per_bootstrapper()
+					.saturating_sub(<BurnedBootstrapperNewbieTickets<T>>::get(cid, holder)),
+			);
+		}
+		remaining
+	}
+
+	fn endorse(
+		cid: CommunityIdentifier,
+		cindex: CeremonyIndexType,
+		endorser: T::AccountId,
+		newbie: T::AccountId,
+	) -> DispatchResultWithPostInfo {
+		<Endorsees<T>>::insert((cid, cindex), newbie.clone(), ());
+		let new_endorsee_count = Self::endorsee_count((cid, cindex))
+			.checked_add(1)
+			.ok_or(<Error<T>>::RegistryOverflow)?;
+		<EndorseesCount<T>>::insert((cid, cindex), new_endorsee_count);
+
+		if <NewbieIndex<T>>::contains_key((cid, cindex), &newbie) {
+			Self::remove_participant_from_registry(cid, cindex, &newbie)?;
+			Self::register(cid, cindex, &newbie, false)?;
+		}
+
+		debug!(target: LOG, "{endorser:?} endorsed newbie: {newbie:?}");
+		Self::deposit_event(Event::EndorsedParticipant(cid, endorser, newbie));
+
+		Ok(().into())
+	}
+
 	#[allow(deprecated)]
 	fn purge_community_ceremony_internal(cc: CommunityCeremony) {
 		let cid = cc.0;
@@ -1364,6 +1876,7 @@ impl<T: Config> Pallet<T> {
 		<ReputableRegistry<T>>::remove_prefix(cc, None);
 		<ReputableIndex<T>>::remove_prefix(cc, None);
 		<ReputableCount<T>>::remove(cc);
+		<PrioritizedReputableCount<T>>::remove_prefix(cc, None);
 
 		<EndorseeRegistry<T>>::remove_prefix(cc, None);
 		<EndorseeIndex<T>>::remove_prefix(cc, None);
@@ -1376,6 +1889,10 @@ impl<T: Config> Pallet<T> {
 		<AssignmentCounts<T>>::remove(cc);
 
 		Assignments::<T>::remove(cc);
+		<PreferredRegion<T>>::remove_prefix(cc, None);
+		<RegionalAssignments<T>>::remove(cc);
+		<PartitionedParticipantIndex<T>>::remove_prefix(cc, None);
+		<PartitionedParticipantRegistry<T>>::remove_prefix(cc, None);
 
 		<ParticipantReputation<T>>::remove_prefix(cc, None);
 		<ReputationCount<T>>::remove(cc);
@@ -1391,7 +1908,12 @@ impl<T: Config> Pallet<T> {
 
 		<MeetupParticipantCountVote<T>>::remove_prefix(cc, None);
 		<IssuedRewards<T>>::remove_prefix(cc, None);
+		<IssuedNominalIncome<T>>::remove(cc);
+		<MeetupResultTally<T>>::remove(cc);
 		<BurnedReputableNewbieTickets<T>>::remove_prefix(cc, None);
+		<DelegatedNewbieTickets<T>>::remove_prefix(cc, None);
+		<ProvenAttendances<T>>::remove_prefix(cc, None);
+		<ReceivedDelegatedNewbieTickets<T>>::remove_prefix(cc, None);
 
 		Self::deposit_event(Event::CommunityCeremonyHistoryPurged(cid, cindex));
 	}
@@ -1428,32 +1950,50 @@ impl<T: Config> Pallet<T> {
 			);
 			return Ok(());
 		}
+
+		let regional_assignments = Self::partition_by_preferred_region(
+			community_ceremony,
+			&assignment_allowance,
+			random_source,
+		)?;
+		let unpartitioned =
+			Self::unpartitioned_assignment_count(&assignment_allowance, &regional_assignments);
+		let num_unpartitioned_meetups =
+			Self::unpartitioned_meetup_count(num_meetups, &regional_assignments);
+		let num_meetups = regional_assignments
+			.iter()
+			.fold(num_unpartitioned_meetups, |n, r| n.saturating_add(r.meetup_count));
 		info!(target: LOG, "assigning {:} meetups for cid {:?}", num_meetups, community_ceremony.0);
 
 		<Assignments<T>>::insert(
 			community_ceremony,
 			Assignment {
 				bootstrappers_reputables: generate_assignment_function_params(
-					assignment_allowance.bootstrappers + assignment_allowance.reputables,
-					num_meetups,
+					unpartitioned.bootstrappers + unpartitioned.reputables,
+					num_unpartitioned_meetups,
 					random_source,
 				),
 				endorsees: generate_assignment_function_params(
-					assignment_allowance.endorsees,
-					num_meetups,
+					unpartitioned.endorsees,
+					num_unpartitioned_meetups,
 					random_source,
 				),
 				newbies: generate_assignment_function_params(
-					assignment_allowance.newbies,
-					num_meetups,
+					unpartitioned.newbies,
+					num_unpartitioned_meetups,
 					random_source,
 				),
 				locations: Self::generate_location_assignment_params(
 					community_ceremony,
+					Self::unpartitioned_locations(&community_ceremony.0, &regional_assignments)
+						.len() as u64,
 					random_source,
 				),
 			},
 		);
+		if !regional_assignments.is_empty() {
+			<RegionalAssignments<T>>::insert(community_ceremony, regional_assignments);
+		}
 
 		<AssignmentCounts<T>>::insert(community_ceremony, assignment_allowance);
 		<MeetupCount<T>>::insert(community_ceremony, num_meetups);
@@ -1462,11 +2002,14 @@ impl<T: Config> Pallet<T> {
 
 	fn generate_location_assignment_params(
 		community_ceremony: CommunityCeremony,
+		num_locations: u64,
 		random_source: &mut RandomNumberGenerator<T::Hashing>,
 	) -> AssignmentParams {
-		let num_locations =
-			<pallet_encointer_communities::Pallet<T>>::get_locations(&community_ceremony.0).len()
-				as u64;
+		if !Self::community_rules(&community_ceremony.0).has_meetup_locations() ||
+			num_locations == 0
+		{
+			return AssignmentParams::default();
+		}
 
 		AssignmentParams {
 			m: num_locations,
@@ -1475,6 +2018,231 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Partitions the participants who prefer a region off into meetups within that region.
+	///
+	/// A region is only partitioned off if its participants as well as the remaining ones can be
+	/// assigned to meetups on their own, following the same rules as the whole community, and if
+	/// the region's participants fill at least `MinRegionalMeetupCount` meetups. Within each
+	/// partition, participants are assigned with freshly generated params. A small group sharing
+	/// a preference therefore can't arrange to meet each other, but whom a participant may meet
+	/// is narrowed down to the population of the preferred region.
+	fn partition_by_preferred_region(
+		community_ceremony: CommunityCeremony,
+		assigned: &AssignmentCount,
+		random_source: &mut RandomNumberGenerator<T::Hashing>,
+	) -> Result<Vec<RegionalAssignment>, Error<T>> {
+		let cid = community_ceremony.0;
+		if !Self::community_rules(&cid).has_meetup_locations() {
+			return Ok(vec![]);
+		}
+
+		let mut preferences: BTreeMap<GeoHash, [Vec<ParticipantIndexType>; 3]> = BTreeMap::new();
+		for (participant, region) in <PreferredRegion<T>>::iter_prefix(community_ceremony) {
+			if let Some((group, index)) =
+				Self::get_assignment_group_and_index(community_ceremony, &participant, assigned)
+			{
+				preferences.entry(region).or_default()[group as usize].push(index);
+			}
+		}
+		if preferences.is_empty() {
+			return Ok(vec![]);
+		}
+
+		let mut remaining = *assigned;
+		let mut remaining_locations =
+			<pallet_encointer_communities::Pallet<T>>::get_locations(&cid).len() as u64;
+		let mut partitions = vec![];
+		for (region, mut indices) in preferences.into_iter() {
+			indices.iter_mut().for_each(|i| i.sort());
+			let num_bootstrappers =
+				indices[0].iter().filter(|i| **i < assigned.bootstrappers).count() as u64;
+			let counts = AssignmentCount {
+				bootstrappers: num_bootstrappers,
+				reputables: indices[0].len() as u64 - num_bootstrappers,
+				endorsees: indices[1].len() as u64,
+				newbies: indices[2].len() as u64,
+			};
+			let rest = AssignmentCount {
+				bootstrappers: remaining.bootstrappers - counts.bootstrappers,
+				reputables: remaining.reputables - counts.reputables,
+				endorsees: remaining.endorsees - counts.endorsees,
+				newbies: remaining.newbies - counts.newbies,
+			};
+			let num_locations =
+				<pallet_encointer_communities::Pallet<T>>::locations(cid, &region).len() as u64;
+			let rest_locations = remaining_locations.saturating_sub(num_locations);
+
+			let rest_can_meet = rest.get_number_of_participants() == 0 ||
+				Self::partition_meetup_count(&rest, rest_locations).is_some();
+			match Self::partition_meetup_count(&counts, num_locations) {
+				Some(meetup_count)
+					if rest_can_meet && meetup_count >= T::MinRegionalMeetupCount::get() =>
+				{
+					debug!(
+						target: LOG,
+						"partitioning {:?} participants off to region {:?} for cid {:?}",
+						counts.get_number_of_participants(),
+						region,
+						cid
+					);
+					remaining = rest;
+					remaining_locations = rest_locations;
+					partitions.push((region, indices, counts, meetup_count, num_locations));
+				},
+				_ => debug!(
+					target: LOG,
+					"not enough participants prefer region {region:?} for cid {cid:?}"
+				),
+			}
+		}
+
+		let mut meetup_offset = checked_ceil_division(
+			remaining.get_number_of_participants(),
+			T::MeetupSizeTarget::get(),
+		)
+		.ok_or(Error::<T>::CheckedMath)?;
+		let mut moved: [Vec<ParticipantIndexType>; 3] = Default::default();
+		let mut regional_assignments = vec![];
+		for (region_index, (region, indices, counts, meetup_count, num_locations)) in
+			partitions.into_iter().enumerate()
+		{
+			let region_index = region_index as RegionIndexType;
+			let params = Assignment {
+				bootstrappers_reputables: generate_assignment_function_params(
+					counts.bootstrappers + counts.reputables,
+					meetup_count,
+					random_source,
+				),
+				endorsees: generate_assignment_function_params(
+					counts.endorsees,
+					meetup_count,
+					random_source,
+				),
+				newbies: generate_assignment_function_params(
+					counts.newbies,
+					meetup_count,
+					random_source,
+				),
+				locations: Self::generate_location_assignment_params(
+					community_ceremony,
+					num_locations,
+					random_source,
+				),
+			};
+			for group in AssignmentGroup::ALL {
+				for (partition_index, index) in indices[group as usize].iter().enumerate() {
+					Self::insert_partitioned_participant(
+						community_ceremony,
+						assigned,
+						(Some(region_index), group, partition_index as ParticipantIndexType),
+						*index,
+					);
+				}
+			}
+			for (m, i) in moved.iter_mut().zip(indices) {
+				m.extend(i);
+			}
+			regional_assignments.push(RegionalAssignment {
+				region,
+				meetup_offset,
+				meetup_count,
+				counts,
+				params,
+			});
+			meetup_offset = meetup_offset.saturating_add(meetup_count);
+		}
+
+		// the remaining participants must be numbered without gaps
+		for group in AssignmentGroup::ALL {
+			let moved = &mut moved[group as usize];
+			moved.sort();
+			for (index, partition_index) in compact_indices(moved, assigned.of_group(group)) {
+				Self::insert_partitioned_participant(
+					community_ceremony,
+					assigned,
+					(None, group, partition_index),
+					index,
+				);
+			}
+		}
+		Ok(regional_assignments)
+	}
+
+	/// Number of meetups for a partition of the assigned participants, or `None` if they can't
+	/// meet on their own.
+	fn partition_meetup_count(
+		counts: &AssignmentCount,
+		num_locations: u64,
+	) -> Option<MeetupIndexType> {
+		let experienced = counts.bootstrappers + counts.reputables;
+		let num_participants = counts.get_number_of_participants();
+		if num_participants < T::MeetupMinSize::get() ||
+			counts.newbies >
+				(experienced + counts.endorsees) / T::MeetupNewbieLimitDivider::get()
+		{
+			return None;
+		}
+		let num_meetups = checked_ceil_division(num_participants, T::MeetupSizeTarget::get())?;
+		// every meetup needs at least one experienced participant
+		(num_meetups <= min(num_locations, experienced)).then_some(num_meetups)
+	}
+
+	fn insert_partitioned_participant(
+		community_ceremony: CommunityCeremony,
+		assigned: &AssignmentCount,
+		key: (Option<RegionIndexType>, AssignmentGroup, ParticipantIndexType),
+		index: ParticipantIndexType,
+	) {
+		let (region_index, group, partition_index) = key;
+		if let Some(participant) =
+			Self::get_participant_by_assignment_index(community_ceremony, group, index, assigned)
+		{
+			<PartitionedParticipantIndex<T>>::insert(
+				community_ceremony,
+				&participant,
+				(region_index, partition_index),
+			);
+			<PartitionedParticipantRegistry<T>>::insert(community_ceremony, key, participant);
+		}
+	}
+
+	fn unpartitioned_assignment_count(
+		assigned: &AssignmentCount,
+		regional_assignments: &[RegionalAssignment],
+	) -> AssignmentCount {
+		regional_assignments.iter().fold(*assigned, |rest, r| AssignmentCount {
+			bootstrappers: rest.bootstrappers.saturating_sub(r.counts.bootstrappers),
+			reputables: rest.reputables.saturating_sub(r.counts.reputables),
+			endorsees: rest.endorsees.saturating_sub(r.counts.endorsees),
+			newbies: rest.newbies.saturating_sub(r.counts.newbies),
+		})
+	}
+
+	fn unpartitioned_meetup_count(
+		meetup_count: MeetupIndexType,
+		regional_assignments: &[RegionalAssignment],
+	) -> MeetupIndexType {
+		regional_assignments.first().map_or(meetup_count, |r| r.meetup_offset)
+	}
+
+	/// The locations which are not reserved for regional meetups.
+	fn unpartitioned_locations(
+		cid: &CommunityIdentifier,
+		regional_assignments: &[RegionalAssignment],
+	) -> Vec<Location> {
+		let locations = <pallet_encointer_communities::Pallet<T>>::get_locations(cid);
+		if regional_assignments.is_empty() {
+			return locations;
+		}
+		locations
+			.into_iter()
+			.filter(|l| {
+				GeoHash::try_from_params(l.lat, l.lon)
+					.map_or(true, |g| !regional_assignments.iter().any(|r| r.region == g))
+			})
+			.collect()
+	}
+
 	fn compute_assignment_allowance(
 		community_ceremony: CommunityCeremony,
 		meetup_multiplier: u64,
@@ -1486,7 +2254,9 @@ impl<T: Config> Pallet<T> {
 			target: LOG,
 			"Number of locations for cid {:?} is {:?}", community_ceremony.0, num_locations
 		);
-		if num_locations == 0 {
+		let has_meetup_locations =
+			Self::community_rules(&community_ceremony.0).has_meetup_locations();
+		if has_meetup_locations && num_locations == 0 {
 			return Err(<Error<T>>::NoLocationsAvailable);
 		}
 
@@ -1499,10 +2269,13 @@ impl<T: Config> Pallet<T> {
 			"Number of registered bootstrappers {num_registered_bootstrappers:?}, endorsees {num_registered_endorsees:?}, reputables {num_registered_reputables:?}, newbies {num_registered_newbies:?}",
 		);
 
-		let max_num_meetups = min(
-			num_locations,
-			find_prime_below(num_registered_bootstrappers + num_registered_reputables),
-		);
+		let max_num_meetups_by_participants =
+			find_prime_below(num_registered_bootstrappers + num_registered_reputables);
+		let max_num_meetups = if has_meetup_locations {
+			min(num_locations, max_num_meetups_by_participants)
+		} else {
+			max_num_meetups_by_participants
+		};
 
 		//safe; number of assigned bootstrappers <= max_num_meetups <=num_assigned_bootstrappers +
 		// num_reputables
@@ -1566,11 +2339,156 @@ impl<T: Config> Pallet<T> {
 		}
 
 		<InactivityCounters<T>>::remove(cid);
+		<AttestationPolicyOverride<T>>::remove(cid);
 
 		#[allow(deprecated)]
 		<BurnedBootstrapperNewbieTickets<T>>::remove_prefix(cid, None);
+		#[allow(deprecated)]
+		<CommunityCeremonyStats<T>>::remove_prefix(cid, None);
 
 		<pallet_encointer_communities::Pallet<T>>::remove_community(cid);
+
+		// move the reward issuance sweep on, so it doesn't refer to the purged community
+		if let Some((cindex, cursor_cid, _)) = Self::reward_issuance_cursor() {
+			if cursor_cid == cid {
+				match Self::next_community(Some(cid)) {
+					Some(next_cid) => <RewardIssuanceCursor<T>>::put((cindex, next_cid, 1)),
+					None => <RewardIssuanceCursor<T>>::kill(),
+				}
+			}
+		}
+	}
+
+	/// The number of past attendances `participant` proved when registering, at least 1.
+	fn proven_attendance_count(
+		community_ceremony: CommunityCeremony,
+		participant: &T::AccountId,
+	) -> u32 {
+		(Self::proven_attendances(community_ceremony, participant).len() as u32).max(1)
+	}
+
+	/// Index of the first reputable per number of proven attendances, at position `level`.
+	/// Reputables with more proven attendances come first, so that they get the lower indices,
+	/// which are assigned first. Position 0 holds the index after the last reputable.
+	fn reputable_block_starts(community_ceremony: CommunityCeremony) -> Vec<ParticipantIndexType> {
+		let max_level = T::MaxRegistrationProofs::get().max(1);
+		let mut starts = vec![0; max_level as usize + 1];
+		let mut start: ParticipantIndexType = 1;
+		for level in (2..=max_level).rev() {
+			starts[level as usize] = start;
+			start.saturating_accrue(Self::prioritized_reputable_count(community_ceremony, level));
+		}
+		starts[1] = start;
+		starts[0] = Self::reputable_count(community_ceremony).saturating_add(1);
+		starts
+	}
+
+	/// Moves the reputable at index `from` to index `to`.
+	fn move_reputable(
+		community_ceremony: CommunityCeremony,
+		from: ParticipantIndexType,
+		to: ParticipantIndexType,
+	) {
+		if from == to {
+			return;
+		}
+		if let Some(reputable) = <ReputableRegistry<T>>::take(community_ceremony, from) {
+			<ReputableRegistry<T>>::insert(community_ceremony, to, &reputable);
+			<ReputableIndex<T>>::insert(community_ceremony, &reputable, to);
+		}
+	}
+
+	/// Inserts a reputable at the end of the block of reputables with as many proven
+	/// attendances. Makes room by moving the first reputable of each block with fewer proven
+	/// attendances to the end of its block, so registering takes a bounded number of writes.
+	fn insert_reputable(
+		community_ceremony: CommunityCeremony,
+		reputable: &T::AccountId,
+		proven_attendances: u32,
+	) -> Result<(), Error<T>> {
+		let count = Self::reputable_count(community_ceremony)
+			.checked_add(1)
+			.ok_or(Error::<T>::RegistryOverflow)?;
+		let level = proven_attendances.clamp(1, T::MaxRegistrationProofs::get().max(1));
+		let starts = Self::reputable_block_starts(community_ceremony);
+		let mut index = count;
+		for lower_level in 1..level {
+			let start = starts[lower_level as usize];
+			Self::move_reputable(community_ceremony, start, index);
+			index = start;
+		}
+		<ReputableRegistry<T>>::insert(community_ceremony, index, reputable);
+		<ReputableIndex<T>>::insert(community_ceremony, reputable, index);
+		<ReputableCount<T>>::insert(community_ceremony, count);
+		if level > 1 {
+			<PrioritizedReputableCount<T>>::mutate(community_ceremony, level, |c| {
+				c.saturating_inc()
+			});
+		}
+		Ok(())
+	}
+
+	/// Removes a reputable while keeping the indices continuous and the registry ordered. The gap
+	/// is closed by moving the last reputable of the reputable's block and of each block with
+	/// fewer proven attendances.
+	fn remove_reputable(community_ceremony: CommunityCeremony, reputable: &T::AccountId) {
+		let Ok(mut index) = <ReputableIndex<T>>::try_get(community_ceremony, reputable) else {
+			return;
+		};
+		let starts = Self::reputable_block_starts(community_ceremony);
+		let level = (1..starts.len())
+			.rev()
+			.find(|level| starts[*level] <= index && index < starts[*level - 1])
+			.unwrap_or(1);
+		<ReputableRegistry<T>>::remove(community_ceremony, index);
+		<ReputableIndex<T>>::remove(community_ceremony, reputable);
+		for block_level in (1..=level).rev() {
+			let last = starts[block_level - 1].saturating_sub(1);
+			Self::move_reputable(community_ceremony, last, index);
+			index = last;
+		}
+		<ReputableCount<T>>::insert(community_ceremony, starts[0].saturating_sub(2));
+		if level > 1 {
+			<PrioritizedReputableCount<T>>::mutate(community_ceremony, level as u32, |c| {
+				c.saturating_dec()
+			});
+		}
+	}
+
+	/// Marks the rewards of a meetup as issued and counts its result for the ceremony stats.
+	fn record_meetup_result(
+		cc: CommunityCeremony,
+		meetup_index: MeetupIndexType,
+		result: MeetupResult,
+	) {
+		<IssuedRewards<T>>::insert(cc, meetup_index, result);
+		<MeetupResultTally<T>>::mutate(cc, |counts| counts.add(result));
+	}
+
+	/// Records the statistics of ceremony `cindex` for all communities. To be called once reward
+	/// issuance for `cindex` has ended.
+	fn record_ceremony_stats(cindex: CeremonyIndexType) {
+		for cid in <pallet_encointer_communities::Pallet<T>>::community_identifiers().iter() {
+			let cc = (*cid, cindex);
+			let meetup_count = Self::meetup_count(cc);
+			let mut meetup_results = Self::meetup_result_tally(cc);
+			meetup_results.not_evaluated = meetup_count
+				.saturating_sub(meetup_results.ok)
+				.saturating_sub(meetup_results.votes_not_dependable)
+				.saturating_sub(meetup_results.meetup_validation_index_out_of_bounds);
+
+			let stats = CeremonyStats {
+				bootstrapper_count: Self::bootstrapper_count(cc),
+				reputable_count: Self::reputable_count(cc),
+				endorsee_count: Self::endorsee_count(cc),
+				newbie_count: Self::newbie_count(cc),
+				meetup_count,
+				meetup_results,
+				reputation_count: Self::reputation_count(cc),
+				issued: Self::issued_nominal_income(cc),
+			};
+			<CommunityCeremonyStats<T>>::insert(cid, cindex, stats);
+		}
 	}
 
 	fn generate_all_meetup_assignment_params() {
@@ -1619,57 +2537,92 @@ impl<T: Config> Pallet<T> {
 		let meetup_count = Self::meetup_count(community_ceremony);
 		let assignment_count = Self::assignment_counts(community_ceremony);
 
-		let assignment = Self::assignments(community_ceremony);
-
-		let participant_type = Self::get_participant_type(community_ceremony, participant)?;
+		let (group, participant_index) = Self::get_assignment_group_and_index(
+			community_ceremony,
+			participant,
+			&assignment_count,
+		)?;
 
-		let (participant_index, assignment_params) = match participant_type {
-			ParticipantType::Bootstrapper => {
-				let participant_index =
-					Self::bootstrapper_index(community_ceremony, participant) - 1;
-				if participant_index < assignment_count.bootstrappers {
-					(participant_index, assignment.bootstrappers_reputables)
-				} else {
-					return None;
-				}
-			},
-			ParticipantType::Reputable => {
-				let participant_index = Self::reputable_index(community_ceremony, participant) - 1;
-				if participant_index < assignment_count.reputables {
-					(
-						participant_index + assignment_count.bootstrappers,
-						assignment.bootstrappers_reputables,
-					)
-				} else {
-					return None;
-				}
+		let regional_assignments = Self::regional_assignments(community_ceremony);
+		let maybe_partitioned = if regional_assignments.is_empty() {
+			None
+		} else {
+			Self::partitioned_participant_index(community_ceremony, participant)
+		};
+		match maybe_partitioned {
+			Some((Some(region_index), index)) => {
+				let regional = regional_assignments.get(region_index as usize)?;
+				meetup_index(index, regional.params.params(group), regional.meetup_count)
+					.map(|i| i + regional.meetup_offset)
 			},
+			_ => meetup_index(
+				maybe_partitioned.map_or(participant_index, |(_, index)| index),
+				Self::assignments(community_ceremony).params(group),
+				Self::unpartitioned_meetup_count(meetup_count, &regional_assignments),
+			),
+		}
+	}
 
-			ParticipantType::Endorsee => {
-				let participant_index = Self::endorsee_index(community_ceremony, participant) - 1;
-				if participant_index < assignment_count.endorsees {
-					(participant_index, assignment.endorsees)
-				} else {
-					return None;
-				}
-			},
+	/// The assignment group of an assigned participant and their index within it, as given by
+	/// the registries.
+	fn get_assignment_group_and_index(
+		community_ceremony: CommunityCeremony,
+		participant: &T::AccountId,
+		assignment_count: &AssignmentCount,
+	) -> Option<(AssignmentGroup, ParticipantIndexType)> {
+		let participant_type = Self::get_participant_type(community_ceremony, participant)?;
 
-			ParticipantType::Newbie => {
-				let participant_index = Self::newbie_index(community_ceremony, participant) - 1;
-				if participant_index < assignment_count.newbies {
-					(participant_index, assignment.newbies)
-				} else {
-					return None;
-				}
-			},
+		let (group, participant_index, count) = match participant_type {
+			ParticipantType::Bootstrapper => (
+				AssignmentGroup::BootstrappersReputables,
+				Self::bootstrapper_index(community_ceremony, participant) - 1,
+				assignment_count.bootstrappers,
+			),
+			ParticipantType::Reputable => (
+				AssignmentGroup::BootstrappersReputables,
+				Self::reputable_index(community_ceremony, participant) - 1 +
+					assignment_count.bootstrappers,
+				assignment_count.bootstrappers + assignment_count.reputables,
+			),
+			ParticipantType::Endorsee => (
+				AssignmentGroup::Endorsees,
+				Self::endorsee_index(community_ceremony, participant) - 1,
+				assignment_count.endorsees,
+			),
+			ParticipantType::Newbie => (
+				AssignmentGroup::Newbies,
+				Self::newbie_index(community_ceremony, participant) - 1,
+				assignment_count.newbies,
+			),
 		};
+		(participant_index < count).then_some((group, participant_index))
+	}
 
-		meetup_index(participant_index, assignment_params, meetup_count)
+	/// Inverse of `get_assignment_group_and_index`.
+	fn get_participant_by_assignment_index(
+		community_ceremony: CommunityCeremony,
+		group: AssignmentGroup,
+		index: ParticipantIndexType,
+		assigned: &AssignmentCount,
+	) -> Option<T::AccountId> {
+		//safe; small number per meetup
+		match group {
+			AssignmentGroup::BootstrappersReputables if index < assigned.bootstrappers =>
+				Self::bootstrapper_registry(community_ceremony, index + 1),
+			AssignmentGroup::BootstrappersReputables
+				if index < assigned.bootstrappers + assigned.reputables =>
+				Self::reputable_registry(community_ceremony, index - assigned.bootstrappers + 1),
+			AssignmentGroup::Endorsees if index < assigned.endorsees =>
+				Self::endorsee_registry(community_ceremony, index + 1),
+			AssignmentGroup::Newbies if index < assigned.newbies =>
+				Self::newbie_registry(community_ceremony, index + 1),
+			_ => None,
+		}
 	}
 
 	fn get_meetup_participants(
 		community_ceremony: CommunityCeremony,
-		mut meetup_index: MeetupIndexType,
+		meetup_index: MeetupIndexType,
 	) -> Result<Vec<T::AccountId>, Error<T>> {
 		let mut result: Vec<T::AccountId> = vec![];
 		let meetup_count = Self::meetup_count(community_ceremony);
@@ -1682,71 +2635,67 @@ impl<T: Config> Pallet<T> {
 			return Err(<Error<T>>::InvalidMeetupIndex);
 		}
 
-		//safe; meetup index conversion from 1 based to 0 based
-		meetup_index -= 1;
+		let assigned = Self::assignment_counts(community_ceremony);
+		let regional_assignments = Self::regional_assignments(community_ceremony);
 
-		let params = Self::assignments(community_ceremony);
+		let (region_index, meetup_offset, partition_meetup_count, params, counts) =
+			match regional_assignments.iter().position(|r| r.contains_meetup(meetup_index)) {
+				Some(i) => {
+					let regional = &regional_assignments[i];
+					(
+						Some(i as RegionIndexType),
+						regional.meetup_offset,
+						regional.meetup_count,
+						regional.params,
+						regional.counts,
+					)
+				},
+				None => (
+					None,
+					0,
+					Self::unpartitioned_meetup_count(meetup_count, &regional_assignments),
+					Self::assignments(community_ceremony),
+					Self::unpartitioned_assignment_count(&assigned, &regional_assignments),
+				),
+			};
 
-		let assigned = Self::assignment_counts(community_ceremony);
+		//safe; meetup index conversion from 1 based to 0 based
+		let meetup_index = meetup_index - meetup_offset - 1;
 
-		let bootstrappers_reputables = assignment_fn_inverse(
-			meetup_index,
-			params.bootstrappers_reputables,
-			meetup_count,
-			assigned.bootstrappers + assigned.reputables,
-		)
-		.ok_or(<Error<T>>::GetMeetupParticipantsError)?;
-		for p in bootstrappers_reputables {
-			if p < assigned.bootstrappers {
-				//safe; small number per meetup
-				match Self::bootstrapper_registry(community_ceremony, p + 1) {
-					Some(bs) => result.push(bs),
-					None => error!(
-						target: LOG,
-						"[Ceremonies::get_meetup_participants] Bootstrapper not found!!"
-					),
-				}
-			} else if p < assigned.bootstrappers + assigned.reputables {
-				//safe; small number per meetup
-				match Self::reputable_registry(community_ceremony, p - assigned.bootstrappers + 1) {
-					Some(r) => result.push(r),
-					None => error!(
-						target: LOG,
-						"[Ceremonies::get_meetup_participants] Reputable not found!!"
-					),
+		for group in AssignmentGroup::ALL {
+			let participants = assignment_fn_inverse(
+				meetup_index,
+				params.params(group),
+				partition_meetup_count,
+				counts.of_group(group),
+			)
+			.ok_or(<Error<T>>::GetMeetupParticipantsError)?;
+			for p in participants {
+				let maybe_partitioned = if regional_assignments.is_empty() {
+					None
+				} else {
+					Self::partitioned_participant_registry(
+						community_ceremony,
+						(region_index, group, p),
+					)
 				};
-			}
-		}
-
-		let endorsees =
-			assignment_fn_inverse(meetup_index, params.endorsees, meetup_count, assigned.endorsees)
-				.ok_or(<Error<T>>::GetMeetupParticipantsError)?;
-		for p in endorsees {
-			if p < assigned.endorsees {
-				//safe; small number per meetup
-				match Self::endorsee_registry(community_ceremony, p + 1) {
-					Some(e) => result.push(e),
-					None => error!(
-						target: LOG,
-						"[Ceremonies::get_meetup_participants] Endorsee not found!!"
+				let maybe_participant = match (maybe_partitioned, region_index) {
+					(Some(participant), _) => Some(participant),
+					(None, None) => Self::get_participant_by_assignment_index(
+						community_ceremony,
+						group,
+						p,
+						&assigned,
 					),
+					(None, Some(_)) => None,
 				};
-			}
-		}
-
-		let newbies =
-			assignment_fn_inverse(meetup_index, params.newbies, meetup_count, assigned.newbies)
-				.ok_or(<Error<T>>::GetMeetupParticipantsError)?;
-		for p in newbies {
-			if p < assigned.newbies {
-				//safe; small number per meetup
-				match Self::newbie_registry(community_ceremony, p + 1) {
-					Some(n) => result.push(n),
+				match maybe_participant {
+					Some(participant) => result.push(participant),
 					None => error!(
 						target: LOG,
-						"[Ceremonies::get_meetup_participants] Newbie not found!!"
+						"[Ceremonies::get_meetup_participants] {group:?} participant {p} not found!!"
 					),
-				};
+				}
 			}
 		}
 
@@ -1757,14 +2706,31 @@ impl<T: Config> Pallet<T> {
 		cc: CommunityCeremony,
 		meetup_idx: MeetupIndexType,
 	) -> Option<Location> {
-		let locations = <pallet_encointer_communities::Pallet<T>>::get_locations(&cc.0);
+		if !Self::community_rules(&cc.0).has_meetup_locations() {
+			return None;
+		}
+		let regional_assignments = Self::regional_assignments(cc);
+		if let Some(regional) = regional_assignments.iter().find(|r| r.contains_meetup(meetup_idx))
+		{
+			let locations =
+				<pallet_encointer_communities::Pallet<T>>::locations(cc.0, &regional.region);
+			return meetup_location(
+				meetup_idx - regional.meetup_offset,
+				locations.to_vec(),
+				regional.params.locations,
+			);
+		}
+		let locations = Self::unpartitioned_locations(&cc.0, &regional_assignments);
 		let assignment_params = Self::assignments(cc).locations;
 
 		meetup_location(meetup_idx, locations, assignment_params)
 	}
 
 	// this function only works during ATTESTING, so we're keeping it for private use
-	pub(crate) fn get_meetup_time(location: Location) -> Option<T::Moment> {
+	pub(crate) fn get_meetup_time(
+		cid: CommunityIdentifier,
+		location: Location,
+	) -> Option<T::Moment> {
 		if !(<pallet_encointer_scheduler::Pallet<T>>::current_phase() ==
 			CeremonyPhaseType::Attesting)
 		{
@@ -1780,10 +2746,84 @@ impl<T: Config> Pallet<T> {
 			location,
 			start,
 			T::MomentsPerDay::get(),
-			Self::meetup_time_offset(),
+			<pallet_encointer_communities::Pallet<T>>::location_meetup_time_offset(cid, location)
+				.unwrap_or_else(Self::meetup_time_offset),
 		))
 	}
 
+	/// Returns the rule set the community `cid` runs its ceremonies with.
+	pub fn community_rules(cid: &CommunityIdentifier) -> CommunityRules {
+		<pallet_encointer_communities::Pallet<T>>::community_metadata(cid).rules
+	}
+
+	/// Returns the maximum distance [m] of a meetup from its assigned location for `cid`, or
+	/// `None` if the community's meetups have no assigned location.
+	pub fn meetup_location_tolerance(cid: &CommunityIdentifier) -> Option<u32> {
+		match Self::community_rules(cid) {
+			CommunityRules::LoCo => Some(Self::location_tolerance()),
+			CommunityRules::LoCoFlex =>
+				Some(Self::location_tolerance().saturating_mul(T::LoCoFlexToleranceFactor::get())),
+			CommunityRules::BeeDance => None,
+		}
+	}
+
+	/// Returns the maximum deviation of a meetup from its meetup time for `cid`, or `None` if
+	/// the community's meetups have no assigned time.
+	pub fn meetup_time_tolerance(cid: &CommunityIdentifier) -> Option<T::Moment> {
+		match Self::community_rules(cid) {
+			CommunityRules::LoCo => Some(Self::time_tolerance()),
+			CommunityRules::LoCoFlex => Some(
+				Self::time_tolerance().saturating_mul(T::LoCoFlexToleranceFactor::get().into()),
+			),
+			CommunityRules::BeeDance => None,
+		}
+	}
+
+	/// Ensures that `location` and `timestamp` are within the tolerances of the community's rules
+	/// around `meetup_location` and `meetup_time`:
+	///
+	/// * `LoCo`: within `LocationTolerance` [m] and `TimeTolerance` [ms].
+	/// * `LoCoFlex`: within both tolerances widened by `LoCoFlexToleranceFactor`.
+	/// * `BeeDance`: anywhere and anytime, as meetups have no assigned location and time.
+	pub fn ensure_within_meetup_tolerances(
+		cid: &CommunityIdentifier,
+		location: &Location,
+		timestamp: T::Moment,
+		meetup_location: &Location,
+		meetup_time: T::Moment,
+	) -> Result<(), Error<T>> {
+		if let Some(location_tolerance) = Self::meetup_location_tolerance(cid) {
+			ensure!(
+				<pallet_encointer_communities::Pallet<T>>::haversine_distance(
+					location,
+					meetup_location
+				) <= location_tolerance,
+				Error::<T>::ClaimOfAttendanceTooFarAway
+			);
+		}
+		if let Some(time_tolerance) = Self::meetup_time_tolerance(cid) {
+			let time_deviation = if timestamp > meetup_time {
+				timestamp - meetup_time
+			} else {
+				meetup_time - timestamp
+			};
+			ensure!(time_deviation <= time_tolerance, Error::<T>::StaleClaimOfAttendance);
+		}
+		Ok(())
+	}
+
+	/// Returns the policy by which the meetups of `cid` are validated. Unless it has been set for
+	/// the community, it is derived from the community's rules.
+	pub fn attestation_policy(cid: &CommunityIdentifier) -> AttestationPolicyKind {
+		Self::attestation_policy_override(cid).unwrap_or_else(|| {
+			match Self::community_rules(cid) {
+				CommunityRules::LoCo | CommunityRules::LoCoFlex => AttestationPolicyKind::Default,
+				// without a common location, only full mutual attestation is dependable
+				CommunityRules::BeeDance => AttestationPolicyKind::FullGraph,
+			}
+		})
+	}
+
 	/// Returns the community-specific nominal income if it is set. Otherwise returns the
 	/// the ceremony reward defined in the genesis config.
 	pub fn nominal_income(cid: &CommunityIdentifier) -> NominalIncome {
@@ -1791,6 +2831,180 @@ impl<T: Config> Pallet<T> {
 			.unwrap_or_else(|_| Self::ceremony_reward())
 	}
 
+	/// Evaluates the meetups of the previous ceremony which nobody has claimed rewards for, as
+	/// far as `remaining_weight` allows. Continues where the previous block left off.
+	pub fn issue_pending_rewards(mut remaining_weight: Weight) -> Weight {
+		let mut consumed = T::DbWeight::get().reads(1);
+		let current_phase = <pallet_encointer_scheduler::Pallet<T>>::current_phase();
+		if current_phase != CeremonyPhaseType::Registering {
+			return consumed;
+		}
+		let Some((cindex, mut cid, mut meetup_index)) = Self::reward_issuance_cursor() else {
+			return consumed;
+		};
+		remaining_weight = remaining_weight.saturating_sub(consumed);
+
+		let step_weight =
+			<T as Config>::WeightInfo::claim_rewards().saturating_add(T::DbWeight::get().reads(2));
+
+		while !remaining_weight.any_lt(step_weight) {
+			if meetup_index > Self::meetup_count((cid, cindex)) {
+				let Some(next_cid) = Self::next_community(Some(cid)) else {
+					info!(target: LOG, "reward issuance for cindex {cindex} completed");
+					<RewardIssuanceCursor<T>>::kill();
+					return consumed.saturating_add(T::DbWeight::get().writes(1));
+				};
+				cid = next_cid;
+				meetup_index = 1;
+			} else {
+				if !<IssuedRewards<T>>::contains_key((cid, cindex), meetup_index) {
+					trace!(
+						target: LOG,
+						"issuing pending rewards for meetup {meetup_index:?}, cid: {cid:?}"
+					);
+					// failures are recorded in `IssuedRewards` by the evaluation itself
+					let _ = Self::evaluate_meetup(cid, cindex, meetup_index, current_phase);
+				}
+				meetup_index.saturating_inc();
+			}
+			consumed.saturating_accrue(step_weight);
+			remaining_weight.saturating_reduce(step_weight);
+		}
+		<RewardIssuanceCursor<T>>::put((cindex, cid, meetup_index));
+		consumed.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Returns the community with the smallest identifier greater than `maybe_cid`, or the
+	/// smallest one if `None`.
+	///
+	/// Unlike positions in the community identifiers, this order is not disturbed by
+	/// communities being added or removed.
+	fn next_community(maybe_cid: Option<CommunityIdentifier>) -> Option<CommunityIdentifier> {
+		<pallet_encointer_communities::Pallet<T>>::community_identifiers()
+			.into_iter()
+			.filter(|c| maybe_cid.is_none_or(|cid| *c > cid))
+			.min()
+	}
+
+	/// Evaluates a meetup of `cindex` and issues rewards to all legit participants.
+	///
+	/// In the attesting phase, failing evaluations are not recorded, because a later
+	/// attempt might still succeed.
+	fn evaluate_meetup(
+		cid: CommunityIdentifier,
+		cindex: CeremonyIndexType,
+		meetup_index: MeetupIndexType,
+		current_phase: CeremonyPhaseType,
+	) -> DispatchResultWithPostInfo {
+		if <IssuedRewards<T>>::contains_key((cid, cindex), meetup_index) {
+			return Err(<Error<T>>::RewardsAlreadyIssued.into());
+		}
+
+		//gather all data
+		let meetup_participants = Self::get_meetup_participants((cid, cindex), meetup_index)?;
+		let (participant_votes, participant_attestations) =
+			Self::gather_meetup_validation_data(cid, cindex, meetup_participants.clone());
+
+		// initialize an array of local participant indices that are eligible for the reward
+		// indices will be deleted in the following based on various rules
+		let mut participants_eligible_for_rewards: Vec<usize> =
+			(0..meetup_participants.len()).collect();
+
+		let participant_vote_weights: Vec<u32> = meetup_participants
+			.iter()
+			.map(|p| Self::proven_attendance_count((cid, cindex), p))
+			.collect();
+
+		let participant_judgements = match get_weighted_participant_judgements(
+			&participants_eligible_for_rewards,
+			&participant_votes,
+			&participant_vote_weights,
+			&participant_attestations,
+			Self::attestation_policy(&cid).policy(),
+		) {
+			Ok(participant_judgements) => participant_judgements,
+			// handle errors
+			Err(err) => {
+				let (error, meetup_result) = match err {
+					MeetupValidationError::BallotEmpty => {
+						debug!(
+							target: LOG,
+							"ballot empty for meetup {meetup_index:?}, cid: {cid:?}"
+						);
+						(
+							Err(<Error<T>>::VotesNotDependable.into()),
+							MeetupResult::VotesNotDependable,
+						)
+					},
+					MeetupValidationError::NoDependableVote => {
+						debug!(
+							target: LOG,
+							"ballot doesn't reach dependable majority for meetup {meetup_index:?}, cid: {cid:?}",
+						);
+						(
+							Err(<Error<T>>::VotesNotDependable.into()),
+							MeetupResult::VotesNotDependable,
+						)
+					},
+					MeetupValidationError::IndexOutOfBounds => {
+						debug!(
+							target: LOG,
+							"index out of bounds for meetup {meetup_index:?}, cid: {cid:?}"
+						);
+						(
+							Err(<Error<T>>::MeetupValidationIndexOutOfBounds.into()),
+							MeetupResult::MeetupValidationIndexOutOfBounds,
+						)
+					},
+				};
+				// only mark issuance as complete in registering phase
+				// because in attesting phase there could be a failing early payout attempt
+				if current_phase == CeremonyPhaseType::Registering {
+					info!(target: LOG, "marking issuance as completed for failed meetup.");
+
+					Self::record_meetup_result((cid, cindex), meetup_index, meetup_result);
+					Self::deposit_event(Event::MeetupEvaluated(cid, meetup_index, meetup_result));
+					return Ok(Pays::No.into());
+				} else {
+					return error;
+				}
+			},
+		};
+		if current_phase == CeremonyPhaseType::Attesting &&
+			!participant_judgements.early_rewards_possible
+		{
+			debug!(
+				target: LOG,
+				"early rewards not possible for meetup {meetup_index:?}, cid: {cid:?}"
+			);
+			return Err(<Error<T>>::EarlyRewardsNotPossible.into());
+		}
+		participants_eligible_for_rewards = participant_judgements.legit;
+		// emit events
+		for p in participant_judgements.excluded {
+			let participant = meetup_participants
+				.get(p.index)
+				.ok_or(Error::<T>::MeetupValidationIndexOutOfBounds)?
+				.clone();
+			Self::deposit_event(Event::NoReward {
+				cid,
+				cindex,
+				meetup_index,
+				account: participant,
+				reason: p.reason,
+			});
+		}
+
+		Self::issue_rewards(
+			cid,
+			cindex,
+			meetup_index,
+			meetup_participants,
+			participants_eligible_for_rewards,
+		)?;
+		Ok(Pays::No.into())
+	}
+
 	fn issue_rewards(
 		cid: CommunityIdentifier,
 		cindex: CeremonyIndexType,
@@ -1800,6 +3014,7 @@ impl<T: Config> Pallet<T> {
 	) -> Result<(), Error<T>> {
 		let reward = Self::nominal_income(&cid);
 		let mut reputation_count = 0;
+		let mut issued = NominalIncome::from_num(0);
 		for i in &participants_indices {
 			let participant = &meetup_participants
 				.get(*i)
@@ -1813,13 +3028,15 @@ impl<T: Config> Pallet<T> {
 					Reputation::VerifiedUnlinked,
 				);
 				reputation_count += 1;
+				issued = issued.saturating_add(reward);
 			}
 			sp_io::offchain_index::set(&reputation_cache_dirty_key(participant), &true.encode());
 		}
 		<ReputationCount<T>>::mutate((&cid, cindex), |b| *b += reputation_count); // safe, as reputation_count is limited by the number of locations available on earth
 		<GlobalReputationCount<T>>::mutate(cindex, |b| *b += reputation_count); // safe, as reputation_count is limited by the number of locations available on earth
+		<IssuedNominalIncome<T>>::mutate((&cid, cindex), |i| *i = i.saturating_add(issued));
 
-		<IssuedRewards<T>>::insert((cid, cindex), meetup_idx, MeetupResult::Ok);
+		Self::record_meetup_result((cid, cindex), meetup_idx, MeetupResult::Ok);
 		info!(target: LOG, "issuing rewards completed");
 
 		Self::deposit_event(Event::RewardsIssued(
@@ -1857,21 +3074,111 @@ impl<T: Config> Pallet<T> {
 		(participant_votes, participant_attestations)
 	}
 
-	fn gather_meetup_data(
+	fn gather_meetup_participants(
 		cid: &CommunityIdentifier,
 		participant: &T::AccountId,
-	) -> Result<MeetupData<T::AccountId, T::Moment>, Error<T>> {
+	) -> Result<(CeremonyIndexType, MeetupIndexType, Vec<T::AccountId>), Error<T>> {
 		let cindex = <pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index();
 
 		let meetup_index = Self::get_meetup_index((*cid, cindex), participant)
 			.ok_or(Error::<T>::ParticipantIsNotRegistered)?;
 		let meetup_participants = Self::get_meetup_participants((*cid, cindex), meetup_index)?;
+		Ok((cindex, meetup_index, meetup_participants))
+	}
+
+	/// Gathers the data of the meetup `participant` is assigned to. The meetup location and time
+	/// are only gathered if the community's rules assign them.
+	fn gather_meetup_data_for_rules(
+		cid: &CommunityIdentifier,
+		participant: &T::AccountId,
+	) -> Result<
+		(CeremonyIndexType, MeetupIndexType, Vec<T::AccountId>, Option<(Location, T::Moment)>),
+		Error<T>,
+	> {
+		if Self::community_rules(cid).has_meetup_locations() {
+			let (cindex, meetup_index, meetup_participants, meetup_location, meetup_time) =
+				Self::gather_meetup_data(cid, participant)?;
+			Ok((cindex, meetup_index, meetup_participants, Some((meetup_location, meetup_time))))
+		} else {
+			let (cindex, meetup_index, meetup_participants) =
+				Self::gather_meetup_participants(cid, participant)?;
+			Ok((cindex, meetup_index, meetup_participants, None))
+		}
+	}
+
+	fn verify_claim_of_attendance(
+		claim: &ClaimOfAttendanceOf<T>,
+		cid: &CommunityIdentifier,
+		cindex: CeremonyIndexType,
+		meetup_index: MeetupIndexType,
+		meetup_location_and_time: Option<(Location, T::Moment)>,
+	) -> Result<(), Error<T>> {
+		ensure!(claim.verify_signature(), Error::<T>::BadClaimOfAttendanceSignature);
+		ensure!(
+			claim.community_identifier == *cid &&
+				claim.ceremony_index == cindex &&
+				claim.meetup_index == meetup_index,
+			Error::<T>::ClaimOfAttendanceForWrongMeetup
+		);
+		match meetup_location_and_time {
+			Some((meetup_location, meetup_time)) => Self::ensure_within_meetup_tolerances(
+				cid,
+				&claim.location,
+				claim.timestamp,
+				&meetup_location,
+				meetup_time,
+			),
+			None => Ok(()),
+		}
+	}
+
+	#[allow(clippy::too_many_arguments)]
+	fn register_attestations(
+		participant: T::AccountId,
+		cid: &CommunityIdentifier,
+		cindex: CeremonyIndexType,
+		meetup_index: MeetupIndexType,
+		meetup_participants: &[T::AccountId],
+		number_of_participants_vote: u32,
+		attestations: &[T::AccountId],
+	) -> Result<(), Error<T>> {
+		ensure!(attestations.len() < meetup_participants.len(), Error::<T>::TooManyAttestations);
+
+		debug!(
+			target: LOG,
+			"{:?} attempts to submit {:?} attestations",
+			participant,
+			attestations.len()
+		);
+
+		<MeetupParticipantCountVote<T>>::insert(
+			(cid, cindex),
+			&participant,
+			number_of_participants_vote,
+		);
+
+		Self::add_attestations_to_registry(
+			participant,
+			cid,
+			cindex,
+			meetup_index,
+			meetup_participants,
+			attestations,
+		)
+	}
+
+	fn gather_meetup_data(
+		cid: &CommunityIdentifier,
+		participant: &T::AccountId,
+	) -> Result<MeetupData<T::AccountId, T::Moment>, Error<T>> {
+		let (cindex, meetup_index, meetup_participants) =
+			Self::gather_meetup_participants(cid, participant)?;
 
 		let meetup_location = Self::get_meetup_location((*cid, cindex), meetup_index)
 			.ok_or(Error::<T>::MeetupLocationNotFound)?;
 
-		let meetup_time =
-			Self::get_meetup_time(meetup_location).ok_or(Error::<T>::MeetupTimeCalculationError)?;
+		let meetup_time = Self::get_meetup_time(*cid, meetup_location)
+			.ok_or(Error::<T>::MeetupTimeCalculationError)?;
 
 		Ok((cindex, meetup_index, meetup_participants, meetup_location, meetup_time))
 	}
@@ -1984,6 +3291,13 @@ impl<T: Config> OnCeremonyPhaseChange for Pallet<T> {
 	fn on_ceremony_phase_change(new_phase: CeremonyPhaseType) {
 		match new_phase {
 			CeremonyPhaseType::Assigning => {
+				// Inactivity is judged from the issued rewards. Whatever the sweep did not reach
+				// by now is considered unclaimed.
+				<RewardIssuanceCursor<T>>::kill();
+				let cindex = <pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index();
+				if cindex > 1 {
+					Self::record_ceremony_stats(cindex.saturating_sub(1));
+				}
 				let inactives = Self::update_inactivity_counters(
 					<pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index()
 						.saturating_sub(1),
@@ -1999,6 +3313,11 @@ impl<T: Config> OnCeremonyPhaseChange for Pallet<T> {
 			CeremonyPhaseType::Attesting => {},
 			CeremonyPhaseType::Registering => {
 				let cindex = <pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index();
+				// Rewards which have not been claimed during the last ceremony are issued in
+				// `on_idle`.
+				if let Some(cid) = Self::next_community(None) {
+					<RewardIssuanceCursor<T>>::put((cindex.saturating_sub(1), cid, 1));
+				}
 				// Clean up with a time delay, such that participants can claim their UBI in the
 				// following cycle.
 				if cindex > Self::reputation_lifetime() {
@@ -2011,6 +3330,22 @@ impl<T: Config> OnCeremonyPhaseChange for Pallet<T> {
 	}
 }
 
+impl<T: Config> OnBootstrappersChanged<T::AccountId> for Pallet<T> {
+	// burned newbie tickets are kept, so that a bootstrapper which is removed and added again
+	// can not endorse more newbies
+	fn on_bootstrapper_removed(cid: CommunityIdentifier, bootstrapper: &T::AccountId) {
+		Self::revoke_bootstrapper_registration(cid, bootstrapper);
+	}
+
+	fn on_bootstrapper_replaced(cid: CommunityIdentifier, old: &T::AccountId, new: &T::AccountId) {
+		Self::revoke_bootstrapper_registration(cid, old);
+		// the replacement must not be able to endorse more newbies than the bootstrapper it
+		// replaces
+		let burned = <BurnedBootstrapperNewbieTickets<T>>::get(cid, old);
+		<BurnedBootstrapperNewbieTickets<T>>::mutate(cid, new, |b| *b = max(*b, burned));
+	}
+}
+
 mod weights;
 
 #[cfg(feature = "runtime-benchmarks")]
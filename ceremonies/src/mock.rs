@@ -36,6 +36,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		EncointerScheduler: pallet_encointer_scheduler::{Pallet, Call, Storage, Config<T>, Event},
 		EncointerCeremonies: dut::{Pallet, Call, Storage, Config<T>, Event<T>},
 		EncointerCommunities: pallet_encointer_communities::{Pallet, Call, Storage, Event<T>},
@@ -63,14 +64,19 @@ impl dut::Config for TestRuntime {
 	type MeetupSizeTarget = MeetupSizeTarget;
 	type MeetupMinSize = MeetupMinSize;
 	type MeetupNewbieLimitDivider = MeetupNewbieLimitDivider;
+	type MinRegionalMeetupCount = frame_support::traits::ConstU64<2>;
 	type WeightInfo = ();
 	type MaxAttestations = ConstU32<25>;
+	type LoCoFlexToleranceFactor = ConstU32<3>;
+	type MaxDelegatedNewbieTickets = frame_support::traits::ConstU8<5>;
+	type MaxRegistrationProofs = ConstU32<3>;
 }
 
 // boilerplate
 impl_frame_system!(TestRuntime);
 impl_timestamp!(TestRuntime, EncointerScheduler);
-impl_encointer_communities!(TestRuntime);
+impl_balances!(TestRuntime, System);
+impl_encointer_communities!(TestRuntime, EncointerCeremonies);
 impl_encointer_scheduler!(TestRuntime, EncointerCeremonies);
 impl_encointer_balances!(TestRuntime);
 
@@ -21,12 +21,16 @@
 use super::*;
 use approx::assert_abs_diff_eq;
 use encointer_primitives::{
-	communities::{CommunityIdentifier, Degree, Location, LossyInto},
+	communities::{
+		CommunityIdentifier, CommunityMetadata, CommunityRules, Degree, GeoHash, Location,
+		LossyInto,
+	},
 	scheduler::{CeremonyIndexType, CeremonyPhaseType},
 };
 use frame_support::{
 	assert_err, assert_ok,
-	traits::{OnFinalize, OnInitialize},
+	traits::{OnFinalize, OnIdle, OnInitialize},
+	weights::Weight,
 };
 use itertools::Itertools;
 use mock::{
@@ -37,7 +41,11 @@ use pallet_encointer_balances::Event as BalancesEvent;
 use rstest::*;
 use sp_core::{bounded_vec, sr25519, Pair, H256};
 use sp_runtime::{traits::BlakeTwo256, DispatchError};
-use std::{collections::BTreeSet, ops::Rem, str::FromStr};
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	ops::Rem,
+	str::FromStr,
+};
 use test_utils::{
 	helpers::{
 		account_id, add_population, assert_dispatch_err, bootstrappers, event_at_index,
@@ -1042,6 +1050,190 @@ fn claim_rewards_can_be_called_by_non_participant() {
 	});
 }
 
+/// Registers and fully attests a meetup of six bootstrappers, then runs to the registering phase.
+fn perform_ceremony_without_claiming(cid: CommunityIdentifier) {
+	register_alice_bob_ferdie(cid);
+	register_charlie_dave_eve(cid);
+	run_to_next_phase();
+	// Assigning
+	run_to_next_phase();
+	// Attesting
+	fully_attest_meetup(cid, 1);
+	run_to_next_phase();
+	// Registering
+}
+
+#[test]
+fn unclaimed_rewards_are_issued_in_on_idle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		perform_ceremony_without_claiming(cid);
+		assert_eq!(EncointerCeremonies::reward_issuance_cursor(), Some((cindex, cid, 1)));
+
+		EncointerCeremonies::on_idle(System::block_number(), Weight::MAX);
+
+		assert!(event_deposited::<TestRuntime>(Event::RewardsIssued(cid, 1, 6).into()));
+		assert_eq!(IssuedRewards::<TestRuntime>::get((cid, cindex), 1), Some(MeetupResult::Ok));
+		assert_eq!(EncointerCeremonies::reputation_count((cid, cindex)), 6);
+		assert_eq!(EncointerCeremonies::reward_issuance_cursor(), None);
+		// a late claim finds the rewards issued already
+		assert_dispatch_err(
+			EncointerCeremonies::claim_rewards(
+				RuntimeOrigin::signed(account_id(&AccountKeyring::Alice.pair())),
+				cid,
+				None,
+			),
+			Error::<TestRuntime>::RewardsAlreadyIssued.into(),
+		);
+	});
+}
+
+#[test]
+fn ceremony_stats_are_recorded_once_reward_issuance_ends() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		perform_ceremony_without_claiming(cid);
+		assert_ok!(EncointerCeremonies::claim_rewards(
+			RuntimeOrigin::signed(account_id(&AccountKeyring::Alice.pair())),
+			cid,
+			None,
+		));
+		assert_eq!(EncointerCeremonies::ceremony_stats(cid, cindex), None);
+		assert_eq!(EncointerCeremonies::meetup_result_tally((cid, cindex)).ok, 1);
+
+		run_to_next_phase();
+		// Assigning
+
+		let stats = EncointerCeremonies::ceremony_stats(cid, cindex).unwrap();
+		assert_eq!(
+			stats,
+			CeremonyStats {
+				bootstrapper_count: 6,
+				reputable_count: 0,
+				endorsee_count: 0,
+				newbie_count: 0,
+				meetup_count: 1,
+				meetup_results: MeetupResultCounts { ok: 1, ..Default::default() },
+				reputation_count: 6,
+				issued: EncointerCeremonies::nominal_income(&cid) * 6,
+			}
+		);
+
+		// the stats outlive the purged registry
+		for _ in 0..=EncointerCeremonies::reputation_lifetime() {
+			IssuedRewards::<TestRuntime>::insert(
+				(cid, EncointerScheduler::current_ceremony_index()),
+				0,
+				MeetupResult::Ok,
+			);
+			run_to_next_phase();
+			run_to_next_phase();
+			run_to_next_phase();
+		}
+		assert_eq!(EncointerCeremonies::bootstrapper_count((cid, cindex)), 0);
+		assert_eq!(EncointerCeremonies::ceremony_stats(cid, cindex), Some(stats));
+	});
+}
+
+#[test]
+fn ceremony_stats_count_unclaimed_meetups_as_not_evaluated() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		perform_ceremony_without_claiming(cid);
+
+		run_to_next_phase();
+		// Assigning
+
+		let stats = EncointerCeremonies::ceremony_stats(cid, cindex).unwrap();
+		assert_eq!(stats.meetup_count, 1);
+		assert_eq!(
+			stats.meetup_results,
+			MeetupResultCounts { not_evaluated: 1, ..Default::default() }
+		);
+		assert_eq!(stats.reputation_count, 0);
+		assert_eq!(stats.issued, NominalIncome::from_num(0));
+	});
+}
+
+#[test]
+fn reward_issuance_in_on_idle_is_bounded_by_weight() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		perform_ceremony_without_claiming(cid);
+		let step_weight = <TestRuntime as Config>::WeightInfo::claim_rewards();
+
+		EncointerCeremonies::on_idle(
+			System::block_number(),
+			step_weight.saturating_sub(Weight::from_parts(1, 0)),
+		);
+		assert!(!IssuedRewards::<TestRuntime>::contains_key((cid, cindex), 1));
+		assert_eq!(EncointerCeremonies::reward_issuance_cursor(), Some((cindex, cid, 1)));
+
+		EncointerCeremonies::on_idle(System::block_number(), step_weight);
+		assert!(IssuedRewards::<TestRuntime>::contains_key((cid, cindex), 1));
+		assert_eq!(EncointerCeremonies::reward_issuance_cursor(), Some((cindex, cid, 2)));
+
+		// finding no further community ends the sweep
+		EncointerCeremonies::on_idle(System::block_number(), step_weight);
+		assert_eq!(EncointerCeremonies::reward_issuance_cursor(), None);
+	});
+}
+
+#[test]
+fn reward_issuance_survives_purge_of_swept_community() {
+	new_test_ext().execute_with(|| {
+		let cid_a = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let cid_b = register_test_community::<TestRuntime>(None, 1.0, 1.0);
+		let (first, second) = if cid_a < cid_b { (cid_a, cid_b) } else { (cid_b, cid_a) };
+		let cindex = EncointerScheduler::current_ceremony_index();
+		for cid in [first, second] {
+			register_alice_bob_ferdie(cid);
+			register_charlie_dave_eve(cid);
+		}
+		run_to_next_phase();
+		// Assigning
+		run_to_next_phase();
+		// Attesting
+		fully_attest_meetup(first, 1);
+		fully_attest_meetup(second, 1);
+		run_to_next_phase();
+		// Registering
+		assert_eq!(EncointerCeremonies::reward_issuance_cursor(), Some((cindex, first, 1)));
+
+		let step_weight = <TestRuntime as Config>::WeightInfo::claim_rewards();
+		EncointerCeremonies::on_idle(System::block_number(), step_weight);
+		assert!(IssuedRewards::<TestRuntime>::contains_key((first, cindex), 1));
+		assert_eq!(EncointerCeremonies::reward_issuance_cursor(), Some((cindex, first, 2)));
+
+		EncointerCeremonies::purge_community(first);
+		assert_eq!(EncointerCeremonies::reward_issuance_cursor(), Some((cindex, second, 1)));
+
+		EncointerCeremonies::on_idle(System::block_number(), Weight::MAX);
+		assert!(IssuedRewards::<TestRuntime>::contains_key((second, cindex), 1));
+		assert_eq!(EncointerCeremonies::reward_issuance_cursor(), None);
+	});
+}
+
+#[test]
+fn reward_issuance_is_abandoned_when_registering_phase_ends() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		perform_ceremony_without_claiming(cid);
+
+		run_to_next_phase();
+		// Assigning
+		assert_eq!(EncointerCeremonies::reward_issuance_cursor(), None);
+		EncointerCeremonies::on_idle(System::block_number(), Weight::MAX);
+		assert!(!IssuedRewards::<TestRuntime>::contains_key((cid, cindex), 1));
+	});
+}
+
 #[test]
 fn early_rewards_works() {
 	new_test_ext().execute_with(|| {
@@ -1308,6 +1500,235 @@ fn register_with_reputation_works() {
 	});
 }
 
+/// Proofs of attendance of `p` for each of the `n` ceremonies before the current one.
+fn make_reputable_and_get_proofs(
+	p: &sr25519::Pair,
+	cid: CommunityIdentifier,
+	n: u32,
+) -> BoundedVec<TestProofOfAttendance, <TestRuntime as Config>::MaxRegistrationProofs> {
+	let cindex = EncointerScheduler::current_ceremony_index();
+	BoundedVec::truncate_from(
+		(1..=n)
+			.map(|i| make_reputable_and_get_proof(p, cid, cindex - i))
+			.collect::<Vec<_>>(),
+	)
+}
+
+#[test]
+fn register_with_proofs_works() {
+	new_test_ext().execute_with(|| {
+		let cid = perform_bootstrapping_ceremony(None, 1);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let zoran = sr25519::Pair::from_seed_slice(&[9u8; 32]).unwrap();
+		let proofs = make_reputable_and_get_proofs(&zoran, cid, 2);
+
+		assert_ok!(EncointerCeremonies::register_participant_with_proofs(
+			RuntimeOrigin::signed(account_id(&zoran)),
+			cid,
+			proofs
+		));
+
+		assert_eq!(EncointerCeremonies::reputable_count((cid, cindex)), 1);
+		assert_eq!(
+			EncointerCeremonies::participant_reputation((cid, cindex), account_id(&zoran)),
+			Reputation::UnverifiedReputable
+		);
+		for c in [cindex - 1, cindex - 2] {
+			assert_eq!(
+				EncointerCeremonies::participant_reputation((cid, c), account_id(&zoran)),
+				Reputation::VerifiedLinked(cindex)
+			);
+		}
+		assert_eq!(
+			EncointerCeremonies::proven_attendances((cid, cindex), account_id(&zoran)).into_inner(),
+			vec![(cid, cindex - 1), (cid, cindex - 2)]
+		);
+		assert_eq!(
+			EncointerCeremonies::proven_attendance_count((cid, cindex), &account_id(&zoran)),
+			2
+		);
+	});
+}
+
+#[test]
+fn register_with_proofs_fails_for_invalid_proofs() {
+	new_test_ext().execute_with(|| {
+		let cid = perform_bootstrapping_ceremony(None, 1);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let zoran = sr25519::Pair::from_seed_slice(&[9u8; 32]).unwrap();
+		let yuri = sr25519::Pair::from_seed_slice(&[10u8; 32]).unwrap();
+		let zoran_proof = make_reputable_and_get_proof(&zoran, cid, cindex - 1);
+
+		// reputation of others can't be combined
+		let yuri_proof = make_reputable_and_get_proof(&yuri, cid, cindex - 2);
+		let foreign_proof = prove_attendance(account_id(&zoran), cid, cindex - 2, &yuri);
+		assert_err!(
+			EncointerCeremonies::register_participant_with_proofs(
+				RuntimeOrigin::signed(account_id(&zoran)),
+				cid,
+				bounded_vec![zoran_proof.clone(), foreign_proof]
+			),
+			Error::<TestRuntime>::ProofOfForeignAttendance
+		);
+
+		assert_err!(
+			EncointerCeremonies::register_participant_with_proofs(
+				RuntimeOrigin::signed(account_id(&zoran)),
+				cid,
+				bounded_vec![zoran_proof.clone(), zoran_proof.clone()]
+			),
+			Error::<TestRuntime>::DuplicateProofCeremony
+		);
+
+		// a proof for a ceremony without attendance fails the whole registration
+		let unproven = prove_attendance(account_id(&zoran), cid, cindex - 2, &zoran);
+		assert_err!(
+			EncointerCeremonies::register_participant_with_proofs(
+				RuntimeOrigin::signed(account_id(&zoran)),
+				cid,
+				bounded_vec![zoran_proof, unproven]
+			),
+			Error::<TestRuntime>::AttendanceUnverifiedOrAlreadyUsed
+		);
+		assert_eq!(
+			EncointerCeremonies::participant_reputation((cid, cindex - 1), account_id(&zoran)),
+			Reputation::VerifiedUnlinked
+		);
+		assert!(!EncointerCeremonies::is_registered(cid, cindex, &account_id(&zoran)));
+		assert_ok!(register(account_id(&yuri), cid, Some(yuri_proof)));
+	});
+}
+
+#[test]
+fn unregister_refunds_all_proven_attendances() {
+	new_test_ext().execute_with(|| {
+		let cid = perform_bootstrapping_ceremony(None, 1);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let zoran = sr25519::Pair::from_seed_slice(&[9u8; 32]).unwrap();
+		let proofs = make_reputable_and_get_proofs(&zoran, cid, 2);
+		assert_ok!(EncointerCeremonies::register_participant_with_proofs(
+			RuntimeOrigin::signed(account_id(&zoran)),
+			cid,
+			proofs
+		));
+
+		assert_ok!(EncointerCeremonies::unregister_participant(
+			RuntimeOrigin::signed(account_id(&zoran)),
+			cid,
+			Some((cid, cindex - 1)),
+		));
+
+		for c in [cindex - 1, cindex - 2] {
+			assert_eq!(
+				EncointerCeremonies::participant_reputation((cid, c), account_id(&zoran)),
+				Reputation::VerifiedUnlinked
+			);
+		}
+		assert!(
+			EncointerCeremonies::proven_attendances((cid, cindex), account_id(&zoran)).is_empty()
+		);
+	});
+}
+
+#[test]
+fn reputables_with_more_proven_attendances_are_assigned_first() {
+	new_test_ext().execute_with(|| {
+		let cid = perform_bootstrapping_ceremony(None, 1);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		bootstrappers().iter().for_each(|b| {
+			assert_ok!(register(account_id(b), cid, None));
+		});
+
+		// one location with 6 bootstrappers leaves 9 seats for reputables
+		let reputables: Vec<sr25519::Pair> =
+			(20u8..30).map(|s| sr25519::Pair::from_seed_slice(&[s; 32]).unwrap()).collect();
+		for r in reputables[..9].iter() {
+			assert_ok!(register_as_reputable(r, cid));
+		}
+		let veteran = &reputables[9];
+		assert_ok!(EncointerCeremonies::register_participant_with_proofs(
+			RuntimeOrigin::signed(account_id(veteran)),
+			cid,
+			make_reputable_and_get_proofs(veteran, cid, 2)
+		));
+
+		run_to_next_phase();
+		// Assigning
+
+		assert_eq!(EncointerCeremonies::reputable_count((cid, cindex)), 10);
+		assert_eq!(EncointerCeremonies::reputable_index((cid, cindex), account_id(veteran)), 1);
+		assert!(
+			EncointerCeremonies::get_meetup_index((cid, cindex), &account_id(veteran)).is_some()
+		);
+		// the veteran took the place of the first reputable with a single proof, who doesn't get
+		// a seat anymore
+		assert_eq!(
+			EncointerCeremonies::reputable_index((cid, cindex), account_id(&reputables[0])),
+			10
+		);
+		assert_eq!(
+			EncointerCeremonies::get_meetup_index((cid, cindex), &account_id(&reputables[0])),
+			None
+		);
+		assert!(EncointerCeremonies::get_meetup_index((cid, cindex), &account_id(&reputables[8]))
+			.is_some());
+	});
+}
+
+#[test]
+fn reputable_registry_stays_ordered_by_proven_attendances() {
+	new_test_ext().execute_with(|| {
+		let cid = perform_bootstrapping_ceremony(None, 1);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let cc = (cid, cindex);
+		let pairs: Vec<sr25519::Pair> =
+			(20u8..25).map(|s| sr25519::Pair::from_seed_slice(&[s; 32]).unwrap()).collect();
+		for (i, p) in pairs.iter().enumerate() {
+			if i % 2 == 0 {
+				assert_ok!(register_as_reputable(p, cid));
+			} else {
+				assert_ok!(EncointerCeremonies::register_participant_with_proofs(
+					RuntimeOrigin::signed(account_id(p)),
+					cid,
+					make_reputable_and_get_proofs(p, cid, 2)
+				));
+			}
+		}
+		let index_of = |p: &sr25519::Pair| EncointerCeremonies::reputable_index(cc, account_id(p));
+		let assert_registry_is_consistent = || {
+			for i in 1..=EncointerCeremonies::reputable_count(cc) {
+				let reputable = EncointerCeremonies::reputable_registry(cc, i).unwrap();
+				assert_eq!(EncointerCeremonies::reputable_index(cc, reputable), i);
+			}
+		};
+
+		assert_eq!(EncointerCeremonies::reputable_count(cc), 5);
+		assert_eq!(EncointerCeremonies::prioritized_reputable_count(cc, 2), 2);
+		assert_registry_is_consistent();
+		assert!([&pairs[1], &pairs[3]].iter().all(|p| index_of(p) <= 2));
+
+		assert_ok!(EncointerCeremonies::remove_participant_from_registry(
+			cid,
+			cindex,
+			&account_id(&pairs[1])
+		));
+		assert_eq!(EncointerCeremonies::reputable_count(cc), 4);
+		assert_eq!(EncointerCeremonies::prioritized_reputable_count(cc, 2), 1);
+		assert_registry_is_consistent();
+		assert_eq!(index_of(&pairs[3]), 1);
+		assert_eq!(EncointerCeremonies::reputable_registry(cc, 5), None);
+
+		assert_ok!(EncointerCeremonies::remove_participant_from_registry(
+			cid,
+			cindex,
+			&account_id(&pairs[0])
+		));
+		assert_eq!(EncointerCeremonies::reputable_count(cc), 3);
+		assert_registry_is_consistent();
+		assert_eq!(index_of(&pairs[3]), 1);
+	});
+}
+
 #[test]
 fn double_registering_by_adversary_bootstrapper_fails() {
 	new_test_ext().execute_with(|| {
@@ -1618,24 +2039,222 @@ fn endorse_newbie_works_for_reputables() {
 	});
 }
 
+/// Gives reputation of the previous ceremony to accounts seeded with `seeds`.
+fn fake_reputables(cid: CommunityIdentifier, seeds: &[u8]) -> Vec<AccountId> {
+	let cindex = EncointerScheduler::current_ceremony_index();
+	seeds
+		.iter()
+		.map(|seed| {
+			let reputable = account_id(&sr25519::Pair::from_seed_slice(&[*seed; 32]).unwrap());
+			EncointerCeremonies::fake_reputation(
+				(cid, cindex - 1),
+				&reputable,
+				Reputation::VerifiedUnlinked,
+			);
+			reputable
+		})
+		.collect()
+}
+
 #[test]
-fn endorse_newbie_fails_if_already_endorsed_in_previous_ceremony() {
+fn endorse_newbie_with_delegated_ticket_works() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
 		let cid = perform_bootstrapping_ceremony(None, 1);
-		let alice = AccountId::from(AccountKeyring::Alice);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let reputables = fake_reputables(cid, &[10, 11]);
+		let (delegator, delegate) = (reputables[0].clone(), reputables[1].clone());
 
-		// a newbie
-		let yran = account_id(&sr25519::Pair::from_seed_slice(&[8u8; 32]).unwrap());
-		assert_ok!(EncointerCeremonies::endorse_newcomer(
-			RuntimeOrigin::signed(alice.clone()),
+		assert_ok!(EncointerCeremonies::delegate_newbie_tickets(
+			RuntimeOrigin::signed(delegator.clone()),
 			cid,
-			yran.clone()
+			delegate.clone(),
+			1
 		));
+		assert_eq!(
+			last_event::<TestRuntime>(),
+			Some(Event::NewbieTicketsDelegated(cid, delegator.clone(), delegate.clone(), 1).into())
+		);
+
+		let zoran = account_id(&sr25519::Pair::from_seed_slice(&[9u8; 32]).unwrap());
+		assert_ok!(EncointerCeremonies::endorse_newcomer_with_delegated_ticket(
+			RuntimeOrigin::signed(delegate.clone()),
+			cid,
+			zoran.clone(),
+			delegator.clone()
+		));
+		assert!(Endorsees::<TestRuntime>::contains_key((cid, cindex), &zoran));
+		assert_eq!(BurnedReputableNewbieTickets::<TestRuntime>::get((cid, cindex), &delegator), 1);
+		assert_eq!(BurnedReputableNewbieTickets::<TestRuntime>::get((cid, cindex), &delegate), 0);
+		assert_eq!(
+			DelegatedNewbieTickets::<TestRuntime>::get((cid, cindex), (&delegator, &delegate)),
+			0
+		);
+
+		let yran = account_id(&sr25519::Pair::from_seed_slice(&[8u8; 32]).unwrap());
+		assert_err!(
+			EncointerCeremonies::endorse_newcomer_with_delegated_ticket(
+				RuntimeOrigin::signed(delegate),
+				cid,
+				yran,
+				delegator
+			),
+			Error::<TestRuntime>::NoDelegatedNewbieTickets,
+		);
+	});
+}
+
+#[test]
+fn delegated_tickets_are_lost_if_delegator_burned_them() {
+	new_test_ext().execute_with(|| {
+		let cid = perform_bootstrapping_ceremony(None, 1);
+		let reputables = fake_reputables(cid, &[10, 11]);
+		let (delegator, delegate) = (reputables[0].clone(), reputables[1].clone());
+
+		assert_ok!(EncointerCeremonies::delegate_newbie_tickets(
+			RuntimeOrigin::signed(delegator.clone()),
+			cid,
+			delegate.clone(),
+			2
+		));
+		for seed in [8u8, 9u8] {
+			assert_ok!(EncointerCeremonies::endorse_newcomer(
+				RuntimeOrigin::signed(delegator.clone()),
+				cid,
+				account_id(&sr25519::Pair::from_seed_slice(&[seed; 32]).unwrap())
+			));
+		}
+
+		assert_err!(
+			EncointerCeremonies::endorse_newcomer_with_delegated_ticket(
+				RuntimeOrigin::signed(delegate),
+				cid,
+				account_id(&sr25519::Pair::from_seed_slice(&[7u8; 32]).unwrap()),
+				delegator
+			),
+			Error::<TestRuntime>::NoMoreNewbieTickets,
+		);
+	});
+}
+
+#[test]
+fn delegate_newbie_tickets_fails_with_invalid_delegation() {
+	new_test_ext().execute_with(|| {
+		let cid = perform_bootstrapping_ceremony(None, 1);
+		let reputables = fake_reputables(cid, &[10, 11]);
+		let newbie = account_id(&sr25519::Pair::from_seed_slice(&[9u8; 32]).unwrap());
+
+		assert_err!(
+			EncointerCeremonies::delegate_newbie_tickets(
+				RuntimeOrigin::signed(reputables[0].clone()),
+				cid,
+				reputables[0].clone(),
+				1
+			),
+			Error::<TestRuntime>::CannotDelegateToSelf,
+		);
+		assert_err!(
+			EncointerCeremonies::delegate_newbie_tickets(
+				RuntimeOrigin::signed(reputables[0].clone()),
+				cid,
+				newbie.clone(),
+				1
+			),
+			Error::<TestRuntime>::DelegateMustBeReputable,
+		);
+		// a reputable has 2 tickets per ceremony
+		assert_err!(
+			EncointerCeremonies::delegate_newbie_tickets(
+				RuntimeOrigin::signed(reputables[0].clone()),
+				cid,
+				reputables[1].clone(),
+				3
+			),
+			Error::<TestRuntime>::NoMoreNewbieTickets,
+		);
+		assert_err!(
+			EncointerCeremonies::delegate_newbie_tickets(
+				RuntimeOrigin::signed(newbie),
+				cid,
+				reputables[1].clone(),
+				1
+			),
+			Error::<TestRuntime>::NoMoreNewbieTickets,
+		);
+	});
+}
+
+#[test]
+fn delegated_newbie_tickets_are_limited_per_delegate() {
+	new_test_ext().execute_with(|| {
+		let cid = perform_bootstrapping_ceremony(None, 1);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let reputables = fake_reputables(cid, &[10, 11, 12, 13]);
+		let delegate = reputables[0].clone();
+
+		for delegator in reputables[1..3].iter() {
+			assert_ok!(EncointerCeremonies::delegate_newbie_tickets(
+				RuntimeOrigin::signed(delegator.clone()),
+				cid,
+				delegate.clone(),
+				2
+			));
+		}
+		assert_ok!(EncointerCeremonies::endorse_newcomer_with_delegated_ticket(
+			RuntimeOrigin::signed(delegate.clone()),
+			cid,
+			account_id(&sr25519::Pair::from_seed_slice(&[9u8; 32]).unwrap()),
+			reputables[1].clone()
+		));
+		// MaxDelegatedNewbieTickets is 5
+		assert_err!(
+			EncointerCeremonies::delegate_newbie_tickets(
+				RuntimeOrigin::signed(reputables[3].clone()),
+				cid,
+				delegate.clone(),
+				2
+			),
+			Error::<TestRuntime>::TooManyDelegatedNewbieTickets,
+		);
+
+		// revoking the unused ticket frees it, the used one still counts
+		assert_ok!(EncointerCeremonies::delegate_newbie_tickets(
+			RuntimeOrigin::signed(reputables[1].clone()),
+			cid,
+			delegate.clone(),
+			0
+		));
+		assert_eq!(
+			EncointerCeremonies::received_delegated_newbie_tickets((cid, cindex), &delegate),
+			3
+		);
+		assert_ok!(EncointerCeremonies::delegate_newbie_tickets(
+			RuntimeOrigin::signed(reputables[3].clone()),
+			cid,
+			delegate,
+			2
+		));
+	});
+}
+
+#[test]
+fn endorse_newbie_fails_if_already_endorsed_in_previous_ceremony() {
+	new_test_ext().execute_with(|| {
+		let cid = perform_bootstrapping_ceremony(None, 1);
+		let alice = AccountId::from(AccountKeyring::Alice);
+
+		// a newbie
+		let yran = account_id(&sr25519::Pair::from_seed_slice(&[8u8; 32]).unwrap());
+		assert_ok!(EncointerCeremonies::endorse_newcomer(
+			RuntimeOrigin::signed(alice.clone()),
+			cid,
+			yran.clone()
+		));
+
+		run_to_next_phase();
+		run_to_next_phase();
+		run_to_next_phase();
 
-		run_to_next_phase();
-		run_to_next_phase();
-		run_to_next_phase();
-
 		assert_err!(
 			EncointerCeremonies::endorse_newcomer(RuntimeOrigin::signed(alice), cid, yran),
 			Error::<TestRuntime>::AlreadyEndorsed
@@ -1661,6 +2280,108 @@ fn endorse_newbie_fails_if_sender_has_no_reputation_and_is_not_bootstrapper() {
 	});
 }
 
+#[test]
+fn removing_bootstrapper_revokes_bootstrapper_registration() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let alice = account_id(&AccountKeyring::Alice.pair());
+		register_alice_bob_ferdie(cid);
+		assert_eq!(BootstrapperCount::<TestRuntime>::get((cid, cindex)), 3);
+
+		assert_ok!(EncointerCommunities::remove_bootstrapper(
+			RuntimeOrigin::signed(master()),
+			cid,
+			alice.clone(),
+		));
+
+		assert!(!BootstrapperIndex::<TestRuntime>::contains_key((cid, cindex), &alice));
+		assert_eq!(BootstrapperCount::<TestRuntime>::get((cid, cindex)), 2);
+		assert!(!EncointerCeremonies::is_registered(cid, cindex, &alice));
+	});
+}
+
+#[test]
+fn removing_bootstrapper_keeps_assignments_of_current_ceremony() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let alice = account_id(&AccountKeyring::Alice.pair());
+		register_alice_bob_ferdie(cid);
+		register_charlie_dave_eve(cid);
+		run_to_next_phase();
+		// Assigning
+
+		assert_ok!(EncointerCommunities::remove_bootstrapper(
+			RuntimeOrigin::signed(master()),
+			cid,
+			alice.clone(),
+		));
+
+		assert!(BootstrapperIndex::<TestRuntime>::contains_key((cid, cindex), &alice));
+		assert_eq!(EncointerCeremonies::get_meetup_index((cid, cindex), &alice), Some(1));
+	});
+}
+
+#[test]
+fn removing_and_adding_bootstrapper_again_keeps_burned_newbie_tickets() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let alice = account_id(&AccountKeyring::Alice.pair());
+		assert_ok!(register(alice.clone(), cid, None));
+
+		let endorsee = add_population(1, 6).pop().unwrap();
+		assert_ok!(EncointerCeremonies::endorse_newcomer(
+			RuntimeOrigin::signed(alice.clone()),
+			cid,
+			account_id(&endorsee)
+		));
+
+		assert_ok!(EncointerCommunities::remove_bootstrapper(
+			RuntimeOrigin::signed(master()),
+			cid,
+			alice.clone(),
+		));
+		assert_ok!(EncointerCommunities::add_bootstrapper(
+			RuntimeOrigin::signed(master()),
+			cid,
+			alice.clone(),
+		));
+
+		assert_eq!(BurnedBootstrapperNewbieTickets::<TestRuntime>::get(cid, &alice), 1);
+	});
+}
+
+#[test]
+fn replacing_bootstrapper_hands_over_burned_newbie_tickets() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let alice = account_id(&AccountKeyring::Alice.pair());
+		let new_bootstrapper = account_id(&AccountKeyring::One.pair());
+		assert_ok!(register(alice.clone(), cid, None));
+
+		let endorsee = add_population(1, 6).pop().unwrap();
+		assert_ok!(EncointerCeremonies::endorse_newcomer(
+			RuntimeOrigin::signed(alice.clone()),
+			cid,
+			account_id(&endorsee)
+		));
+		assert_eq!(BurnedBootstrapperNewbieTickets::<TestRuntime>::get(cid, &alice), 1);
+
+		assert_ok!(EncointerCommunities::replace_bootstrapper(
+			RuntimeOrigin::signed(master()),
+			cid,
+			alice.clone(),
+			new_bootstrapper.clone(),
+		));
+
+		assert_eq!(BurnedBootstrapperNewbieTickets::<TestRuntime>::get(cid, &alice), 1);
+		assert_eq!(BurnedBootstrapperNewbieTickets::<TestRuntime>::get(cid, &new_bootstrapper), 1);
+		assert!(!BootstrapperIndex::<TestRuntime>::contains_key((cid, cindex), &alice));
+	});
+}
+
 #[test]
 fn registering_in_attestation_phase_works() {
 	new_test_ext().execute_with(|| {
@@ -1789,16 +2510,47 @@ fn get_meetup_time_works(lat_micro: i64, lon_micro: i64, meetup_time_offset: i64
 
 		println!(
 			"difference {:?}",
-			EncointerCeremonies::get_meetup_time(location).unwrap() as i64 - adjusted_mtime
+			EncointerCeremonies::get_meetup_time(cid, location).unwrap() as i64 - adjusted_mtime
 		);
 		println!("lon before {:?}", lon_micro as f64 / 1_000_000.0);
 		assert!(
-			tol > (EncointerCeremonies::get_meetup_time(location).unwrap() as i64 - adjusted_mtime)
+			tol > (EncointerCeremonies::get_meetup_time(cid, location).unwrap() as i64 -
+				adjusted_mtime)
 				.unsigned_abs()
 		);
 	});
 }
 
+#[test]
+fn get_meetup_time_honours_location_meetup_time_offset() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		register_alice_bob_ferdie(cid);
+		assert_ok!(EncointerCeremonies::set_meetup_time_offset(
+			RuntimeOrigin::signed(master()),
+			100_000
+		));
+		let location = EncointerCommunities::get_locations(&cid)[0];
+		assert_ok!(EncointerCommunities::set_location_meetup_time_offset(
+			RuntimeOrigin::signed(master()),
+			cid,
+			location,
+			Some(-3600 * 1000),
+		));
+
+		run_to_next_phase();
+		run_to_next_phase();
+		// Attesting
+
+		assert_eq!(EncointerCeremonies::get_meetup_location((cid, cindex), 1), Some(location));
+		let with_override = EncointerCeremonies::get_meetup_time(cid, location).unwrap();
+		EncointerCommunities::do_set_location_meetup_time_offset(cid, location, None).unwrap();
+		let without_override = EncointerCeremonies::get_meetup_time(cid, location).unwrap();
+		assert_eq!(without_override - with_override, 100_000 + 3600 * 1000);
+	});
+}
+
 #[test]
 fn ceremony_index_and_purging_registry_works() {
 	new_test_ext().execute_with(|| {
@@ -2602,30 +3354,318 @@ fn generate_meetup_assignment_params_is_random() {
 	});
 }
 
+fn region_of(lat: f64, lon: f64) -> GeoHash {
+	GeoHash::try_from_params(Degree::from_num(lat), Degree::from_num(lon)).unwrap()
+}
+
+/// Registers a community with three locations in each of the regions around (10, 10), (20, 20)
+/// and (30, 30).
+fn register_community_with_regions() -> CommunityIdentifier {
+	let cid = register_test_community::<TestRuntime>(None, 10.0, 10.0);
+	// each triple lies within a single geohash bucket
+	for coord in [9.99, 10.01, 20.0, 20.01, 20.02, 29.98, 29.99, 30.0] {
+		assert_ok!(EncointerCommunities::add_location(
+			RuntimeOrigin::signed(account_id(&AccountKeyring::Alice.pair())),
+			cid,
+			Location::new(Degree::from_num(coord), Degree::from_num(coord))
+		));
+	}
+	cid
+}
+
+/// Writes the registries for a population of the given size directly.
+fn insert_population(
+	cc: CommunityCeremony,
+	n_bootstrappers: usize,
+	n_reputables: usize,
+	n_newbies: usize,
+) -> (Vec<AccountId>, Vec<AccountId>, Vec<AccountId>) {
+	let mut population: Vec<AccountId> =
+		add_population(n_bootstrappers + n_reputables + n_newbies, 0)
+			.iter()
+			.map(account_id)
+			.collect();
+	let newbies = population.split_off(n_bootstrappers + n_reputables);
+	let reputables = population.split_off(n_bootstrappers);
+	let bootstrappers = population;
+	for (i, p) in bootstrappers.iter().enumerate() {
+		BootstrapperRegistry::<TestRuntime>::insert(cc, i as u64 + 1, p);
+		BootstrapperIndex::<TestRuntime>::insert(cc, p, i as u64 + 1);
+	}
+	for (i, p) in reputables.iter().enumerate() {
+		ReputableRegistry::<TestRuntime>::insert(cc, i as u64 + 1, p);
+		ReputableIndex::<TestRuntime>::insert(cc, p, i as u64 + 1);
+	}
+	for (i, p) in newbies.iter().enumerate() {
+		NewbieRegistry::<TestRuntime>::insert(cc, i as u64 + 1, p);
+		NewbieIndex::<TestRuntime>::insert(cc, p, i as u64 + 1);
+	}
+	BootstrapperCount::<TestRuntime>::insert(cc, n_bootstrappers as u64);
+	ReputableCount::<TestRuntime>::insert(cc, n_reputables as u64);
+	NewbieCount::<TestRuntime>::insert(cc, n_newbies as u64);
+	(bootstrappers, reputables, newbies)
+}
+
+fn meetup_region(cc: CommunityCeremony, meetup_index: MeetupIndexType) -> GeoHash {
+	let location = EncointerCeremonies::get_meetup_location(cc, meetup_index).unwrap();
+	GeoHash::try_from_params(location.lat, location.lon).unwrap()
+}
+
 #[test]
-fn remove_participant_from_registry_fails_in_wrong_phase() {
+fn set_preferred_region_works() {
 	new_test_ext().execute_with(|| {
-		let cid = register_test_community::<TestRuntime>(None, 1.0, 1.0);
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let cid = register_community_with_regions();
 		let cindex = EncointerScheduler::current_ceremony_index();
-
 		let alice = account_id(&AccountKeyring::Alice.pair());
-		run_to_next_phase();
-		assert!(EncointerCeremonies::remove_participant_from_registry(cid, cindex, &alice).is_err());
+		let region = region_of(20.0, 20.0);
+
+		assert_dispatch_err(
+			EncointerCeremonies::set_preferred_region(
+				RuntimeOrigin::signed(alice.clone()),
+				cid,
+				Some(region.clone()),
+			),
+			Error::<TestRuntime>::ParticipantIsNotRegistered.into(),
+		);
+		assert_ok!(register(alice.clone(), cid, None));
+		assert_dispatch_err(
+			EncointerCeremonies::set_preferred_region(
+				RuntimeOrigin::signed(alice.clone()),
+				cid,
+				Some(region_of(40.0, 40.0)),
+			),
+			Error::<TestRuntime>::NoLocationsInRegion.into(),
+		);
+
+		assert_ok!(EncointerCeremonies::set_preferred_region(
+			RuntimeOrigin::signed(alice.clone()),
+			cid,
+			Some(region.clone()),
+		));
+		assert_eq!(
+			EncointerCeremonies::preferred_region((cid, cindex), &alice),
+			Some(region.clone())
+		);
+		assert_eq!(
+			last_event::<TestRuntime>(),
+			Some(Event::PreferredRegionSet(cid, alice.clone(), Some(region)).into())
+		);
+
+		assert_ok!(EncointerCeremonies::set_preferred_region(
+			RuntimeOrigin::signed(alice.clone()),
+			cid,
+			None,
+		));
+		assert_eq!(EncointerCeremonies::preferred_region((cid, cindex), &alice), None);
 	});
 }
 
 #[test]
-fn remove_participant_from_registry_works() {
+fn unregistering_clears_preferred_region() {
 	new_test_ext().execute_with(|| {
-		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let cid = register_community_with_regions();
 		let cindex = EncointerScheduler::current_ceremony_index();
-
-		IssuedRewards::<TestRuntime>::insert((cid, cindex - 1), 0, MeetupResult::Ok);
-		let bootstrapper = account_id(&AccountKeyring::Ferdie.pair());
-		EncointerCommunities::insert_bootstrappers(cid, bounded_vec![bootstrapper.clone()]);
-
 		let alice = account_id(&AccountKeyring::Alice.pair());
-		let bob = account_id(&AccountKeyring::Bob.pair());
+		assert_ok!(register(alice.clone(), cid, None));
+		assert_ok!(EncointerCeremonies::set_preferred_region(
+			RuntimeOrigin::signed(alice.clone()),
+			cid,
+			Some(region_of(20.0, 20.0)),
+		));
+
+		assert_ok!(EncointerCeremonies::unregister_participant(
+			RuntimeOrigin::signed(alice.clone()),
+			cid,
+			None
+		));
+		assert_eq!(EncointerCeremonies::preferred_region((cid, cindex), &alice), None);
+	});
+}
+
+#[rstest(seed, case(1), case(2), case(3), case(4))]
+fn preferred_regions_partition_meetup_assignment(seed: u64) {
+	new_test_ext().execute_with(|| {
+		let cid = register_community_with_regions();
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let cc = (cid, cindex);
+		let (bootstrappers, reputables, newbies) = insert_population(cc, 6, 54, 16);
+		let (region_a, region_b, region_c) =
+			(region_of(10.0, 10.0), region_of(20.0, 20.0), region_of(30.0, 30.0));
+
+		let mut preferences: BTreeMap<AccountId, GeoHash> = BTreeMap::new();
+		preferences.insert(bootstrappers[0].clone(), region_a.clone());
+		preferences.insert(bootstrappers[5].clone(), region_b.clone());
+		// interleaved, so that the remaining participants need to be renumbered
+		for i in (0..38).step_by(2) {
+			preferences.insert(reputables[i].clone(), region_a.clone());
+		}
+		for i in (1..22).step_by(2) {
+			preferences.insert(reputables[i].clone(), region_b.clone());
+		}
+		for i in [0, 2, 4, 6, 8] {
+			preferences.insert(newbies[i].clone(), region_a.clone());
+		}
+		for i in [1, 3, 5, 7] {
+			preferences.insert(newbies[i].clone(), region_b.clone());
+		}
+		for (p, region) in preferences.iter() {
+			PreferredRegion::<TestRuntime>::insert(cc, p, region);
+		}
+
+		let mut random_source =
+			RandomNumberGenerator::<BlakeTwo256>::new(H256::from_low_u64_be(seed));
+		assert_ok!(EncointerCeremonies::generate_meetup_assignment_params(cc, &mut random_source));
+
+		// 25 participants prefer region a, 16 region b and 35 remain
+		let regional_assignments = EncointerCeremonies::regional_assignments(cc);
+		assert_eq!(regional_assignments.len(), 2);
+		let meetups_by_region: BTreeMap<GeoHash, MeetupIndexType> = regional_assignments
+			.iter()
+			.map(|r| (r.region.clone(), r.meetup_count))
+			.collect();
+		assert_eq!(meetups_by_region.get(&region_a), Some(&2));
+		assert_eq!(meetups_by_region.get(&region_b), Some(&2));
+		assert_eq!(EncointerCeremonies::meetup_count(cc), 7);
+
+		let everybody: Vec<AccountId> = bootstrappers
+			.iter()
+			.chain(reputables.iter())
+			.chain(newbies.iter())
+			.cloned()
+			.collect();
+		let mut meetup_sizes: BTreeMap<GeoHash, Vec<usize>> = BTreeMap::new();
+		let mut assigned = BTreeSet::new();
+		let mut meetup_locations = BTreeSet::new();
+		for meetup_index in 1..=EncointerCeremonies::meetup_count(cc) {
+			let region = meetup_region(cc, meetup_index);
+			assert!(meetup_locations
+				.insert(EncointerCeremonies::get_meetup_location(cc, meetup_index).unwrap()));
+			let participants =
+				EncointerCeremonies::get_meetup_participants(cc, meetup_index).unwrap();
+			for p in participants.iter() {
+				assert_eq!(EncointerCeremonies::get_meetup_index(cc, p), Some(meetup_index));
+				assert_eq!(preferences.get(p).unwrap_or(&region_c), &region);
+				assert!(assigned.insert(p.clone()));
+			}
+			// every meetup has experienced participants
+			assert!(participants.iter().any(|p| !newbies.contains(p)));
+			meetup_sizes.entry(region).or_default().push(participants.len());
+		}
+		assert_eq!(assigned.len(), everybody.len());
+
+		// meetups are balanced within each region
+		for (region, sizes) in meetup_sizes.iter() {
+			let total: usize = sizes.iter().sum();
+			let expected_total = everybody
+				.iter()
+				.filter(|p| preferences.get(p).unwrap_or(&region_c) == region)
+				.count();
+			assert_eq!(total, expected_total);
+			assert!(sizes.iter().max().unwrap() - sizes.iter().min().unwrap() <= 3, "{sizes:?}");
+		}
+	});
+}
+
+#[rstest(
+	n_reputables_b,
+	n_newbies_b,
+	expect_partitioned,
+	case(2, 0, false),
+	case(0, 3, false),
+	case(3, 2, false),
+	case(10, 3, false),
+	case(14, 4, true)
+)]
+fn preferred_region_is_only_partitioned_if_it_can_meet_on_its_own(
+	n_reputables_b: usize,
+	n_newbies_b: usize,
+	expect_partitioned: bool,
+) {
+	new_test_ext().execute_with(|| {
+		let cid = register_community_with_regions();
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let cc = (cid, cindex);
+		let (_, reputables, newbies) = insert_population(cc, 6, 30, 10);
+		let region_b = region_of(20.0, 20.0);
+		for p in reputables.iter().take(n_reputables_b).chain(newbies.iter().take(n_newbies_b)) {
+			PreferredRegion::<TestRuntime>::insert(cc, p, region_b.clone());
+		}
+
+		let mut random_source = RandomNumberGenerator::<BlakeTwo256>::new(H256::random());
+		assert_ok!(EncointerCeremonies::generate_meetup_assignment_params(cc, &mut random_source));
+
+		assert_eq!(!EncointerCeremonies::regional_assignments(cc).is_empty(), expect_partitioned);
+		if expect_partitioned {
+			for p in reputables.iter().take(n_reputables_b).chain(newbies.iter().take(n_newbies_b))
+			{
+				let meetup_index = EncointerCeremonies::get_meetup_index(cc, p).unwrap();
+				assert_eq!(meetup_region(cc, meetup_index), region_b);
+			}
+		} else {
+			// preferences are ignored altogether
+			assert_eq!(PartitionedParticipantIndex::<TestRuntime>::iter_prefix(cc).count(), 0);
+		}
+	});
+}
+
+#[test]
+fn small_preference_group_is_not_assigned_to_the_same_meetup() {
+	let mut split_up = false;
+	for seed in 1..=8 {
+		new_test_ext().execute_with(|| {
+			let cid = register_community_with_regions();
+			let cindex = EncointerScheduler::current_ceremony_index();
+			let cc = (cid, cindex);
+			let (_, reputables, _) = insert_population(cc, 6, 30, 10);
+			let group = &reputables[..3];
+			for p in group.iter() {
+				PreferredRegion::<TestRuntime>::insert(cc, p, region_of(20.0, 20.0));
+			}
+
+			let mut random_source =
+				RandomNumberGenerator::<BlakeTwo256>::new(H256::from_low_u64_be(seed));
+			assert_ok!(EncointerCeremonies::generate_meetup_assignment_params(
+				cc,
+				&mut random_source
+			));
+
+			assert!(EncointerCeremonies::regional_assignments(cc).is_empty());
+			let meetups: BTreeSet<MeetupIndexType> = group
+				.iter()
+				.map(|p| EncointerCeremonies::get_meetup_index(cc, p).unwrap())
+				.collect();
+			split_up |= meetups.len() > 1;
+		});
+	}
+	// the group can't arrange to meet by sharing a preference
+	assert!(split_up);
+}
+
+#[test]
+fn remove_participant_from_registry_fails_in_wrong_phase() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 1.0, 1.0);
+		let cindex = EncointerScheduler::current_ceremony_index();
+
+		let alice = account_id(&AccountKeyring::Alice.pair());
+		run_to_next_phase();
+		assert!(EncointerCeremonies::remove_participant_from_registry(cid, cindex, &alice).is_err());
+	});
+}
+
+#[test]
+fn remove_participant_from_registry_works() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let cindex = EncointerScheduler::current_ceremony_index();
+
+		IssuedRewards::<TestRuntime>::insert((cid, cindex - 1), 0, MeetupResult::Ok);
+		let bootstrapper = account_id(&AccountKeyring::Ferdie.pair());
+		EncointerCommunities::insert_bootstrappers(cid, bounded_vec![bootstrapper.clone()]);
+
+		let alice = account_id(&AccountKeyring::Alice.pair());
+		let bob = account_id(&AccountKeyring::Bob.pair());
 		let charlie = account_id(&AccountKeyring::Charlie.pair());
 		let eve = account_id(&AccountKeyring::Eve.pair());
 
@@ -3363,6 +4403,138 @@ fn get_aggregated_account_data_works() {
 	});
 }
 
+#[test]
+fn get_meetup_assignment_works() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 1.0, 1.0);
+		let alice = account_id(&AccountKeyring::Alice.pair());
+		let bob = account_id(&AccountKeyring::Bob.pair());
+		let ferdie = account_id(&AccountKeyring::Ferdie.pair());
+		let eve = account_id(&AccountKeyring::Eve.pair());
+		register_alice_bob_ferdie(cid);
+
+		assert_eq!(EncointerCeremonies::get_meetup_assignment(cid, &alice), None);
+
+		run_to_next_phase();
+		run_to_next_phase();
+		// Attesting
+
+		let assignment = EncointerCeremonies::get_meetup_assignment(cid, &alice).unwrap();
+		assert_eq!(assignment.meetup_index, 1);
+		assert_eq!(
+			assignment.location,
+			Some(Location::new(Degree::from_num(1.0), Degree::from_num(1.0)))
+		);
+		assert_eq!(assignment.time, Some(correct_meetup_time(&cid, 1)));
+		assert_eq!(assignment.participants.len(), 3);
+		assert!([&alice, &bob, &ferdie].iter().all(|a| assignment.participants.contains(a)));
+
+		// eve is not registered
+		assert_eq!(EncointerCeremonies::get_meetup_assignment(cid, &eve), None);
+	});
+}
+
+#[test]
+fn get_meetup_registry_works() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let alice = account_id(&AccountKeyring::Alice.pair());
+		register_alice_bob_ferdie(cid);
+
+		run_to_next_phase();
+		assert_eq!(
+			EncointerCeremonies::get_meetup_registry(cid, 1),
+			EncointerCeremonies::get_meetup_assignment(cid, &alice).unwrap().participants
+		);
+		assert_eq!(EncointerCeremonies::get_meetup_registry(cid, 1).len(), 3);
+		assert!(EncointerCeremonies::get_meetup_registry(cid, 2).is_empty());
+		assert!(EncointerCeremonies::get_meetup_registry(cid, 0).is_empty());
+	});
+}
+
+#[test]
+fn attestation_history_works() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		perform_ceremony_without_claiming(cid);
+
+		let history = EncointerCeremonies::attestation_history(cid, cindex, cindex + 1);
+
+		// the ongoing ceremony has no attestations yet
+		assert_eq!(history.len(), 1);
+		assert_eq!(history[0].ceremony_index, cindex);
+		assert_eq!(history[0].meetup_count, 1);
+		assert_eq!(history[0].attestations.len(), 6);
+		assert!(history[0].attestations.iter().all(|(_, attestees)| attestees.len() == 5));
+	});
+}
+
+#[test]
+fn get_collusion_report_works() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		perform_ceremony_without_claiming(cid);
+
+		// a single meetup is no evidence of anything
+		let report = EncointerCeremonies::get_collusion_report(
+			cid,
+			cindex,
+			cindex,
+			CollusionDetectionParams::default(),
+		);
+		assert_eq!(report.analyzed_ceremonies, vec![cindex]);
+		assert!(report.suspicious_pairs.is_empty());
+
+		let report = EncointerCeremonies::get_collusion_report(
+			cid,
+			cindex,
+			cindex,
+			CollusionDetectionParams {
+				min_co_attendances: 1,
+				min_excess_ratio: BalanceType::from_num(1),
+			},
+		);
+		assert_eq!(report.suspicious_pairs.len(), 15);
+		assert_eq!(report.clusters.len(), 1);
+		assert_eq!(report.clusters[0].len(), 6);
+	});
+}
+
+#[test]
+fn get_collusion_report_is_limited_to_unpurged_ceremonies() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		perform_ceremony_without_claiming(cid);
+
+		let report = EncointerCeremonies::get_collusion_report(
+			cid,
+			0,
+			CeremonyIndexType::MAX,
+			CollusionDetectionParams::default(),
+		);
+		assert_eq!(report.analyzed_ceremonies, vec![cindex]);
+
+		// once the reputation lifetime has passed, the ceremony is out of range
+		let lifetime = EncointerCeremonies::reputation_lifetime();
+		for _ in 0..lifetime {
+			run_to_next_phase();
+			run_to_next_phase();
+			run_to_next_phase();
+		}
+		assert_eq!(EncointerScheduler::current_ceremony_index(), cindex + lifetime + 1);
+		let report = EncointerCeremonies::get_collusion_report(
+			cid,
+			cindex,
+			cindex,
+			CollusionDetectionParams::default(),
+		);
+		assert!(report.analyzed_ceremonies.is_empty());
+	});
+}
+
 #[test]
 fn attest_attendees_works() {
 	new_test_ext().execute_with(|| {
@@ -3621,3 +4793,556 @@ fn validate_reputation_works() {
 		assert!(EncointerCeremonies::validate_reputation(&alice, &cid, 7));
 	});
 }
+
+fn set_community_rules(cid: CommunityIdentifier, rules: CommunityRules) {
+	assert_ok!(EncointerCommunities::update_community_metadata(
+		RuntimeOrigin::signed(master()),
+		cid,
+		CommunityMetadata { rules, ..Default::default() }
+	));
+}
+
+#[test]
+fn meetup_tolerances_depend_on_community_rules() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		assert_eq!(EncointerCeremonies::community_rules(&cid), CommunityRules::LoCo);
+		assert_eq!(EncointerCeremonies::meetup_location_tolerance(&cid), Some(LOCATION_TOLERANCE));
+		assert_eq!(EncointerCeremonies::meetup_time_tolerance(&cid), Some(TIME_TOLERANCE));
+
+		set_community_rules(cid, CommunityRules::LoCoFlex);
+		assert_eq!(
+			EncointerCeremonies::meetup_location_tolerance(&cid),
+			Some(3 * LOCATION_TOLERANCE)
+		);
+		assert_eq!(EncointerCeremonies::meetup_time_tolerance(&cid), Some(3 * TIME_TOLERANCE));
+
+		set_community_rules(cid, CommunityRules::BeeDance);
+		assert_eq!(EncointerCeremonies::meetup_location_tolerance(&cid), None);
+		assert_eq!(EncointerCeremonies::meetup_time_tolerance(&cid), None);
+	});
+}
+
+#[rstest(
+	rules,
+	lat_offset,
+	time_offset,
+	expected,
+	case(CommunityRules::LoCo, 0.02, 0, Err(Error::<TestRuntime>::ClaimOfAttendanceTooFarAway)),
+	case(CommunityRules::LoCo, 0.0, TIME_TOLERANCE + 1, Err(Error::<TestRuntime>::StaleClaimOfAttendance)),
+	case(CommunityRules::LoCoFlex, 0.02, TIME_TOLERANCE + 1, Ok(())),
+	case(CommunityRules::LoCoFlex, 0.05, 0, Err(Error::<TestRuntime>::ClaimOfAttendanceTooFarAway)),
+	case(CommunityRules::LoCoFlex, 0.0, 3 * TIME_TOLERANCE + 1, Err(Error::<TestRuntime>::StaleClaimOfAttendance)),
+	case(CommunityRules::BeeDance, 10.0, 1000 * TIME_TOLERANCE, Ok(()))
+)]
+fn loco_flex_meets_within_widened_tolerances(
+	rules: CommunityRules,
+	lat_offset: f64,
+	time_offset: Moment,
+	expected: Result<(), Error<TestRuntime>>,
+) {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		set_community_rules(cid, rules);
+		let meetup_location = Location::default();
+		let meetup_time = 1_000_000 * TIME_TOLERANCE;
+		let location = Location { lat: Degree::from_num(lat_offset), lon: meetup_location.lon };
+
+		assert_eq!(
+			EncointerCeremonies::ensure_within_meetup_tolerances(
+				&cid,
+				&location,
+				meetup_time + time_offset,
+				&meetup_location,
+				meetup_time
+			)
+			.map_err(DispatchError::from),
+			expected.map_err(DispatchError::from)
+		);
+	});
+}
+
+#[rstest(
+	rules,
+	expected_meetups,
+	expected_reputables,
+	case(CommunityRules::LoCo, 1, 9),
+	case(CommunityRules::LoCoFlex, 1, 9),
+	case(CommunityRules::BeeDance, 2, 20)
+)]
+fn meetup_assignment_depends_on_community_rules(
+	rules: CommunityRules,
+	expected_meetups: MeetupIndexType,
+	expected_reputables: u64,
+) {
+	new_test_ext().execute_with(|| {
+		// the community has a single location
+		let cid = perform_bootstrapping_ceremony(None, 1);
+		set_community_rules(cid, rules);
+		let cindex = EncointerScheduler::current_ceremony_index();
+
+		register_alice_bob_ferdie(cid);
+		register_charlie_dave_eve(cid);
+		for i in 0..20 {
+			let pair = sr25519::Pair::from_seed_slice(&[i + 13; 32]).unwrap();
+			assert_ok!(register_as_reputable(&pair, cid));
+		}
+
+		run_to_next_phase();
+		// Assigning
+
+		assert_eq!(EncointerCeremonies::meetup_count((cid, cindex)), expected_meetups);
+		assert_eq!(
+			EncointerCeremonies::assignment_counts((cid, cindex)).reputables,
+			expected_reputables
+		);
+		assert_eq!(
+			EncointerCeremonies::get_meetup_location((cid, cindex), 1).is_some(),
+			rules.has_meetup_locations()
+		);
+	});
+}
+
+#[test]
+fn bee_dance_ceremony_works_without_meetup_location() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		set_community_rules(cid, CommunityRules::BeeDance);
+		let alice = AccountKeyring::Alice.to_account_id();
+		let cindex = EncointerScheduler::current_ceremony_index();
+		register_alice_bob_ferdie(cid);
+		register_charlie_dave_eve(cid);
+
+		run_to_next_phase();
+		// Assigning
+		run_to_next_phase();
+		// Attesting
+		assert_eq!(EncointerCeremonies::get_meetup_location((cid, cindex), 1), None);
+		let personal =
+			EncointerCeremonies::get_aggregated_account_data(cid, &alice).personal.unwrap();
+		assert_eq!(personal.meetup_index, Some(1));
+		assert_eq!(personal.meetup_location_index, None);
+		assert_eq!(personal.meetup_time, None);
+
+		let participants = bootstrappers();
+		for attestor in participants.iter() {
+			let attestees: Vec<_> =
+				participants.iter().filter(|p| p.public() != attestor.public()).collect();
+			attest_by_rules(attestor, &attestees, cid);
+		}
+
+		run_to_next_phase();
+		// Registering
+		assert_ok!(EncointerCeremonies::claim_rewards(RuntimeOrigin::signed(alice), cid, None));
+		assert!(event_deposited::<TestRuntime>(Event::RewardsIssued(cid, 1, 6).into()));
+		assert_eq!(EncointerCeremonies::reputation_count((cid, cindex)), 6);
+	});
+}
+
+#[rstest(
+	rules,
+	expected_rewards,
+	case(CommunityRules::LoCo, 6),
+	case(CommunityRules::LoCoFlex, 6),
+	case(CommunityRules::BeeDance, 5)
+)]
+fn attestation_threshold_depends_on_community_rules(
+	rules: CommunityRules,
+	expected_rewards: MeetupParticipantIndexType,
+) {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		set_community_rules(cid, rules);
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+		register_alice_bob_ferdie(cid);
+		register_charlie_dave_eve(cid);
+
+		run_to_next_phase();
+		// Assigning
+		run_to_next_phase();
+		// Attesting
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let participants = bootstrappers();
+		for attestor in participants.iter() {
+			// alice doesn't attest bob
+			let attestees: Vec<_> = participants
+				.iter()
+				.filter(|a| {
+					a.public() != attestor.public() &&
+						!(account_id(attestor) == alice && account_id(a) == bob)
+				})
+				.collect();
+			attest_by_rules(attestor, &attestees, cid);
+		}
+
+		run_to_next_phase();
+		// Registering
+		assert_ok!(EncointerCeremonies::claim_rewards(
+			RuntimeOrigin::signed(alice.clone()),
+			cid,
+			None
+		));
+		// bob is still attested by everybody who remains legit
+		assert!(event_deposited::<TestRuntime>(
+			Event::RewardsIssued(cid, 1, expected_rewards).into()
+		));
+		assert_eq!(
+			EncointerCeremonies::participant_reputation((cid, cindex), &alice) ==
+				Reputation::VerifiedUnlinked,
+			rules.has_meetup_locations()
+		);
+	});
+}
+
+#[test]
+fn set_attestation_policy_errs_with_bad_origin() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		assert_dispatch_err(
+			EncointerCeremonies::set_attestation_policy(
+				RuntimeOrigin::signed(AccountKeyring::Bob.into()),
+				cid,
+				Some(AttestationPolicyKind::Lenient),
+			),
+			DispatchError::BadOrigin,
+		);
+	});
+}
+
+#[test]
+fn set_attestation_policy_fails_with_inexistent_community() {
+	new_test_ext().execute_with(|| {
+		assert_err!(
+			EncointerCeremonies::set_attestation_policy(
+				RuntimeOrigin::signed(master()),
+				CommunityIdentifier::default(),
+				Some(AttestationPolicyKind::Lenient),
+			),
+			Error::<TestRuntime>::InexistentCommunity,
+		);
+	});
+}
+
+#[test]
+fn set_attestation_policy_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		assert_eq!(EncointerCeremonies::attestation_policy(&cid), AttestationPolicyKind::Default);
+
+		assert_ok!(EncointerCeremonies::set_attestation_policy(
+			RuntimeOrigin::signed(master()),
+			cid,
+			Some(AttestationPolicyKind::Lenient),
+		));
+		assert_eq!(EncointerCeremonies::attestation_policy(&cid), AttestationPolicyKind::Lenient);
+		assert!(event_deposited::<TestRuntime>(
+			Event::AttestationPolicyUpdated(cid, Some(AttestationPolicyKind::Lenient)).into()
+		));
+
+		// the policy no longer follows the rules
+		set_community_rules(cid, CommunityRules::BeeDance);
+		assert_eq!(EncointerCeremonies::attestation_policy(&cid), AttestationPolicyKind::Lenient);
+
+		assert_ok!(EncointerCeremonies::set_attestation_policy(
+			RuntimeOrigin::signed(master()),
+			cid,
+			None,
+		));
+		assert_eq!(EncointerCeremonies::attestation_policy(&cid), AttestationPolicyKind::FullGraph);
+	});
+}
+
+#[test]
+fn lenient_attestation_policy_rewards_participants_attested_by_half_of_the_meetup() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		set_community_rules(cid, CommunityRules::BeeDance);
+		assert_ok!(EncointerCeremonies::set_attestation_policy(
+			RuntimeOrigin::signed(master()),
+			cid,
+			Some(AttestationPolicyKind::Lenient),
+		));
+		let alice = AccountKeyring::Alice.to_account_id();
+		register_alice_bob_ferdie(cid);
+		register_charlie_dave_eve(cid);
+
+		run_to_next_phase();
+		// Assigning
+		run_to_next_phase();
+		// Attesting
+		let participants = bootstrappers();
+		for (i, attestor) in participants.iter().enumerate() {
+			// everybody attests only the three participants following them
+			let attestees: Vec<_> =
+				(1..4).map(|k| &participants[(i + k) % participants.len()]).collect();
+			attest_by_rules(attestor, &attestees, cid);
+		}
+
+		run_to_next_phase();
+		// Registering
+		assert_ok!(EncointerCeremonies::claim_rewards(RuntimeOrigin::signed(alice), cid, None));
+		assert!(event_deposited::<TestRuntime>(Event::RewardsIssued(cid, 1, 6).into()));
+	});
+}
+
+fn signed_claim(
+	attendee: &sr25519::Pair,
+	cid: CommunityIdentifier,
+	cindex: CeremonyIndexType,
+	mindex: MeetupIndexType,
+	location: Location,
+	timestamp: Moment,
+) -> ClaimOfAttendanceOf<TestRuntime> {
+	ClaimOfAttendanceOf::<TestRuntime>::new_unsigned(
+		account_id(attendee),
+		cindex,
+		cid,
+		mindex,
+		location,
+		timestamp,
+		6,
+	)
+	.sign(attendee)
+}
+
+/// Registers the default bootstrappers and advances to the attesting phase.
+///
+/// Returns the meetup location and time.
+fn setup_meetup_for_claims(cid: CommunityIdentifier) -> (Location, Moment) {
+	register_alice_bob_ferdie(cid);
+	register_charlie_dave_eve(cid);
+	run_to_next_phase();
+	// Assigning
+	run_to_next_phase();
+	// Attesting
+	let cindex = EncointerScheduler::current_ceremony_index();
+	let location = EncointerCeremonies::get_meetup_location((cid, cindex), 1).unwrap();
+	(location, EncointerCeremonies::get_meetup_time(cid, location).unwrap())
+}
+
+fn attest_claims(
+	attestor: &sr25519::Pair,
+	cid: CommunityIdentifier,
+	claims: Vec<ClaimOfAttendanceOf<TestRuntime>>,
+) -> DispatchResultWithPostInfo {
+	EncointerCeremonies::attest_claims(
+		RuntimeOrigin::signed(account_id(attestor)),
+		cid,
+		6,
+		BoundedVec::try_from(claims).unwrap(),
+	)
+}
+
+/// Attests `attestees` in a meetup of 6, with signed claims of attendance if the community
+/// rules require them.
+fn attest_by_rules(
+	attestor: &sr25519::Pair,
+	attestees: &[&sr25519::Pair],
+	cid: CommunityIdentifier,
+) {
+	if !EncointerCeremonies::community_rules(&cid).requires_signed_claims() {
+		attest_all(account_id(attestor), attestees.iter().map(|a| account_id(a)).collect(), cid, 6);
+		return;
+	}
+	let cindex = EncointerScheduler::current_ceremony_index();
+	let mindex =
+		EncointerCeremonies::get_meetup_index((cid, cindex), &account_id(attestor)).unwrap();
+	let (location, time) = EncointerCeremonies::get_meetup_location((cid, cindex), mindex)
+		.map(|l| (l, EncointerCeremonies::get_meetup_time(cid, l).unwrap()))
+		.unwrap_or_default();
+	let claims = attestees
+		.iter()
+		.map(|a| signed_claim(a, cid, cindex, mindex, location, time))
+		.collect();
+	assert_ok!(attest_claims(attestor, cid, claims));
+}
+
+#[rstest(rules, case(CommunityRules::LoCoFlex), case(CommunityRules::BeeDance))]
+fn attest_attendees_fails_if_rules_require_signed_claims(rules: CommunityRules) {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		set_community_rules(cid, rules);
+		register_alice_bob_ferdie(cid);
+		register_charlie_dave_eve(cid);
+		run_to_next_phase();
+		// Assigning
+		run_to_next_phase();
+		// Attesting
+		let alice = AccountKeyring::Alice.to_account_id();
+		let bob = AccountKeyring::Bob.to_account_id();
+
+		assert_err!(
+			EncointerCeremonies::attest_attendees(
+				RuntimeOrigin::signed(alice.clone()),
+				cid,
+				6,
+				BoundedVec::try_from(vec![bob]).unwrap()
+			),
+			Error::<TestRuntime>::SignedClaimsRequired
+		);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		assert_eq!(EncointerCeremonies::attestation_index((cid, cindex), &alice), 0);
+	});
+}
+
+#[test]
+fn attest_claims_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let (location, time) = setup_meetup_for_claims(cid);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let participants = bootstrappers();
+
+		for attestor in participants.iter() {
+			let claims = participants
+				.iter()
+				.filter(|p| p.public() != attestor.public())
+				.map(|p| signed_claim(p, cid, cindex, 1, location, time))
+				.collect();
+			assert_ok!(attest_claims(attestor, cid, claims));
+		}
+		assert_eq!(
+			EncointerCeremonies::attestation_registry(
+				(cid, cindex),
+				EncointerCeremonies::attestation_index((cid, cindex), account_id(&participants[0]))
+			)
+			.unwrap()
+			.len(),
+			5
+		);
+
+		run_to_next_phase();
+		// Registering
+		assert_ok!(EncointerCeremonies::claim_rewards(
+			RuntimeOrigin::signed(account_id(&participants[0])),
+			cid,
+			None
+		));
+		assert!(event_deposited::<TestRuntime>(Event::RewardsIssued(cid, 1, 6).into()));
+	});
+}
+
+#[test]
+fn attest_claims_rejects_bad_signature() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let (location, time) = setup_meetup_for_claims(cid);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let alice = AccountKeyring::Alice.pair();
+		let bob = AccountKeyring::Bob.pair();
+		let charlie = AccountKeyring::Charlie.pair();
+
+		// charlie signs a claim in the name of bob
+		let mut claim = signed_claim(&charlie, cid, cindex, 1, location, time);
+		claim.claimant_public = account_id(&bob);
+		assert_err!(
+			attest_claims(&alice, cid, vec![claim]),
+			Error::<TestRuntime>::BadClaimOfAttendanceSignature
+		);
+
+		let mut claim = signed_claim(&bob, cid, cindex, 1, location, time);
+		claim.claimant_signature = None;
+		assert_err!(
+			attest_claims(&alice, cid, vec![claim]),
+			Error::<TestRuntime>::BadClaimOfAttendanceSignature
+		);
+	});
+}
+
+#[test]
+fn attest_claims_rejects_more_claims_than_meetup_participants_before_verifying_them() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let (location, time) = setup_meetup_for_claims(cid);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let alice = AccountKeyring::Alice.pair();
+		let bob = AccountKeyring::Bob.pair();
+
+		// the meetup has 6 participants
+		let mut claim = signed_claim(&bob, cid, cindex, 1, location, time);
+		claim.claimant_signature = None;
+		assert_err!(
+			attest_claims(&alice, cid, vec![claim; 6]),
+			Error::<TestRuntime>::TooManyAttestations
+		);
+	});
+}
+
+#[test]
+fn attest_claims_rejects_claim_for_wrong_meetup() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		let (location, time) = setup_meetup_for_claims(cid);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let alice = AccountKeyring::Alice.pair();
+		let bob = AccountKeyring::Bob.pair();
+
+		assert_err!(
+			attest_claims(
+				&alice,
+				cid,
+				vec![signed_claim(&bob, cid, cindex - 1, 1, location, time)]
+			),
+			Error::<TestRuntime>::ClaimOfAttendanceForWrongMeetup
+		);
+		assert_err!(
+			attest_claims(&alice, cid, vec![signed_claim(&bob, cid, cindex, 2, location, time)]),
+			Error::<TestRuntime>::ClaimOfAttendanceForWrongMeetup
+		);
+	});
+}
+
+#[rstest(
+	rules,
+	lat_offset,
+	time_offset,
+	expected,
+	case(CommunityRules::LoCo, 0.005, 0, Ok(())),
+	case(CommunityRules::LoCo, 0.02, 0, Err(Error::<TestRuntime>::ClaimOfAttendanceTooFarAway)),
+	case(CommunityRules::LoCoFlex, 0.02, 0, Ok(())),
+	case(CommunityRules::LoCoFlex, 0.05, 0, Err(Error::<TestRuntime>::ClaimOfAttendanceTooFarAway)),
+	case(CommunityRules::LoCo, 0.0, TIME_TOLERANCE, Ok(())),
+	case(CommunityRules::LoCo, 0.0, TIME_TOLERANCE + 1, Err(Error::<TestRuntime>::StaleClaimOfAttendance)),
+	case(CommunityRules::LoCoFlex, 0.0, 3 * TIME_TOLERANCE, Ok(())),
+	case(CommunityRules::LoCoFlex, 0.0, 3 * TIME_TOLERANCE + 1, Err(Error::<TestRuntime>::StaleClaimOfAttendance))
+)]
+fn attest_claims_checks_location_and_time_tolerance(
+	rules: CommunityRules,
+	lat_offset: f64,
+	time_offset: Moment,
+	expected: Result<(), Error<TestRuntime>>,
+) {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community::<TestRuntime>(None, 0.0, 0.0);
+		set_community_rules(cid, rules);
+		let (location, time) = setup_meetup_for_claims(cid);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		let alice = AccountKeyring::Alice.pair();
+		let bob = AccountKeyring::Bob.pair();
+
+		let expected = expected.map_err(DispatchError::from);
+		let claim_location =
+			Location { lat: location.lat + Degree::from_num(lat_offset), lon: location.lon };
+		for timestamp in [time + time_offset, time - time_offset] {
+			let result = attest_claims(
+				&alice,
+				cid,
+				vec![signed_claim(&bob, cid, cindex, 1, claim_location, timestamp)],
+			);
+			match expected {
+				Ok(()) => {
+					assert_ok!(result);
+				},
+				Err(e) => {
+					assert_err!(result, e);
+				},
+			}
+		}
+	});
+}
@@ -65,6 +65,12 @@ pub trait WeightInfo {
 	fn set_time_tolerance() -> Weight;
 	fn set_location_tolerance() -> Weight;
 	fn purge_community_ceremony() -> Weight;
+	fn attest_claims(n: u32) -> Weight;
+	fn set_preferred_region() -> Weight;
+	fn delegate_newbie_tickets() -> Weight;
+	fn endorse_newcomer_with_delegated_ticket() -> Weight;
+	fn register_participant_with_proofs() -> Weight;
+	fn set_attestation_policy() -> Weight;
 }
 
 // For tests
@@ -125,4 +131,35 @@ impl WeightInfo for () {
 	fn purge_community_ceremony() -> Weight {
 		Weight::from_parts(139_000_000, 0).saturating_add(RocksDbWeight::get().writes(12))
 	}
+	fn attest_claims(n: u32) -> Weight {
+		Weight::from_parts(272_000_000, 0)
+			.saturating_add(Weight::from_parts(218_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(29))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	fn set_preferred_region() -> Weight {
+		Weight::from_parts(58_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn delegate_newbie_tickets() -> Weight {
+		Weight::from_parts(142_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(15))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	fn endorse_newcomer_with_delegated_ticket() -> Weight {
+		Weight::from_parts(2_087_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(21))
+			.saturating_add(RocksDbWeight::get().writes(10))
+	}
+	fn register_participant_with_proofs() -> Weight {
+		Weight::from_parts(873_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(21))
+			.saturating_add(RocksDbWeight::get().writes(11))
+	}
+	fn set_attestation_policy() -> Weight {
+		Weight::from_parts(33_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
 }
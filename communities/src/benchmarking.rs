@@ -1,6 +1,6 @@
 use crate::{Pallet as Communities, *};
 use encointer_primitives::{
-	balances::Demurrage,
+	balances::{BalanceType, Demurrage},
 	communities::{Location, NominalIncome},
 };
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
@@ -62,7 +62,8 @@ fn setup_test_community<T: Config>() -> (
 		bootstrappers.clone(),
 		community_metadata.clone(),
 		demurrage,
-		nominal_income
+		nominal_income,
+		None
 	));
 	let cid = CommunityIdentifier::new(get_location(0), bootstrappers.clone()).unwrap();
 
@@ -82,7 +83,7 @@ benchmarks! {
 		}
 		assert_eq!(Pallet::<T>::community_identifiers().len(), 1);
 	} : {
-		assert_ok!(Communities::<T>::new_community(RawOrigin::Root.into(), get_location(NUM_LOCATIONS-1), bootstrappers, community_metadata, demurrage, nominal_income));
+		assert_ok!(Communities::<T>::new_community(RawOrigin::Root.into(), get_location(NUM_LOCATIONS-1), bootstrappers, community_metadata, demurrage, nominal_income, Some(BalanceType::from_num(1))));
 	}
 	verify {
 		assert_eq!(Pallet::<T>::community_identifiers().len(), 2);
@@ -159,6 +160,56 @@ benchmarks! {
 		assert_eq!(Pallet::<T>::max_speed_mps(), 1_000_000_000);
 	}
 
+	set_location_meetup_time_offset {
+		let (cid, bootstrappers, community_metadata, demurrage, nominal_income) = setup_test_community::<T>();
+		let location = get_location(1);
+		assert_ok!(Pallet::<T>::add_location(RawOrigin::Root.into(), cid, location));
+		// any offset checks all nearby buckets, but a large one would make the location reachable
+		// from its neighbour
+	} : _(RawOrigin::Root, cid, location, Some(1))
+	verify {
+		assert_eq!(Pallet::<T>::location_meetup_time_offset(cid, location), Some(1));
+	}
+
+	add_bootstrapper {
+		let (cid, bootstrappers, community_metadata, demurrage, nominal_income) = setup_test_community::<T>();
+		let bootstrapper: T::AccountId = account("new bootstrapper", 10, 10);
+	} : _(RawOrigin::Root, cid, bootstrapper.clone())
+	verify {
+		assert!(Pallet::<T>::bootstrappers(cid).contains(&bootstrapper));
+	}
+
+	remove_bootstrapper {
+		let (cid, bootstrappers, community_metadata, demurrage, nominal_income) = setup_test_community::<T>();
+	} : _(RawOrigin::Root, cid, bootstrappers[0].clone())
+	verify {
+		assert!(!Pallet::<T>::bootstrappers(cid).contains(&bootstrappers[0]));
+	}
+
+	replace_bootstrapper {
+		let (cid, bootstrappers, community_metadata, demurrage, nominal_income) = setup_test_community::<T>();
+		let bootstrapper: T::AccountId = account("new bootstrapper", 10, 10);
+	} : _(RawOrigin::Root, cid, bootstrappers[0].clone(), bootstrapper.clone())
+	verify {
+		assert_eq!(Pallet::<T>::bootstrappers(cid)[0], bootstrapper);
+	}
+
+	set_demurrage_exemption {
+		let (cid, bootstrappers, community_metadata, demurrage, nominal_income) = setup_test_community::<T>();
+		let account: T::AccountId = account("treasury", 10, 10);
+		assert_ok!(pallet_encointer_balances::Pallet::<T>::issue(cid, &account, BalanceType::from_num(100)));
+	} : _(RawOrigin::Root, cid, account.clone(), true)
+	verify {
+		assert!(pallet_encointer_balances::Pallet::<T>::is_demurrage_exempt(cid, &account));
+	}
+
+	update_existential_deposit {
+		let (cid, bootstrappers, community_metadata, demurrage, nominal_income) = setup_test_community::<T>();
+	} : _(RawOrigin::Root, cid, BalanceType::from_num(1))
+	verify {
+		assert_eq!(pallet_encointer_balances::Pallet::<T>::existential_deposit(cid), 1);
+	}
+
 	purge_community {
 		// Todo: Properly benchmark this #189
 
@@ -24,7 +24,8 @@
 
 use core::marker::PhantomData;
 use encointer_primitives::{
-	balances::{BalanceEntry, Demurrage},
+	balances::{BalanceEntry, BalanceType, Demurrage},
+	ceremonies::{MeetupTimeOffsetType, MAX_MEETUP_TIME_OFFSET_MS},
 	common::PalletString,
 	communities::{
 		consts::*, CommunityIdentifier, CommunityMetadata as CommunityMetadataType, Degree,
@@ -85,6 +86,9 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type MaxBootstrappers: Get<u32>;
+
+		/// Who to inform about changes of a community's bootstrappers
+		type OnBootstrappersChanged: OnBootstrappersChanged<Self::AccountId>;
 	}
 
 	#[pallet::call]
@@ -102,6 +106,7 @@ pub mod pallet {
 			community_metadata: CommunityMetadataType,
 			demurrage: Option<Demurrage>,
 			nominal_income: Option<NominalIncomeType>,
+			existential_deposit: Option<BalanceType>,
 		) -> DispatchResultWithPostInfo {
 			T::TrustableForNonDestructiveAction::ensure_origin(origin)?;
 			Self::validate_bootstrappers(&bootstrappers)?;
@@ -115,6 +120,9 @@ pub mod pallet {
 			ensure!(!cids.contains(&cid), Error::<T>::CommunityAlreadyRegistered);
 
 			Self::validate_location(&location)?;
+			if let Some(ed) = existential_deposit {
+				Self::validate_existential_deposit(ed, nominal_income)?;
+			}
 			// All checks done, now mutate state
 			let geo_hash = GeoHash::try_from_params(location.lat, location.lon)
 				.map_err(|_| <Error<T>>::InvalidLocationForGeohash)?;
@@ -154,6 +162,9 @@ pub mod pallet {
 			if let Some(i) = nominal_income {
 				<NominalIncome<T>>::insert(cid, i)
 			}
+			if let Some(ed) = existential_deposit {
+				<pallet_encointer_balances::Pallet<T>>::set_existential_deposit(cid, ed);
+			}
 
 			sp_io::offchain_index::set(&cid.encode(), &community_metadata.name.encode());
 			sp_io::offchain_index::set(CACHE_DIRTY_KEY, &true.encode());
@@ -285,6 +296,107 @@ pub mod pallet {
 			Self::remove_community(cid);
 			Ok(().into())
 		}
+
+		/// Set the meetup time offset for an existing meetup `location` of the community with
+		/// `cid`, overriding the global one. `None` removes the override.
+		///
+		/// May only be called from `T::CommunityMaster`.
+		#[pallet::call_index(9)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_location_meetup_time_offset(), DispatchClass::Normal, Pays::Yes)
+        )]
+		pub fn set_location_meetup_time_offset(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			location: Location,
+			meetup_time_offset: Option<MeetupTimeOffsetType>,
+		) -> DispatchResultWithPostInfo {
+			T::CommunityMaster::ensure_origin(origin)?;
+			ensure!(
+				<pallet_encointer_scheduler::Pallet<T>>::current_phase() ==
+					CeremonyPhaseType::Registering,
+				Error::<T>::RegistrationPhaseRequired
+			);
+			Self::do_set_location_meetup_time_offset(cid, location, meetup_time_offset)
+		}
+
+		/// Add `bootstrapper` to the bootstrappers of the community with `cid`.
+		///
+		/// May only be called from `T::CommunityMaster`.
+		#[pallet::call_index(10)]
+		#[pallet::weight((<T as Config>::WeightInfo::add_bootstrapper(), DispatchClass::Normal, Pays::Yes)
+        )]
+		pub fn add_bootstrapper(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			bootstrapper: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::CommunityMaster::ensure_origin(origin)?;
+			Self::do_add_bootstrapper(cid, bootstrapper)
+		}
+
+		/// Remove `bootstrapper` from the bootstrappers of the community with `cid`.
+		///
+		/// May only be called from `T::CommunityMaster`.
+		#[pallet::call_index(11)]
+		#[pallet::weight((<T as Config>::WeightInfo::remove_bootstrapper(), DispatchClass::Normal, Pays::Yes)
+        )]
+		pub fn remove_bootstrapper(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			bootstrapper: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::CommunityMaster::ensure_origin(origin)?;
+			Self::do_remove_bootstrapper(cid, bootstrapper)
+		}
+
+		/// Replace bootstrapper `old` of the community with `cid` by `new`, e.g. after `old`
+		/// lost their keys.
+		///
+		/// May only be called from `T::CommunityMaster`.
+		#[pallet::call_index(12)]
+		#[pallet::weight((<T as Config>::WeightInfo::replace_bootstrapper(), DispatchClass::Normal, Pays::Yes)
+        )]
+		pub fn replace_bootstrapper(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			old: T::AccountId,
+			new: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::CommunityMaster::ensure_origin(origin)?;
+			Self::do_replace_bootstrapper(cid, old, new)
+		}
+
+		/// Exempt the balances of `account` in the community with `cid` from demurrage, or
+		/// subject them to demurrage again, e.g. for treasuries or escrow accounts.
+		///
+		/// May only be called from `T::CommunityMaster`.
+		#[pallet::call_index(13)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_demurrage_exemption(), DispatchClass::Normal, Pays::Yes)
+        )]
+		pub fn set_demurrage_exemption(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			account: T::AccountId,
+			exempt: bool,
+		) -> DispatchResultWithPostInfo {
+			T::CommunityMaster::ensure_origin(origin)?;
+			Self::do_set_demurrage_exemption(cid, account, exempt)
+		}
+
+		/// Set the existential deposit of the community with `cid`.
+		///
+		/// May only be called from `T::CommunityMaster`.
+		#[pallet::call_index(14)]
+		#[pallet::weight((<T as Config>::WeightInfo::update_existential_deposit(), DispatchClass::Normal, Pays::Yes)
+        )]
+		pub fn update_existential_deposit(
+			origin: OriginFor<T>,
+			cid: CommunityIdentifier,
+			existential_deposit: BalanceType,
+		) -> DispatchResultWithPostInfo {
+			T::CommunityMaster::ensure_origin(origin)?;
+			Self::do_update_existential_deposit(cid, existential_deposit)
+		}
 	}
 
 	#[pallet::event]
@@ -308,6 +420,24 @@ pub mod pallet {
 		MaxSpeedMpsUpdated(MaxSpeedMpsType),
 		/// a community has been purged
 		CommunityPurged(CommunityIdentifier),
+		/// The meetup time offset of a location has been set or removed
+		LocationMeetupTimeOffsetUpdated(
+			CommunityIdentifier,
+			Location,
+			Option<MeetupTimeOffsetType>,
+		),
+		/// A bootstrapper has been added [community_identifier, bootstrapper]
+		BootstrapperAdded(CommunityIdentifier, T::AccountId),
+		/// A bootstrapper has been removed [community_identifier, bootstrapper]
+		BootstrapperRemoved(CommunityIdentifier, T::AccountId),
+		/// A bootstrapper has been replaced [community_identifier, old, new]
+		BootstrapperReplaced(CommunityIdentifier, T::AccountId, T::AccountId),
+		/// The demurrage exemption of an account has been updated [community_identifier, account,
+		/// exempt]
+		DemurrageExemptionUpdated(CommunityIdentifier, T::AccountId, bool),
+		/// A community's existential deposit was updated [community_identifier,
+		/// new_existential_deposit]
+		ExistentialDepositUpdated(CommunityIdentifier, BalanceType),
 	}
 
 	#[pallet::error]
@@ -346,6 +476,16 @@ pub mod pallet {
 		TooManyLocationsPerGeohash,
 		/// Bootstrappers BoundedVec is full
 		TooManyBootstrappers,
+		/// Location is not a meetup location of the community
+		LocationInexistent,
+		/// Meetup time offset needs to be in [-8h, 8h]
+		InvalidMeetupTimeOffset,
+		/// Account is a bootstrapper of the community already
+		AlreadyBootstrapper,
+		/// Account is not a bootstrapper of the community
+		NotBootstrapper,
+		/// Existential deposit must not exceed the nominal income
+		InvalidExistentialDeposit,
 	}
 
 	#[pallet::storage]
@@ -370,6 +510,19 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Meetup time offset of a location, replacing the global offset of the ceremonies pallet.
+	#[pallet::storage]
+	#[pallet::getter(fn location_meetup_time_offset)]
+	pub(super) type LocationMeetupTimeOffset<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityIdentifier,
+		Blake2_128Concat,
+		Location,
+		MeetupTimeOffsetType,
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn bootstrappers)]
 	pub(super) type Bootstrappers<T: Config> = StorageMap<
@@ -512,6 +665,9 @@ impl<T: Config> Pallet<T> {
 		nominal_income: NominalIncomeType,
 	) -> DispatchResultWithPostInfo {
 		Self::ensure_cid_exists(&cid)?;
+		if let Some(ed) = pallet_encointer_balances::ExistentialDeposits::<T>::get(cid) {
+			ensure!(ed <= nominal_income, Error::<T>::InvalidNominalIncome);
+		}
 
 		<NominalIncome<T>>::insert(cid, nominal_income);
 
@@ -520,6 +676,132 @@ impl<T: Config> Pallet<T> {
 
 		Ok(().into())
 	}
+
+	pub fn do_set_location_meetup_time_offset(
+		cid: CommunityIdentifier,
+		location: Location,
+		meetup_time_offset: Option<MeetupTimeOffsetType>,
+	) -> DispatchResultWithPostInfo {
+		Self::ensure_cid_exists(&cid)?;
+		let geo_hash = GeoHash::try_from_params(location.lat, location.lon)
+			.map_err(|_| <Error<T>>::InvalidLocationForGeohash)?;
+		ensure!(
+			Self::locations(cid, &geo_hash).contains(&location),
+			Error::<T>::LocationInexistent
+		);
+
+		match meetup_time_offset {
+			Some(offset) => {
+				ensure!(
+					offset.abs() <= MAX_MEETUP_TIME_OFFSET_MS,
+					Error::<T>::InvalidMeetupTimeOffset
+				);
+				// the shifted meetup must not be reachable from a nearby one
+				Self::validate_meetup_trip_times(&location, Some((cid, offset)))?;
+				<LocationMeetupTimeOffset<T>>::insert(cid, location, offset);
+			},
+			None => <LocationMeetupTimeOffset<T>>::remove(cid, location),
+		}
+
+		info!(
+			target: LOG,
+			"set meetup time offset of location {location:?} of cid {cid:?} to {meetup_time_offset:?}"
+		);
+		Self::deposit_event(Event::LocationMeetupTimeOffsetUpdated(
+			cid,
+			location,
+			meetup_time_offset,
+		));
+		Ok(().into())
+	}
+
+	pub fn do_add_bootstrapper(
+		cid: CommunityIdentifier,
+		bootstrapper: T::AccountId,
+	) -> DispatchResultWithPostInfo {
+		Self::ensure_cid_exists(&cid)?;
+		let mut bootstrappers = Self::bootstrappers(cid);
+		ensure!(!bootstrappers.contains(&bootstrapper), Error::<T>::AlreadyBootstrapper);
+		bootstrappers
+			.try_push(bootstrapper.clone())
+			.map_err(|_| Error::<T>::TooManyBootstrappers)?;
+		<Bootstrappers<T>>::insert(cid, bootstrappers);
+
+		info!(target: LOG, "added bootstrapper {bootstrapper:?} to cid: {cid:?}");
+		Self::deposit_event(Event::BootstrapperAdded(cid, bootstrapper));
+		Ok(().into())
+	}
+
+	pub fn do_remove_bootstrapper(
+		cid: CommunityIdentifier,
+		bootstrapper: T::AccountId,
+	) -> DispatchResultWithPostInfo {
+		Self::ensure_cid_exists(&cid)?;
+		let mut bootstrappers = Self::bootstrappers(cid);
+		let index = bootstrappers
+			.iter()
+			.position(|b| b == &bootstrapper)
+			.ok_or(Error::<T>::NotBootstrapper)?;
+		bootstrappers.remove(index);
+		Self::validate_bootstrappers(&bootstrappers)?;
+		<Bootstrappers<T>>::insert(cid, bootstrappers);
+		T::OnBootstrappersChanged::on_bootstrapper_removed(cid, &bootstrapper);
+
+		info!(target: LOG, "removed bootstrapper {bootstrapper:?} from cid: {cid:?}");
+		Self::deposit_event(Event::BootstrapperRemoved(cid, bootstrapper));
+		Ok(().into())
+	}
+
+	pub fn do_replace_bootstrapper(
+		cid: CommunityIdentifier,
+		old: T::AccountId,
+		new: T::AccountId,
+	) -> DispatchResultWithPostInfo {
+		Self::ensure_cid_exists(&cid)?;
+		let mut bootstrappers = Self::bootstrappers(cid);
+		ensure!(!bootstrappers.contains(&new), Error::<T>::AlreadyBootstrapper);
+		let index = bootstrappers
+			.iter()
+			.position(|b| b == &old)
+			.ok_or(Error::<T>::NotBootstrapper)?;
+		bootstrappers[index] = new.clone();
+		<Bootstrappers<T>>::insert(cid, bootstrappers);
+		T::OnBootstrappersChanged::on_bootstrapper_replaced(cid, &old, &new);
+
+		info!(target: LOG, "replaced bootstrapper {old:?} by {new:?} in cid: {cid:?}");
+		Self::deposit_event(Event::BootstrapperReplaced(cid, old, new));
+		Ok(().into())
+	}
+
+	pub fn do_set_demurrage_exemption(
+		cid: CommunityIdentifier,
+		account: T::AccountId,
+		exempt: bool,
+	) -> DispatchResultWithPostInfo {
+		Self::ensure_cid_exists(&cid)?;
+		<pallet_encointer_balances::Pallet<T>>::set_demurrage_exempt(cid, &account, exempt);
+
+		info!(target: LOG, "set demurrage exemption of {account:?} for cid: {cid:?} to {exempt}");
+		Self::deposit_event(Event::DemurrageExemptionUpdated(cid, account, exempt));
+		Ok(().into())
+	}
+
+	pub fn do_update_existential_deposit(
+		cid: CommunityIdentifier,
+		existential_deposit: BalanceType,
+	) -> DispatchResultWithPostInfo {
+		Self::ensure_cid_exists(&cid)?;
+		Self::validate_existential_deposit(
+			existential_deposit,
+			<NominalIncome<T>>::try_get(cid).ok(),
+		)?;
+		<pallet_encointer_balances::Pallet<T>>::set_existential_deposit(cid, existential_deposit);
+
+		info!(target: LOG, " updated existential deposit for cid: {cid:?}");
+		Self::deposit_event(Event::ExistentialDepositUpdated(cid, existential_deposit));
+		Ok(().into())
+	}
+
 	fn remove_location_intern(cid: CommunityIdentifier, location: Location, geo_hash: GeoHash) {
 		//remove location from locations(cid,geohash)
 		let mut locations = Self::locations(cid, &geo_hash);
@@ -528,6 +810,7 @@ impl<T: Config> Pallet<T> {
 			locations.remove(index);
 			locations_len = locations.len();
 			<Locations<T>>::insert(cid, &geo_hash, locations);
+			<LocationMeetupTimeOffset<T>>::remove(cid, location);
 		}
 		// if the list from above is now empty (community has no more locations in this bucket)
 		// remove cid from cids_by_geohash(geohash)
@@ -580,16 +863,35 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn solar_trip_time(from: &Location, to: &Location) -> u32 {
+		Self::meetup_trip_time(from, to, 0)
+	}
+
+	/// Like `solar_trip_time`, but for a meetup at `to` taking place `offset_difference` ms
+	/// later, relative to the sun, than the one at `from`.
+	fn meetup_trip_time(from: &Location, to: &Location, offset_difference: i64) -> u32 {
 		// FIXME: replace by fixpoint implementation within runtime.
 		let d = Pallet::<T>::haversine_distance(from, to); //orthodromic distance bewteen points [m]
 
 		// FIXME: this will not panic, but make sure!
-		let dt = (from.lon - to.lon) * 240; //time, the sun-high needs to travel between locations [s]
+		let dt = (from.lon - to.lon) * 240 + Degree::from_num(offset_difference) / 1000; //time between the meetups [s]
 		let tflight = d / Self::max_speed_mps(); // time required to travel between locations at MaxSpeedMps [s]
 		let dt: u32 = i64::lossy_from(dt.abs()).saturated_into();
 		tflight.saturating_sub(dt)
 	}
 
+	/// Accounts holding less than the existential deposit are removed, so it must not exceed the
+	/// nominal income if the community has one.
+	fn validate_existential_deposit(
+		existential_deposit: BalanceType,
+		nominal_income: Option<NominalIncomeType>,
+	) -> DispatchResult {
+		ensure!(
+			nominal_income.is_none_or(|income| existential_deposit <= income),
+			Error::<T>::InvalidExistentialDeposit
+		);
+		Ok(())
+	}
+
 	fn ensure_cid_exists(cid: &CommunityIdentifier) -> DispatchResult {
 		match Self::community_identifiers().contains(cid) {
 			true => Ok(()),
@@ -745,12 +1047,61 @@ impl<T: Config> Pallet<T> {
 			return Err(<Error<T>>::MinimumDistanceViolationToDateLine)?;
 		}
 
-		let nearby_locations = Self::get_nearby_locations(location)?;
-		for nearby_location in nearby_locations {
-			ensure!(
-				Self::solar_trip_time(location, &nearby_location) >= Self::min_solar_trip_time_s(),
-				<Error<T>>::MinimumDistanceViolationToOtherLocation
-			);
+		Self::validate_meetup_trip_times(location, None)
+	}
+
+	/// Ensures that nobody can attend the meetup at `location` as well as a nearby one, taking
+	/// the meetup time offsets of the locations into account.
+	///
+	/// `existing` is the community and the meetup time offset of `location` if it has been added
+	/// already. New locations have no offset.
+	fn validate_meetup_trip_times(
+		location: &Location,
+		existing: Option<(CommunityIdentifier, MeetupTimeOffsetType)>,
+	) -> DispatchResult {
+		let offset = existing.map_or(0, |(_, offset)| offset);
+		let relevant_locations = Self::get_nearby_locations(location)?;
+		let geo_hash = GeoHash::try_from_params(location.lat, location.lon)
+			.map_err(|_| <Error<T>>::InvalidLocationForGeohash)?;
+		let neighbors = geo_hash.neighbors().map_err(|_| <Error<T>>::InvalidGeohash)?;
+		let buckets = [
+			geo_hash,
+			neighbors.n,
+			neighbors.ne,
+			neighbors.e,
+			neighbors.se,
+			neighbors.s,
+			neighbors.sw,
+			neighbors.w,
+			neighbors.nw,
+		];
+		for bucket in buckets.iter() {
+			for nearby_cid in Self::cids_by_geohash(bucket) {
+				for nearby_location in Self::locations(nearby_cid, bucket) {
+					if existing.is_some_and(|(cid, _)| cid == nearby_cid) &&
+						nearby_location == *location
+					{
+						continue;
+					}
+					let nearby_offset =
+						Self::location_meetup_time_offset(nearby_cid, nearby_location).unwrap_or(0);
+					// the sun takes too long to travel to the irrelevant buckets, unless offsets
+					// make up for it
+					if offset == 0 &&
+						nearby_offset == 0 && !relevant_locations.contains(&nearby_location)
+					{
+						continue;
+					}
+					ensure!(
+						Self::meetup_trip_time(
+							location,
+							&nearby_location,
+							i64::from(nearby_offset) - i64::from(offset)
+						) >= Self::min_solar_trip_time_s(),
+						<Error<T>>::MinimumDistanceViolationToOtherLocation
+					);
+				}
+			}
 		}
 		Ok(())
 	}
@@ -789,6 +1140,13 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+/// An event handler for when the bootstrappers of a community change.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait OnBootstrappersChanged<AccountId> {
+	fn on_bootstrapper_removed(cid: CommunityIdentifier, bootstrapper: &AccountId);
+	fn on_bootstrapper_replaced(cid: CommunityIdentifier, old: &AccountId, new: &AccountId);
+}
+
 mod weights;
 
 #[cfg(test)]
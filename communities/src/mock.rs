@@ -29,6 +29,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		EncointerScheduler: pallet_encointer_scheduler::{Pallet, Call, Storage, Config<T>, Event},
 		EncointerCommunities: dut::{Pallet, Call, Storage, Event<T>},
 		EncointerBalances: pallet_encointer_balances::{Pallet, Call, Storage, Event<T>},
@@ -48,11 +49,13 @@ impl dut::Config for TestRuntime {
 	type MaxBootstrappers = ConstU32<15>;
 	type MaxLocationsPerGeohash = ConstU32<200>;
 	type MaxCommunityIdentifiersPerGeohash = ConstU32<10>;
+	type OnBootstrappersChanged = ();
 }
 
 // boilerplate
 impl_frame_system!(TestRuntime);
 impl_timestamp!(TestRuntime, EncointerScheduler);
+impl_balances!(TestRuntime, System);
 impl_encointer_scheduler!(TestRuntime);
 impl_encointer_balances!(TestRuntime);
 
@@ -59,6 +59,7 @@ pub fn register_test_community(
 		Default::default(),
 		None,
 		None,
+		None,
 	)
 	.unwrap();
 	CommunityIdentifier::new(location, bs).unwrap()
@@ -150,7 +151,8 @@ fn new_community_works() {
 			bs.clone(),
 			community_meta.clone(),
 			None,
-			None
+			None,
+			Some(BalanceType::from_num(0.1))
 		));
 
 		let cid = CommunityIdentifier::new(location, bs.clone()).unwrap();
@@ -164,6 +166,7 @@ fn new_community_works() {
 		assert_eq!(EncointerCommunities::bootstrappers(cid), bs);
 		assert_eq!(EncointerCommunities::bootstrappers(cid), bs);
 		assert_eq!(EncointerCommunities::community_metadata(cid), community_meta);
+		assert_eq!(EncointerBalances::existential_deposit(cid), BalanceType::from_num(0.1));
 	});
 }
 
@@ -193,6 +196,7 @@ fn two_communities_in_same_bucket_works() {
 			bs.clone(),
 			community_meta.clone(),
 			None,
+			None,
 			None
 		));
 
@@ -202,6 +206,7 @@ fn two_communities_in_same_bucket_works() {
 			bs2.clone(),
 			community_meta,
 			None,
+			None,
 			None
 		));
 
@@ -513,6 +518,464 @@ fn remove_location_errs_with_invalid_origin() {
 	});
 }
 
+#[test]
+fn set_location_meetup_time_offset_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let cid = register_test_community(None, 0.0, 0.0);
+		let location = Location { lat: T::from_num(0i32), lon: T::from_num(0i32) };
+		assert_eq!(EncointerCommunities::location_meetup_time_offset(cid, location), None);
+
+		assert_ok!(EncointerCommunities::set_location_meetup_time_offset(
+			RuntimeOrigin::signed(master()),
+			cid,
+			location,
+			Some(-3600 * 1000),
+		));
+		assert_eq!(
+			last_event::<TestRuntime>(),
+			Some(Event::LocationMeetupTimeOffsetUpdated(cid, location, Some(-3600 * 1000)).into())
+		);
+		assert_eq!(
+			EncointerCommunities::location_meetup_time_offset(cid, location),
+			Some(-3600 * 1000)
+		);
+
+		assert_ok!(EncointerCommunities::set_location_meetup_time_offset(
+			RuntimeOrigin::signed(master()),
+			cid,
+			location,
+			None,
+		));
+		assert_eq!(EncointerCommunities::location_meetup_time_offset(cid, location), None);
+	});
+}
+
+#[test]
+fn set_location_meetup_time_offset_errs_with_invalid_origin() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community(None, 0.0, 0.0);
+		assert_dispatch_err(
+			EncointerCommunities::set_location_meetup_time_offset(
+				RuntimeOrigin::signed(AccountKeyring::Bob.into()),
+				cid,
+				Location::default(),
+				Some(0),
+			),
+			DispatchError::BadOrigin,
+		);
+	});
+}
+
+#[test]
+fn set_location_meetup_time_offset_errs_with_unknown_location_or_invalid_offset() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community(None, 0.0, 0.0);
+		let location = Location { lat: T::from_num(0i32), lon: T::from_num(0i32) };
+		assert_dispatch_err(
+			EncointerCommunities::set_location_meetup_time_offset(
+				RuntimeOrigin::signed(master()),
+				cid,
+				Location { lat: T::from_num(1i32), lon: T::from_num(1i32) },
+				Some(0),
+			),
+			Error::<TestRuntime>::LocationInexistent.into(),
+		);
+		assert_dispatch_err(
+			EncointerCommunities::set_location_meetup_time_offset(
+				RuntimeOrigin::signed(master()),
+				cid,
+				location,
+				Some(MAX_MEETUP_TIME_OFFSET_MS + 1),
+			),
+			Error::<TestRuntime>::InvalidMeetupTimeOffset.into(),
+		);
+	});
+}
+
+#[test]
+fn location_meetup_time_offset_respects_min_solar_trip_time() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community(None, 0.0, 0.0);
+		let location = Location { lat: T::from_num(0i32), lon: T::from_num(0i32) };
+		// roughly 1.1 km to the east, its meetup takes place 2s earlier
+		let nearby = Location { lat: T::from_num(0i32), lon: T::from_num(0.01) };
+		assert_ok!(EncointerCommunities::add_location(
+			RuntimeOrigin::signed(master()),
+			cid,
+			nearby
+		));
+
+		// an hour later, one could easily attend both meetups
+		assert_dispatch_err(
+			EncointerCommunities::set_location_meetup_time_offset(
+				RuntimeOrigin::signed(master()),
+				cid,
+				nearby,
+				Some(3600 * 1000),
+			),
+			Error::<TestRuntime>::MinimumDistanceViolationToOtherLocation.into(),
+		);
+		assert_ok!(EncointerCommunities::set_location_meetup_time_offset(
+			RuntimeOrigin::signed(master()),
+			cid,
+			nearby,
+			Some(2000),
+		));
+
+		// new locations are validated against shifted meetups too
+		assert_ok!(EncointerCommunities::remove_location(
+			RuntimeOrigin::signed(master()),
+			cid,
+			nearby
+		));
+		assert_ok!(EncointerCommunities::set_location_meetup_time_offset(
+			RuntimeOrigin::signed(master()),
+			cid,
+			location,
+			Some(-3600 * 1000),
+		));
+		assert_dispatch_err(
+			EncointerCommunities::add_location(
+				RuntimeOrigin::signed(master()),
+				cid,
+				Location { lat: T::from_num(0.01), lon: T::from_num(0i32) },
+			),
+			Error::<TestRuntime>::MinimumDistanceViolationToOtherLocation.into(),
+		);
+	});
+}
+
+#[test]
+fn remove_location_removes_meetup_time_offset() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community(None, 0.0, 0.0);
+		let location = Location { lat: T::from_num(0i32), lon: T::from_num(0i32) };
+		assert_ok!(EncointerCommunities::set_location_meetup_time_offset(
+			RuntimeOrigin::signed(master()),
+			cid,
+			location,
+			Some(3600 * 1000),
+		));
+
+		assert_ok!(EncointerCommunities::remove_location(
+			RuntimeOrigin::signed(master()),
+			cid,
+			location,
+		));
+		assert_eq!(EncointerCommunities::location_meetup_time_offset(cid, location), None);
+	});
+}
+
+#[test]
+fn add_bootstrapper_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let cid = register_test_community(None, 0.0, 0.0);
+		let new_bootstrapper = AccountId::from(AccountKeyring::One);
+
+		assert_ok!(EncointerCommunities::add_bootstrapper(
+			RuntimeOrigin::signed(master()),
+			cid,
+			new_bootstrapper.clone(),
+		));
+		assert_eq!(
+			last_event::<TestRuntime>(),
+			Some(Event::BootstrapperAdded(cid, new_bootstrapper.clone()).into())
+		);
+		assert!(EncointerCommunities::bootstrappers(cid).contains(&new_bootstrapper));
+
+		assert_dispatch_err(
+			EncointerCommunities::add_bootstrapper(
+				RuntimeOrigin::signed(master()),
+				cid,
+				new_bootstrapper,
+			),
+			Error::<TestRuntime>::AlreadyBootstrapper.into(),
+		);
+	});
+}
+
+#[test]
+fn remove_bootstrapper_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let cid = register_test_community(None, 0.0, 0.0);
+		let bootstrapper = account_id(&bootstrappers()[0]);
+
+		assert_ok!(EncointerCommunities::remove_bootstrapper(
+			RuntimeOrigin::signed(master()),
+			cid,
+			bootstrapper.clone(),
+		));
+		assert_eq!(
+			last_event::<TestRuntime>(),
+			Some(Event::BootstrapperRemoved(cid, bootstrapper.clone()).into())
+		);
+		assert_eq!(EncointerCommunities::bootstrappers(cid).len(), bootstrappers().len() - 1);
+		assert!(!EncointerCommunities::bootstrappers(cid).contains(&bootstrapper));
+
+		assert_dispatch_err(
+			EncointerCommunities::remove_bootstrapper(
+				RuntimeOrigin::signed(master()),
+				cid,
+				bootstrapper,
+			),
+			Error::<TestRuntime>::NotBootstrapper.into(),
+		);
+	});
+}
+
+#[test]
+fn remove_bootstrapper_keeps_minimum_number_of_bootstrappers() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community(None, 0.0, 0.0);
+		let bootstrappers: Vec<AccountId> = bootstrappers().iter().map(account_id).collect();
+
+		for b in bootstrappers.iter().skip(3) {
+			assert_ok!(EncointerCommunities::remove_bootstrapper(
+				RuntimeOrigin::signed(master()),
+				cid,
+				b.clone(),
+			));
+		}
+		assert_dispatch_err(
+			EncointerCommunities::remove_bootstrapper(
+				RuntimeOrigin::signed(master()),
+				cid,
+				bootstrappers[0].clone(),
+			),
+			Error::<TestRuntime>::InvalidAmountBootstrappers.into(),
+		);
+	});
+}
+
+#[test]
+fn replace_bootstrapper_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let cid = register_test_community(None, 0.0, 0.0);
+		let old = account_id(&bootstrappers()[0]);
+		let new = AccountId::from(AccountKeyring::One);
+
+		assert_ok!(EncointerCommunities::replace_bootstrapper(
+			RuntimeOrigin::signed(master()),
+			cid,
+			old.clone(),
+			new.clone(),
+		));
+		assert_eq!(
+			last_event::<TestRuntime>(),
+			Some(Event::BootstrapperReplaced(cid, old.clone(), new.clone()).into())
+		);
+		assert_eq!(EncointerCommunities::bootstrappers(cid)[0], new);
+		assert!(!EncointerCommunities::bootstrappers(cid).contains(&old));
+
+		// the replacement must not be a bootstrapper already
+		assert_dispatch_err(
+			EncointerCommunities::replace_bootstrapper(
+				RuntimeOrigin::signed(master()),
+				cid,
+				new,
+				account_id(&bootstrappers()[1]),
+			),
+			Error::<TestRuntime>::AlreadyBootstrapper.into(),
+		);
+	});
+}
+
+#[test]
+fn set_demurrage_exemption_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let cid = register_test_community(None, 0.0, 0.0);
+		let treasury = AccountId::from(AccountKeyring::One);
+		assert_ok!(EncointerBalances::issue(cid, &treasury, BalanceType::from_num(10)));
+
+		assert_ok!(EncointerCommunities::set_demurrage_exemption(
+			RuntimeOrigin::signed(master()),
+			cid,
+			treasury.clone(),
+			true,
+		));
+		assert_eq!(
+			last_event::<TestRuntime>(),
+			Some(Event::DemurrageExemptionUpdated(cid, treasury.clone(), true).into())
+		);
+		assert!(EncointerBalances::is_demurrage_exempt(cid, &treasury));
+		assert_eq!(EncointerBalances::exempt_issuance(cid), BalanceType::from_num(10));
+
+		assert_ok!(EncointerCommunities::set_demurrage_exemption(
+			RuntimeOrigin::signed(master()),
+			cid,
+			treasury.clone(),
+			false,
+		));
+		assert!(!EncointerBalances::is_demurrage_exempt(cid, &treasury));
+	});
+}
+
+#[test]
+fn set_demurrage_exemption_errs_with_invalid_origin_or_inexistent_community() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community(None, 0.0, 0.0);
+		let bob = AccountId::from(AccountKeyring::Bob);
+		assert_dispatch_err(
+			EncointerCommunities::set_demurrage_exemption(
+				RuntimeOrigin::signed(bob.clone()),
+				cid,
+				bob.clone(),
+				true,
+			),
+			DispatchError::BadOrigin,
+		);
+		assert_dispatch_err(
+			EncointerCommunities::set_demurrage_exemption(
+				RuntimeOrigin::signed(master()),
+				CommunityIdentifier::default(),
+				bob,
+				true,
+			),
+			Error::<TestRuntime>::CommunityInexistent.into(),
+		);
+	});
+}
+
+#[test]
+fn update_existential_deposit_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let cid = register_test_community(None, 0.0, 0.0);
+		let ed = BalanceType::from_num(0.5);
+
+		assert_ok!(EncointerCommunities::update_existential_deposit(
+			RuntimeOrigin::signed(master()),
+			cid,
+			ed,
+		));
+		assert_eq!(
+			last_event::<TestRuntime>(),
+			Some(Event::ExistentialDepositUpdated(cid, ed).into())
+		);
+		assert_eq!(EncointerBalances::existential_deposit(cid), ed);
+	});
+}
+
+#[test]
+fn update_existential_deposit_errs_with_invalid_origin_or_inexistent_community() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community(None, 0.0, 0.0);
+		let bob = AccountId::from(AccountKeyring::Bob);
+		assert_dispatch_err(
+			EncointerCommunities::update_existential_deposit(
+				RuntimeOrigin::signed(bob),
+				cid,
+				BalanceType::from_num(1),
+			),
+			DispatchError::BadOrigin,
+		);
+		assert_dispatch_err(
+			EncointerCommunities::update_existential_deposit(
+				RuntimeOrigin::signed(master()),
+				CommunityIdentifier::default(),
+				BalanceType::from_num(1),
+			),
+			Error::<TestRuntime>::CommunityInexistent.into(),
+		);
+	});
+}
+
+#[test]
+fn existential_deposit_may_not_exceed_nominal_income() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community(None, 0.0, 0.0);
+		assert_ok!(EncointerCommunities::update_nominal_income(
+			RuntimeOrigin::signed(master()),
+			cid,
+			BalanceType::from_num(1),
+		));
+		assert_dispatch_err(
+			EncointerCommunities::update_existential_deposit(
+				RuntimeOrigin::signed(master()),
+				cid,
+				BalanceType::from_num(1.5),
+			),
+			Error::<TestRuntime>::InvalidExistentialDeposit.into(),
+		);
+		assert_ok!(EncointerCommunities::update_existential_deposit(
+			RuntimeOrigin::signed(master()),
+			cid,
+			BalanceType::from_num(1),
+		));
+		assert_dispatch_err(
+			EncointerCommunities::update_nominal_income(
+				RuntimeOrigin::signed(master()),
+				cid,
+				BalanceType::from_num(0.5),
+			),
+			Error::<TestRuntime>::InvalidNominalIncome.into(),
+		);
+	});
+}
+
+#[test]
+fn new_community_with_existential_deposit_above_nominal_income_fails() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from(AccountKeyring::Alice);
+		let bob = AccountId::from(AccountKeyring::Bob);
+		let charlie = AccountId::from(AccountKeyring::Charlie);
+		let location = Location { lat: T::from_num(1i32), lon: T::from_num(1i32) };
+		let bs = vec![alice.clone(), bob.clone(), charlie.clone()];
+
+		assert_dispatch_err(
+			EncointerCommunities::new_community(
+				RuntimeOrigin::signed(alice),
+				location,
+				bs,
+				Default::default(),
+				None,
+				Some(BalanceType::from_num(1)),
+				Some(BalanceType::from_num(2)),
+			),
+			Error::<TestRuntime>::InvalidExistentialDeposit.into(),
+		);
+	});
+}
+
+#[test]
+fn bootstrapper_changes_err_with_invalid_origin() {
+	new_test_ext().execute_with(|| {
+		let cid = register_test_community(None, 0.0, 0.0);
+		let bob = AccountId::from(AccountKeyring::Bob);
+		let one = AccountId::from(AccountKeyring::One);
+		assert_dispatch_err(
+			EncointerCommunities::add_bootstrapper(
+				RuntimeOrigin::signed(bob.clone()),
+				cid,
+				one.clone(),
+			),
+			DispatchError::BadOrigin,
+		);
+		assert_dispatch_err(
+			EncointerCommunities::remove_bootstrapper(
+				RuntimeOrigin::signed(bob.clone()),
+				cid,
+				bob.clone(),
+			),
+			DispatchError::BadOrigin,
+		);
+		assert_dispatch_err(
+			EncointerCommunities::replace_bootstrapper(
+				RuntimeOrigin::signed(bob.clone()),
+				cid,
+				bob,
+				one,
+			),
+			DispatchError::BadOrigin,
+		);
+	});
+}
+
 #[test]
 fn new_community_too_close_to_existing_community_fails() {
 	new_test_ext().execute_with(|| {
@@ -527,6 +990,7 @@ fn new_community_too_close_to_existing_community_fails() {
 			bs.clone(),
 			Default::default(),
 			None,
+			None,
 			None
 		));
 
@@ -538,6 +1002,7 @@ fn new_community_too_close_to_existing_community_fails() {
 			bs,
 			Default::default(),
 			None,
+			None,
 			None
 		)
 		.is_err());
@@ -559,6 +1024,7 @@ fn new_community_with_near_pole_locations_fails() {
 			bs.clone(),
 			Default::default(),
 			None,
+			None,
 			None
 		)
 		.is_err());
@@ -571,6 +1037,7 @@ fn new_community_with_near_pole_locations_fails() {
 			bs,
 			Default::default(),
 			None,
+			None,
 			None
 		)
 		.is_err());
@@ -593,6 +1060,7 @@ fn new_community_near_dateline_fails() {
 			bs,
 			Default::default(),
 			None,
+			None,
 			None
 		)
 		.is_err());
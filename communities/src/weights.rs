@@ -58,6 +58,12 @@ pub trait WeightInfo {
 	fn set_min_solar_trip_time_s() -> Weight;
 	fn set_max_speed_mps() -> Weight;
 	fn purge_community() -> Weight;
+	fn set_location_meetup_time_offset() -> Weight;
+	fn add_bootstrapper() -> Weight;
+	fn remove_bootstrapper() -> Weight;
+	fn replace_bootstrapper() -> Weight;
+	fn set_demurrage_exemption() -> Weight;
+	fn update_existential_deposit() -> Weight;
 }
 
 // For tests
@@ -65,7 +71,7 @@ impl WeightInfo for () {
 	fn new_community() -> Weight {
 		Weight::from_parts(8_232_800_000, 0)
 			.saturating_add(RocksDbWeight::get().reads(5))
-			.saturating_add(RocksDbWeight::get().writes(7))
+			.saturating_add(RocksDbWeight::get().writes(8))
 	}
 	fn add_location() -> Weight {
 		Weight::from_parts(8_156_200_000, 0)
@@ -103,4 +109,34 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4))
 			.saturating_add(RocksDbWeight::get().writes(6))
 	}
+	fn set_location_meetup_time_offset() -> Weight {
+		Weight::from_parts(32_400_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn add_bootstrapper() -> Weight {
+		Weight::from_parts(27_300_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn remove_bootstrapper() -> Weight {
+		Weight::from_parts(61_900_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+	fn replace_bootstrapper() -> Weight {
+		Weight::from_parts(64_200_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	fn set_demurrage_exemption() -> Weight {
+		Weight::from_parts(35_800_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	fn update_existential_deposit() -> Weight {
+		Weight::from_parts(25_600_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
 }
@@ -0,0 +1,43 @@
+// Copyright (c) 2019 Alain Brenzikofer
+// This file is part of Encointer
+//
+// Encointer is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Encointer is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Encointer.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition required by Democracy RPC extensions.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+use sp_std::vec::Vec;
+
+use encointer_primitives::{
+	ceremonies::{CommunityCeremony, ReputationCountType},
+	democracy::{Proposal, ProposalFilter, ProposalIdType, Tally},
+};
+use parity_scale_codec::{Decode, Encode};
+
+sp_api::decl_runtime_apis! {
+	pub trait DemocracyApi<AccountId, Moment, Balance, AssetId>
+	where AccountId: Encode + Decode,
+	Moment: Encode + Decode,
+	Balance: Encode + Decode,
+	AssetId: Encode + Decode
+	{
+		fn get_proposals(filter: ProposalFilter) -> Vec<(ProposalIdType, Proposal<Moment, AccountId, Balance, AssetId>)>;
+		fn get_proposal(proposal_id: ProposalIdType) -> Option<(Proposal<Moment, AccountId, Balance, AssetId>, Tally)>;
+		fn is_passing(proposal_id: ProposalIdType) -> Option<bool>;
+		fn get_electorate(proposal_id: ProposalIdType) -> Option<ReputationCountType>;
+		fn get_eligible_reputations(account: &AccountId, proposal_id: ProposalIdType) -> Vec<CommunityCeremony>;
+	}
+}
@@ -0,0 +1,153 @@
+// Copyright (c) 2019 Alain Brenzikofer
+// This file is part of Encointer
+//
+// Encointer is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Encointer is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Encointer.  If not, see <http://www.gnu.org/licenses/>.
+
+use encointer_primitives::{
+	ceremonies::{CommunityCeremony, ReputationCountType},
+	democracy::{Proposal, ProposalFilter, ProposalIdType, Tally},
+};
+use encointer_rpc::Error;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use pallet_encointer_democracy_rpc_runtime_api::DemocracyApi as DemocracyRuntimeApi;
+use parity_scale_codec::{Decode, Encode};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+#[rpc(client, server)]
+pub trait DemocracyApi<BlockHash, AccountId, Moment, Balance, AssetId>
+where
+	AccountId: 'static + Encode + Decode + Send + Sync,
+	Moment: 'static + Encode + Decode + Send + Sync,
+	Balance: 'static + Encode + Decode + Send + Sync,
+	AssetId: 'static + Encode + Decode + Send + Sync,
+{
+	#[method(name = "encointer_getProposals", blocking)]
+	fn get_proposals(
+		&self,
+		filter: ProposalFilter,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(ProposalIdType, Proposal<Moment, AccountId, Balance, AssetId>)>>;
+
+	#[method(name = "encointer_getProposal")]
+	fn get_proposal(
+		&self,
+		proposal_id: ProposalIdType,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(Proposal<Moment, AccountId, Balance, AssetId>, Tally)>>;
+
+	#[method(name = "encointer_isPassing")]
+	fn is_passing(
+		&self,
+		proposal_id: ProposalIdType,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<bool>>;
+
+	#[method(name = "encointer_getElectorate")]
+	fn get_electorate(
+		&self,
+		proposal_id: ProposalIdType,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ReputationCountType>>;
+
+	#[method(name = "encointer_getEligibleReputations", blocking)]
+	fn get_eligible_reputations(
+		&self,
+		account: AccountId,
+		proposal_id: ProposalIdType,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<CommunityCeremony>>;
+}
+
+pub struct DemocracyRpc<Client, Block, AccountId, Moment, Balance, AssetId> {
+	client: Arc<Client>,
+	_marker: std::marker::PhantomData<(Block, AccountId, Moment, Balance, AssetId)>,
+}
+
+impl<Client, Block, AccountId, Moment, Balance, AssetId>
+	DemocracyRpc<Client, Block, AccountId, Moment, Balance, AssetId>
+{
+	/// Create new `Democracy` instance with the given reference to the client.
+	pub fn new(client: Arc<Client>) -> Self {
+		DemocracyRpc { client, _marker: Default::default() }
+	}
+}
+
+impl<Client, Block, AccountId, Moment, Balance, AssetId>
+	DemocracyApiServer<<Block as BlockT>::Hash, AccountId, Moment, Balance, AssetId>
+	for DemocracyRpc<Client, Block, AccountId, Moment, Balance, AssetId>
+where
+	AccountId: 'static + Clone + Encode + Decode + Send + Sync,
+	Moment: 'static + Clone + Encode + Decode + Send + Sync,
+	Balance: 'static + Clone + Encode + Decode + Send + Sync,
+	AssetId: 'static + Clone + Encode + Decode + Send + Sync,
+	Block: BlockT,
+	Client: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	Client::Api: DemocracyRuntimeApi<Block, AccountId, Moment, Balance, AssetId>,
+{
+	fn get_proposals(
+		&self,
+		filter: ProposalFilter,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(ProposalIdType, Proposal<Moment, AccountId, Balance, AssetId>)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		Ok(api.get_proposals(at, filter).map_err(|e| Error::Runtime(e.into()))?)
+	}
+
+	fn get_proposal(
+		&self,
+		proposal_id: ProposalIdType,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<(Proposal<Moment, AccountId, Balance, AssetId>, Tally)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		Ok(api.get_proposal(at, proposal_id).map_err(|e| Error::Runtime(e.into()))?)
+	}
+
+	fn is_passing(
+		&self,
+		proposal_id: ProposalIdType,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<bool>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		Ok(api.is_passing(at, proposal_id).map_err(|e| Error::Runtime(e.into()))?)
+	}
+
+	fn get_electorate(
+		&self,
+		proposal_id: ProposalIdType,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<ReputationCountType>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		Ok(api.get_electorate(at, proposal_id).map_err(|e| Error::Runtime(e.into()))?)
+	}
+
+	fn get_eligible_reputations(
+		&self,
+		account: AccountId,
+		proposal_id: ProposalIdType,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<CommunityCeremony>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		Ok(api
+			.get_eligible_reputations(at, &account, proposal_id)
+			.map_err(|e| Error::Runtime(e.into()))?)
+	}
+}
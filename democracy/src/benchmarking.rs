@@ -2,17 +2,19 @@ use crate::{Pallet as EncointerDemocracy, *};
 use alloc::boxed::Box;
 use encointer_primitives::{
 	ceremonies::Reputation,
-	communities::CommunityIdentifier,
-	democracy::{ProposalState, Tally, Vote},
+	communities::{CommunityIdentifier, CommunityMetadata, Degree, Location},
+	democracy::{Conviction, ProposalState, Tally, Vote, VoteLock},
 	storage::{current_ceremony_index_key, global_reputation_count, participant_reputation},
 };
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::{
 	assert_ok,
-	traits::{OnFinalize, OriginTrait},
+	traits::{Currency, EnsureOrigin, OnFinalize, OriginTrait},
+	weights::Weight,
 	BoundedVec,
 };
 use frame_system::RawOrigin;
+use pallet_encointer_communities::Pallet as Communities;
 use parity_scale_codec::Encode;
 #[cfg(not(feature = "std"))]
 use sp_std::vec;
@@ -23,6 +25,56 @@ fn advance_timestamp_by<T: Config>(dt: T::Moment) {
 	pallet_timestamp::Pallet::<T>::on_finalize(frame_system::Pallet::<T>::block_number());
 }
 
+fn fund_proposer<T: Config>(who: &T::AccountId) {
+	let deposit = <T as Config>::ProposalDeposit::get();
+	T::VoteLockCurrency::make_free_balance_be(
+		who,
+		deposit.saturating_add(T::VoteLockCurrency::minimum_balance()),
+	);
+}
+
+const NUM_LOCATIONS: u32 = 200;
+
+// as it is complicated to compute sqrt in no_std
+const NUM_LOCATIONS_SQRT: u32 = 32;
+
+fn get_location(i: u32) -> Location {
+	// locations close to each other, so that many of them map to the same geohash bucket, which
+	// is close to the worst case for the location validation
+	let lon = 47.460932 + (i % NUM_LOCATIONS_SQRT) as f64 * 0.001;
+	let lat = 8.437509 + (i / NUM_LOCATIONS_SQRT) as f64 * 0.001;
+	Location { lat: Degree::from_num(lat), lon: Degree::from_num(lon) }
+}
+
+/// The number of locations which fit into the geohash bucket of `get_location`.
+fn num_locations<T: Config>() -> u32 {
+	NUM_LOCATIONS.min(<T as pallet_encointer_communities::Config>::MaxLocationsPerGeohash::get())
+}
+
+/// Creates a community with all but one of `num_locations` locations.
+fn setup_community_with_locations<T: Config>() -> CommunityIdentifier {
+	assert_ok!(Communities::<T>::set_max_speed_mps(RawOrigin::Root.into(), 83));
+	assert_ok!(Communities::<T>::set_min_solar_trip_time_s(RawOrigin::Root.into(), 1));
+	let bootstrappers: Vec<T::AccountId> = (0..10).map(|n| account("dummy name", n, n)).collect();
+	assert_ok!(Communities::<T>::new_community(
+		RawOrigin::Root.into(),
+		get_location(0),
+		bootstrappers.clone(),
+		CommunityMetadata {
+			name: PalletString::try_from("20charsaaaaaaaaaaaaa".as_bytes().to_vec()).unwrap(),
+			..Default::default()
+		},
+		None,
+		None,
+		None
+	));
+	let cid = CommunityIdentifier::new(get_location(0), bootstrappers).unwrap();
+	for i in 1..num_locations::<T>() - 1 {
+		assert_ok!(Communities::<T>::do_add_location(cid, get_location(i)));
+	}
+	cid
+}
+
 benchmarks! {
 	where_clause {
 		where
@@ -31,6 +83,7 @@ benchmarks! {
 	}
 	submit_proposal {
 		let zoran = account("zoran", 1, 1);
+		fund_proposer::<T>(&zoran);
 		let cid = CommunityIdentifier::default();
 		// worst case is petition
 		let proposal_action = ProposalAction::Petition(Some(cid), PalletString::try_from("xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx\
@@ -47,6 +100,7 @@ benchmarks! {
 		frame_support::storage::unhashed::put_raw(&current_ceremony_index_key(), &7u32.encode());
 
 		let zoran = account::<T::AccountId>("zoran", 1, 1);
+		fund_proposer::<T>(&zoran);
 		let cid = CommunityIdentifier::default();
 
 		frame_support::storage::unhashed::put_raw(&participant_reputation((cid, 3), &zoran), &Reputation::VerifiedUnlinked.encode());
@@ -80,6 +134,7 @@ benchmarks! {
 	update_proposal_state {
 		frame_support::storage::unhashed::put_raw(&current_ceremony_index_key(), &7u32.encode());
 		let zoran = account::<T::AccountId>("zoran", 1, 1);
+		fund_proposer::<T>(&zoran);
 		let cid = CommunityIdentifier::default();
 
 		frame_support::storage::unhashed::put_raw(&global_reputation_count(5), &3u128.encode());
@@ -103,6 +158,113 @@ benchmarks! {
 	verify {
 		assert!(<EnactmentQueue<T>>::iter().next().is_some());
 	}
+
+	cancel_proposal {
+		frame_support::storage::unhashed::put_raw(&current_ceremony_index_key(), &7u32.encode());
+		let zoran = account::<T::AccountId>("zoran", 1, 1);
+		fund_proposer::<T>(&zoran);
+
+		let proposal_action = ProposalAction::SetInactivityTimeout(8);
+		assert_ok!(EncointerDemocracy::<T>::submit_proposal(
+			RawOrigin::Signed(zoran).into(),
+			Box::new(proposal_action.clone())
+		));
+
+		let mut proposal = EncointerDemocracy::<T>::proposals(1).unwrap();
+		proposal.state = ProposalState::Approved;
+		<Proposals<T>>::insert(1, proposal);
+		<EnactmentQueue<T>>::insert(
+			proposal_action.get_identifier(),
+			BoundedVec::try_from(vec![1u128]).unwrap(),
+		);
+		let origin = <T as Config>::CancelOrigin::try_successful_origin()
+			.expect("CancelOrigin has no successful origin");
+	}: _<T::RuntimeOrigin>(origin, 1)
+	verify {
+		assert_eq!(EncointerDemocracy::<T>::proposals(1).unwrap().state, ProposalState::Cancelled);
+		assert!(<EnactmentQueue<T>>::iter().next().is_none());
+	}
+
+	enact_proposal {
+		let zoran = account::<T::AccountId>("zoran", 1, 1);
+		fund_proposer::<T>(&zoran);
+		// worst case is adding a location, as it is validated against all nearby locations
+		let cid = setup_community_with_locations::<T>();
+		let proposal_action = ProposalAction::AddLocation(cid, get_location(num_locations::<T>() - 1));
+		assert_ok!(EncointerDemocracy::<T>::submit_proposal(
+			RawOrigin::Signed(zoran).into(),
+			Box::new(proposal_action.clone())
+		));
+		<EnactmentQueue<T>>::insert(
+			proposal_action.get_identifier(),
+			BoundedVec::try_from(vec![1u128]).unwrap(),
+		);
+	}: {
+		EncointerDemocracy::<T>::enact_queued_proposals(Weight::MAX);
+	}
+	verify {
+		assert_eq!(EncointerDemocracy::<T>::proposals(1).unwrap().state, ProposalState::Enacted);
+		assert!(<EnactmentQueue<T>>::iter().next().is_none());
+		assert_eq!(Communities::<T>::get_locations(&cid).len() as u32, num_locations::<T>());
+	}
+
+	vote_with_conviction {
+		frame_support::storage::unhashed::put_raw(&current_ceremony_index_key(), &7u32.encode());
+
+		let zoran = account::<T::AccountId>("zoran", 1, 1);
+		let cid = CommunityIdentifier::default();
+
+		frame_support::storage::unhashed::put_raw(&participant_reputation((cid, 3), &zoran), &Reputation::VerifiedUnlinked.encode());
+		frame_support::storage::unhashed::put_raw(&participant_reputation((cid, 4), &zoran), &Reputation::VerifiedUnlinked.encode());
+		frame_support::storage::unhashed::put_raw(&participant_reputation((cid, 5), &zoran), &Reputation::VerifiedUnlinked.encode());
+		frame_support::storage::unhashed::put_raw(&global_reputation_count(3), &1u128.encode());
+		frame_support::storage::unhashed::put_raw(&global_reputation_count(4), &1u128.encode());
+		frame_support::storage::unhashed::put_raw(&global_reputation_count(5), &1u128.encode());
+
+		let reputation_vec: ReputationVecOf<T> = BoundedVec::try_from(vec![
+			(cid, 3),
+			(cid, 4),
+			(cid, 5),
+		]).unwrap();
+
+		let amount = <T as Config>::MinVoteLockPerReputation::get().saturating_mul(3u32.into());
+		T::VoteLockCurrency::make_free_balance_be(
+			&zoran,
+			amount.saturating_mul(2u32.into()).saturating_add(<T as Config>::ProposalDeposit::get()),
+		);
+
+		let proposal_action = ProposalAction::SpendNative(Some(cid), zoran.clone(), amount);
+		assert_ok!(EncointerDemocracy::<T>::submit_proposal(
+			RawOrigin::Signed(zoran.clone()).into(),
+			Box::new(proposal_action)
+		));
+	}: _(RawOrigin::Signed(zoran.clone()),
+	1,
+	Vote::Aye,
+	reputation_vec,
+	Conviction::Locked6x,
+	amount)
+	verify {
+		assert_eq!(<Tallies<T>>::get(1).unwrap().ayes, 3);
+		assert_eq!(EncointerDemocracy::<T>::vote_locks(&zoran).len(), 1);
+	}
+
+	unlock {
+		frame_support::storage::unhashed::put_raw(&current_ceremony_index_key(), &7u32.encode());
+		let zoran = account::<T::AccountId>("zoran", 1, 1);
+		let amount = <T as Config>::MinVoteLockPerReputation::get();
+		T::VoteLockCurrency::make_free_balance_be(&zoran, amount.saturating_mul(2u32.into()));
+		let vote_locks = BoundedVec::try_from(vec![VoteLock {
+			proposal_id: 1,
+			amount,
+			unlock_cindex: 7,
+		}])
+		.unwrap();
+		<VoteLocks<T>>::insert(&zoran, vote_locks);
+	}: _(RawOrigin::Signed(zoran.clone()), zoran.clone())
+	verify {
+		assert!(EncointerDemocracy::<T>::vote_locks(&zoran).is_empty());
+	}
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::TestRuntime);
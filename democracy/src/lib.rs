@@ -33,7 +33,7 @@ use frame_support::dispatch::DispatchErrorWithPostInfo;
 
 use frame_support::{
 	sp_runtime::{
-		traits::{CheckedAdd, CheckedDiv, CheckedSub},
+		traits::{CheckedAdd, CheckedDiv, CheckedSub, Saturating, Zero},
 		SaturatedConversion,
 	},
 	traits::Get,
@@ -43,9 +43,11 @@ use pallet_encointer_scheduler::OnCeremonyPhaseChange;
 pub use weights::WeightInfo;
 
 #[cfg(not(feature = "std"))]
-use sp_std::vec::Vec;
+use sp_std::{vec, vec::Vec};
 
-use frame_support::traits::Currency;
+use frame_support::traits::{
+	Currency, LockIdentifier, LockableCurrency, ReservableCurrency, WithdrawReasons,
+};
 // Logger target
 //const LOG: &str = "encointer";
 
@@ -59,6 +61,9 @@ pub type BalanceOf<T> = <<T as pallet_encointer_treasuries::Config>::Currency as
 	<T as frame_system::Config>::AccountId,
 >>::Balance;
 
+/// Identifier of the native token lock held by conviction votes.
+pub const DEMOCRACY_ID: LockIdentifier = *b"encdemoc";
+
 use pallet_encointer_ceremonies::Pallet as CeremoniesPallet;
 use pallet_encointer_communities::Pallet as CommunitiesPallet;
 use pallet_encointer_treasuries::Pallet as TreasuriesPallet;
@@ -68,6 +73,7 @@ use pallet_encointer_treasuries::Pallet as TreasuriesPallet;
 pub mod pallet {
 	use super::*;
 	use encointer_primitives::{
+		ceremonies::CommunityCeremony,
 		communities::CommunityIdentifier,
 		democracy::{Tally, *},
 		reputation_commitments::{DescriptorType, PurposeIdType},
@@ -75,7 +81,7 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -118,6 +124,36 @@ pub mod pallet {
 		/// the `Confirming` state.
 		#[pallet::constant]
 		type MinTurnout: Get<u128>;
+
+		/// Origin that may cancel an approved proposal before it is enacted.
+		type CancelOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of proposals that can be `Ongoing` or `Confirming` at the same time.
+		#[pallet::constant]
+		type MaxOpenProposals: Get<u32>;
+
+		/// Native tokens reserved from the proposer while a proposal is `Ongoing` or
+		/// `Confirming`. This makes it costly to fill up `OpenProposals`.
+		#[pallet::constant]
+		type ProposalDeposit: Get<BalanceOf<Self>>;
+
+		/// If true, proposals spending treasury funds are tallied in hybrid mode: reputation
+		/// votes can be amplified by locking native tokens with a `Conviction`.
+		#[pallet::constant]
+		type HybridTreasuryTally: Get<bool>;
+
+		/// Currency to lock native tokens for conviction votes and to reserve proposal deposits.
+		/// This should be the `Currency` of pallet-encointer-treasuries.
+		type VoteLockCurrency: LockableCurrency<Self::AccountId, Balance = BalanceOf<Self>>
+			+ ReservableCurrency<Self::AccountId>;
+
+		/// Minimum amount of native tokens to lock per reputation vote when voting with conviction.
+		#[pallet::constant]
+		type MinVoteLockPerReputation: Get<BalanceOf<Self>>;
+
+		/// Maximum number of concurrent vote locks per account.
+		#[pallet::constant]
+		type MaxVoteLocks: Get<u32>;
 	}
 
 	#[pallet::event]
@@ -152,6 +188,23 @@ pub mod pallet {
 			cid: Option<CommunityIdentifier>,
 			text: PalletString,
 		},
+		/// approved proposal cancelled and removed from the enactment queue
+		ProposalCancelled {
+			proposal_id: ProposalIdType,
+		},
+		/// native tokens locked to amplify a vote
+		VoteLocked {
+			who: T::AccountId,
+			proposal_id: ProposalIdType,
+			conviction: Conviction,
+			amount: BalanceOf<T>,
+			unlock_cindex: CeremonyIndexType,
+		},
+		/// expired vote locks removed. `locked` is the amount that remains locked
+		VoteUnlocked {
+			who: T::AccountId,
+			locked: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -175,6 +228,20 @@ pub mod pallet {
 		PurposeIdCreationFailed,
 		/// error when doing math operations
 		MathError,
+		/// only approved proposals waiting for enactment can be cancelled
+		ProposalCannotBeCancelled,
+		/// too many proposals are ongoing or confirming
+		TooManyOpenProposals,
+		/// conviction voting is only possible for proposals in hybrid tally mode
+		ConvictionNotSupported,
+		/// the amount to lock is below `MinVoteLockPerReputation` times the number of votes
+		InsufficientVoteLock,
+		/// the free balance is too low for the requested vote lock or the proposal deposit
+		InsufficientBalance,
+		/// too many vote locks for this account
+		TooManyVoteLocks,
+		/// the enactment queue for this proposal action is full
+		EnactmentQueueFull,
 	}
 
 	/// Unique `PurposeIds` of a `Proposal`.
@@ -212,7 +279,7 @@ pub mod pallet {
 	pub(super) type LastApprovedProposalForAction<T: Config> = StorageMap<
 		_,
 		Blake2_128Concat,
-		ProposalActionIdentifier,
+		ProposalActionIdentifier<T::AccountId>,
 		(T::Moment, ProposalIdType),
 		OptionQuery,
 	>;
@@ -225,11 +292,41 @@ pub mod pallet {
 	pub(super) type EnactmentQueue<T: Config> = StorageMap<
 		_,
 		Blake2_128Concat,
-		ProposalActionIdentifier,
+		ProposalActionIdentifier<T::AccountId>,
 		BoundedVec<ProposalIdType, ConstU32<ENACTMENT_QUEUE_MAX_PER_TYPE>>,
 		OptionQuery,
 	>;
 
+	/// Proposals that can still change their state, ordered by the moment at which their state
+	/// needs to be updated next.
+	#[pallet::storage]
+	#[pallet::getter(fn open_proposals)]
+	pub(super) type OpenProposals<T: Config> =
+		StorageValue<_, BoundedVec<(T::Moment, ProposalIdType), T::MaxOpenProposals>, ValueQuery>;
+
+	/// Deposits reserved for open proposals, together with the account they are reserved from.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_deposits)]
+	pub(super) type ProposalDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, ProposalIdType, (T::AccountId, BalanceOf<T>), OptionQuery>;
+
+	/// Conviction-amplified tallies for proposals in hybrid tally mode.
+	#[pallet::storage]
+	#[pallet::getter(fn conviction_tallies)]
+	pub(super) type ConvictionTallies<T: Config> =
+		StorageMap<_, Blake2_128Concat, ProposalIdType, ConvictionTally, OptionQuery>;
+
+	/// Native tokens locked by conviction votes per account.
+	#[pallet::storage]
+	#[pallet::getter(fn vote_locks)]
+	pub(super) type VoteLocks<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<VoteLock<BalanceOf<T>>, T::MaxVoteLocks>,
+		ValueQuery,
+	>;
+
 	#[derive(frame_support::DefaultNoBound)]
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
@@ -245,6 +342,20 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T>
+	where
+		sp_core::H256: From<<T as frame_system::Config>::Hash>,
+		T::AccountId: AsRef<[u8; 32]>,
+	{
+		fn on_idle(_block: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let consumed = Self::update_due_proposals(remaining_weight);
+			consumed.saturating_add(Self::enact_queued_proposals(
+				remaining_weight.saturating_sub(consumed),
+			))
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T>
 	where
@@ -263,7 +374,7 @@ pub mod pallet {
 			if Self::enactment_queue(proposal_action.clone().get_identifier()).is_some() {
 				return Err(Error::<T>::ProposalWaitingForEnactment.into());
 			}
-			let _sender = ensure_signed(origin)?;
+			let sender = ensure_signed(origin)?;
 			let cindex = <pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index();
 			let current_proposal_id = Self::proposal_count();
 			let next_proposal_id = current_proposal_id
@@ -287,10 +398,20 @@ pub mod pallet {
 						.map_err(|_| <Error<T>>::PurposeIdCreationFailed)?,
 				)?;
 
+			let deposit = T::ProposalDeposit::get();
+			if !deposit.is_zero() {
+				T::VoteLockCurrency::reserve(&sender, deposit)
+					.map_err(|_| Error::<T>::InsufficientBalance)?;
+				<ProposalDeposits<T>>::insert(next_proposal_id, (sender, deposit));
+			}
+			Self::index_open_proposal(next_proposal_id, Self::next_update_at(&proposal))?;
 			<Proposals<T>>::insert(next_proposal_id, proposal);
 			<PurposeIds<T>>::insert(next_proposal_id, purpose_id);
 			<ProposalCount<T>>::put(next_proposal_id);
 			<Tallies<T>>::insert(next_proposal_id, Tally { turnout: 0, ayes: 0 });
+			if T::HybridTreasuryTally::get() && proposal_action.spends_treasury_funds() {
+				<ConvictionTallies<T>>::insert(next_proposal_id, ConvictionTally::default());
+			}
 			Self::deposit_event(Event::ProposalSubmitted {
 				proposal_id: next_proposal_id,
 				proposal_action: *proposal_action,
@@ -307,36 +428,7 @@ pub mod pallet {
 			reputations: ReputationVecOf<T>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
-			let tally = <Tallies<T>>::get(proposal_id).ok_or(Error::<T>::InexistentProposal)?;
-
-			// make sure we don't vote on proposal that can't update anymore
-			Self::do_update_proposal_state(proposal_id)?;
-
-			let num_votes =
-				Self::validate_and_commit_reputations(proposal_id, &sender, &reputations)?;
-
-			let ayes = match vote {
-				Vote::Aye => num_votes,
-				Vote::Nay => 0,
-			};
-
-			let new_tally = Tally {
-				turnout: tally
-					.turnout
-					.checked_add(num_votes)
-					.ok_or(Error::<T>::VoteCountOverflow)?,
-				ayes: tally.ayes.checked_add(ayes).ok_or(Error::<T>::VoteCountOverflow)?,
-			};
-
-			<Tallies<T>>::insert(proposal_id, new_tally);
-
-			Self::do_update_proposal_state(proposal_id)?;
-
-			if num_votes > 0 {
-				Self::deposit_event(Event::VotePlaced { proposal_id, vote, num_votes })
-			} else {
-				Self::deposit_event(Event::VoteFailed { proposal_id, vote })
-			}
+			Self::do_vote(&sender, proposal_id, vote, &reputations, Conviction::None, 0u32.into())?;
 			Ok(().into())
 		}
 
@@ -351,6 +443,57 @@ pub mod pallet {
 			Self::do_update_proposal_state(proposal_id)?;
 			Ok(().into())
 		}
+
+		/// Cancel an approved proposal before it is enacted.
+		///
+		/// The proposal is removed from the `EnactmentQueue` and will never be enacted.
+		///
+		/// May only be called from `T::CancelOrigin`.
+		#[pallet::call_index(3)]
+		#[pallet::weight((<T as Config>::WeightInfo::cancel_proposal(), DispatchClass::Normal, Pays::Yes)
+        )]
+		pub fn cancel_proposal(
+			origin: OriginFor<T>,
+			proposal_id: ProposalIdType,
+		) -> DispatchResultWithPostInfo {
+			T::CancelOrigin::ensure_origin(origin)?;
+			Self::do_cancel_proposal(proposal_id)?;
+			Ok(().into())
+		}
+
+		/// Vote on a proposal in hybrid tally mode and amplify the reputation votes by locking
+		/// `amount` native tokens for `conviction.lock_periods()` ceremony cycles.
+		#[pallet::call_index(4)]
+		#[pallet::weight((<T as Config>::WeightInfo::vote_with_conviction(), DispatchClass::Normal, Pays::Yes))]
+		pub fn vote_with_conviction(
+			origin: OriginFor<T>,
+			proposal_id: ProposalIdType,
+			vote: Vote,
+			reputations: ReputationVecOf<T>,
+			conviction: Conviction,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				<ConvictionTallies<T>>::contains_key(proposal_id),
+				Error::<T>::ConvictionNotSupported
+			);
+			Self::do_vote(&sender, proposal_id, vote, &reputations, conviction, amount)?;
+			Ok(().into())
+		}
+
+		/// Remove expired vote locks of `target` and reduce its native token lock accordingly.
+		#[pallet::call_index(5)]
+		#[pallet::weight((<T as Config>::WeightInfo::unlock(), DispatchClass::Normal, Pays::Yes))]
+		pub fn unlock(origin: OriginFor<T>, target: T::AccountId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let cindex = <pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index();
+			let mut vote_locks = Self::vote_locks(&target);
+			vote_locks.retain(|l| l.unlock_cindex > cindex);
+			let locked = Self::update_vote_lock(&target, vote_locks);
+			Self::deposit_event(Event::VoteUnlocked { who: target, locked });
+			Ok(().into())
+		}
 	}
 
 	impl<T: Config> Pallet<T>
@@ -416,8 +559,10 @@ pub mod pallet {
 			let purpose_id =
 				Self::purpose_ids(proposal_id).ok_or(Error::<T>::InexistentProposal)?;
 
+			let voting_cindexes = Self::voting_cindexes(proposal.start_cindex)?;
+
 			for community_ceremony in reputations {
-				if !Self::voting_cindexes(proposal.start_cindex)?.contains(&community_ceremony.1) {
+				if !voting_cindexes.contains(&community_ceremony.1) {
 					continue;
 				}
 
@@ -444,10 +589,181 @@ pub mod pallet {
 			Ok(eligible_reputation_count)
 		}
 
+		/// Counts the valid reputations of `sender` as votes and adds them to the tallies.
+		///
+		/// For proposals in hybrid tally mode, the votes are amplified by `conviction` in the
+		/// `ConvictionTally` and `amount` native tokens are locked accordingly.
+		fn do_vote(
+			sender: &T::AccountId,
+			proposal_id: ProposalIdType,
+			vote: Vote,
+			reputations: &ReputationVecOf<T>,
+			conviction: Conviction,
+			amount: BalanceOf<T>,
+		) -> Result<(), DispatchError> {
+			let tally = <Tallies<T>>::get(proposal_id).ok_or(Error::<T>::InexistentProposal)?;
+
+			// make sure we don't vote on proposal that can't update anymore
+			Self::do_update_proposal_state(proposal_id)?;
+
+			let num_votes =
+				Self::validate_and_commit_reputations(proposal_id, sender, reputations)?;
+
+			let ayes = match vote {
+				Vote::Aye => num_votes,
+				Vote::Nay => 0,
+			};
+
+			let new_tally = Tally {
+				turnout: tally
+					.turnout
+					.checked_add(num_votes)
+					.ok_or(Error::<T>::VoteCountOverflow)?,
+				ayes: tally.ayes.checked_add(ayes).ok_or(Error::<T>::VoteCountOverflow)?,
+			};
+
+			<Tallies<T>>::insert(proposal_id, new_tally);
+
+			if let Some(conviction_tally) = Self::conviction_tallies(proposal_id) {
+				let conviction = if num_votes > 0 {
+					Self::lock_for_vote(sender, proposal_id, num_votes, conviction, amount)?;
+					conviction
+				} else {
+					Conviction::None
+				};
+				let amplified_votes = conviction.votes(num_votes);
+				let amplified_ayes = match vote {
+					Vote::Aye => amplified_votes,
+					Vote::Nay => 0,
+				};
+				<ConvictionTallies<T>>::insert(
+					proposal_id,
+					ConvictionTally {
+						turnout: conviction_tally
+							.turnout
+							.checked_add(amplified_votes)
+							.ok_or(Error::<T>::VoteCountOverflow)?,
+						ayes: conviction_tally
+							.ayes
+							.checked_add(amplified_ayes)
+							.ok_or(Error::<T>::VoteCountOverflow)?,
+					},
+				);
+			}
+
+			Self::do_update_proposal_state(proposal_id)?;
+
+			if num_votes > 0 {
+				Self::deposit_event(Event::VotePlaced { proposal_id, vote, num_votes })
+			} else {
+				Self::deposit_event(Event::VoteFailed { proposal_id, vote })
+			}
+			Ok(())
+		}
+
+		/// Locks `amount` native tokens of `who` for `conviction.lock_periods()` ceremony cycles.
+		fn lock_for_vote(
+			who: &T::AccountId,
+			proposal_id: ProposalIdType,
+			num_votes: VoteCountType,
+			conviction: Conviction,
+			amount: BalanceOf<T>,
+		) -> Result<(), Error<T>> {
+			if conviction == Conviction::None {
+				return Ok(());
+			}
+			let min_amount = T::MinVoteLockPerReputation::get()
+				.saturating_mul(num_votes.saturated_into::<u64>().saturated_into());
+			ensure!(amount >= min_amount, Error::<T>::InsufficientVoteLock);
+			ensure!(
+				T::VoteLockCurrency::free_balance(who) >= amount,
+				Error::<T>::InsufficientBalance
+			);
+
+			let unlock_cindex = <pallet_encointer_scheduler::Pallet<T>>::current_ceremony_index()
+				.saturating_add(conviction.lock_periods());
+			let mut vote_locks = Self::vote_locks(who);
+			vote_locks
+				.try_push(VoteLock { proposal_id, amount, unlock_cindex })
+				.map_err(|_| Error::<T>::TooManyVoteLocks)?;
+			Self::update_vote_lock(who, vote_locks);
+
+			Self::deposit_event(Event::VoteLocked {
+				who: who.clone(),
+				proposal_id,
+				conviction,
+				amount,
+				unlock_cindex,
+			});
+			Ok(())
+		}
+
+		/// Stores `vote_locks` and sets the native token lock to the largest of them.
+		///
+		/// Returns the locked amount.
+		fn update_vote_lock(
+			who: &T::AccountId,
+			vote_locks: BoundedVec<VoteLock<BalanceOf<T>>, T::MaxVoteLocks>,
+		) -> BalanceOf<T> {
+			let locked = vote_locks.iter().map(|l| l.amount).max().unwrap_or_default();
+			if vote_locks.is_empty() {
+				T::VoteLockCurrency::remove_lock(DEMOCRACY_ID, who);
+				<VoteLocks<T>>::remove(who);
+			} else {
+				T::VoteLockCurrency::set_lock(DEMOCRACY_ID, who, locked, WithdrawReasons::all());
+				<VoteLocks<T>>::insert(who, vote_locks);
+			}
+			locked
+		}
+
+		/// Returns the reputations of `account_id` that `validate_and_commit_reputations` would
+		/// accept for `proposal_id` at this point in time.
+		pub fn get_eligible_reputations(
+			proposal_id: ProposalIdType,
+			account_id: &T::AccountId,
+		) -> Result<Vec<CommunityCeremony>, Error<T>> {
+			let proposal = Self::proposals(proposal_id).ok_or(Error::<T>::InexistentProposal)?;
+			let purpose_id =
+				Self::purpose_ids(proposal_id).ok_or(Error::<T>::InexistentProposal)?;
+			let cids = match proposal.action.get_access_policy() {
+				ProposalAccessPolicy::Community(cid) => vec![cid],
+				ProposalAccessPolicy::Global =>
+					CommunitiesPallet::<T>::community_identifiers().into_inner(),
+			};
+
+			let mut eligible_reputations = Vec::new();
+			for cindex in Self::voting_cindexes(proposal.start_cindex)? {
+				for cid in cids.iter() {
+					if <pallet_encointer_reputation_commitments::Pallet<T>>::can_commit_reputation(
+						account_id, *cid, cindex, purpose_id,
+					)
+					.is_ok()
+					{
+						eligible_reputations.push((*cid, cindex));
+					}
+				}
+			}
+			Ok(eligible_reputations)
+		}
+
+		/// Returns all proposals whose state matches `filter`, ordered by proposal id.
+		pub fn get_proposals(
+			filter: ProposalFilter,
+		) -> Vec<(ProposalIdType, Proposal<T::Moment, T::AccountId, BalanceOf<T>, AssetKindOf<T>>)>
+		{
+			let mut proposals: Vec<_> =
+				<Proposals<T>>::iter().filter(|(_, p)| filter.matches(p.state)).collect();
+			proposals.sort_by_key(|(id, _)| *id);
+			proposals
+		}
+
 		/// Updates the proposal state.
 		///
 		/// If the state is changed to Approved, the proposal will be enacted.
 		/// In case of enactment, the function returns true.
+		///
+		/// Fails with `EnactmentQueueFull` and leaves the proposal `Confirming` if it cannot be
+		/// enqueued for enactment.
 		pub fn do_update_proposal_state(proposal_id: ProposalIdType) -> Result<bool, Error<T>> {
 			let mut proposal =
 				Self::proposals(proposal_id).ok_or(Error::<T>::InexistentProposal)?;
@@ -457,7 +773,7 @@ pub mod pallet {
 			let now = <pallet_timestamp::Pallet<T>>::get();
 			let proposal_action_identifier = proposal.action.clone().get_identifier();
 			let last_approved_proposal_for_action =
-				Self::last_approved_proposal_for_action(proposal_action_identifier);
+				Self::last_approved_proposal_for_action(&proposal_action_identifier);
 			let proposal_cancelled_by_other = proposal.action.supersedes_same_action() &&
 				last_approved_proposal_for_action.is_some() &&
 				proposal.start < last_approved_proposal_for_action.unwrap().0;
@@ -474,22 +790,19 @@ pub mod pallet {
 						if now.checked_sub(&since).unwrap_or_default() >
 							T::ConfirmationPeriod::get()
 						{
-							proposal.state = ProposalState::Approved;
+							// the proposal stays confirming until there is room to enqueue it
 							<EnactmentQueue<T>>::try_mutate(
-								proposal_action_identifier,
-								|maybe_queue| -> Result<(), ()> {
+								&proposal_action_identifier,
+								|maybe_queue| -> Result<(), Error<T>> {
 									let queue = maybe_queue.get_or_insert_with(BoundedVec::default);
-									queue.try_push(proposal_id).map_err(|_| ())
+									queue
+										.try_push(proposal_id)
+										.map_err(|_| Error::<T>::EnactmentQueueFull)
 								},
-							)
-							.unwrap_or_else(|_| {
-								log::error!(
-									target: "encointer",
-									"EnactmentQueue overflow for proposal {proposal_id}"
-								);
-							});
+							)?;
+							proposal.state = ProposalState::Approved;
 							<LastApprovedProposalForAction<T>>::insert(
-								proposal_action_identifier,
+								&proposal_action_identifier,
 								(now, proposal_id),
 							);
 							approved = true;
@@ -510,6 +823,11 @@ pub mod pallet {
 			}
 			<Proposals<T>>::insert(proposal_id, &proposal);
 			if old_proposal_state != proposal.state {
+				let maybe_update_at = Self::next_update_at(&proposal);
+				if maybe_update_at.is_none() {
+					Self::release_proposal_deposit(proposal_id);
+				}
+				Self::index_open_proposal(proposal_id, maybe_update_at)?;
 				Self::deposit_event(Event::ProposalStateUpdated {
 					proposal_id,
 					proposal_state: proposal.state,
@@ -518,6 +836,42 @@ pub mod pallet {
 			Ok(approved)
 		}
 
+		/// Cancels an approved proposal and removes it from the enactment queue.
+		///
+		/// If the proposal was the last approved one for its action, it is forgotten, so that it
+		/// does not supersede other proposals.
+		pub fn do_cancel_proposal(proposal_id: ProposalIdType) -> Result<(), Error<T>> {
+			let mut proposal =
+				Self::proposals(proposal_id).ok_or(Error::<T>::InexistentProposal)?;
+			ensure!(
+				proposal.state == ProposalState::Approved,
+				Error::<T>::ProposalCannotBeCancelled
+			);
+			let proposal_action_identifier = proposal.action.get_identifier();
+
+			<EnactmentQueue<T>>::mutate_exists(&proposal_action_identifier, |maybe_queue| {
+				if let Some(queue) = maybe_queue {
+					queue.retain(|id| *id != proposal_id);
+					if queue.is_empty() {
+						*maybe_queue = None;
+					}
+				}
+			});
+
+			if let Some((_, last_approved_id)) =
+				Self::last_approved_proposal_for_action(&proposal_action_identifier)
+			{
+				if last_approved_id == proposal_id {
+					<LastApprovedProposalForAction<T>>::remove(&proposal_action_identifier);
+				}
+			}
+
+			proposal.state = ProposalState::Cancelled;
+			<Proposals<T>>::insert(proposal_id, proposal);
+			Self::deposit_event(Event::ProposalCancelled { proposal_id });
+			Ok(())
+		}
+
 		pub fn get_electorate(
 			start_cindex: CeremonyIndexType,
 			proposal_action: ProposalAction<T::AccountId, BalanceOf<T>, T::Moment, AssetKindOf<T>>,
@@ -582,9 +936,156 @@ pub mod pallet {
 				return Ok(false);
 			}
 
-			Self::positive_turnout_bias(electorate, tally.turnout, tally.ayes)
-				.ok_or(Error::<T>::AQBError)
+			// In hybrid tally mode, the turnout is still measured in reputation, but the share of
+			// ayes is the one of the conviction-amplified votes.
+			let ayes = match Self::conviction_tallies(proposal_id) {
+				Some(conviction_tally) if conviction_tally.turnout > 0 => tally
+					.turnout
+					.checked_mul(conviction_tally.ayes)
+					.ok_or(Error::<T>::MathError)?
+					.checked_div(conviction_tally.turnout)
+					.ok_or(Error::<T>::MathError)?,
+				_ => tally.ayes,
+			};
+
+			Self::positive_turnout_bias(electorate, tally.turnout, ayes).ok_or(Error::<T>::AQBError)
+		}
+		/// Returns the moment from which on the state of `proposal` can change without any votes,
+		/// or `None` if the state is final.
+		pub(crate) fn next_update_at(
+			proposal: &Proposal<T::Moment, T::AccountId, BalanceOf<T>, AssetKindOf<T>>,
+		) -> Option<T::Moment> {
+			let one = T::Moment::saturated_from(1u64);
+			match proposal.state {
+				ProposalState::Ongoing => Some(
+					proposal.start.saturating_add(T::ProposalLifetime::get()).saturating_add(one),
+				),
+				ProposalState::Confirming { since } =>
+					Some(since.saturating_add(T::ConfirmationPeriod::get()).saturating_add(one)),
+				_ => None,
+			}
+		}
+
+		/// (Re-)inserts `proposal_id` into `OpenProposals` at the position of `maybe_update_at`, or
+		/// removes it if `None`.
+		fn index_open_proposal(
+			proposal_id: ProposalIdType,
+			maybe_update_at: Option<T::Moment>,
+		) -> Result<(), Error<T>> {
+			<OpenProposals<T>>::try_mutate(|open_proposals| {
+				open_proposals.retain(|(_, id)| *id != proposal_id);
+				if let Some(update_at) = maybe_update_at {
+					let index = open_proposals.partition_point(|(at, _)| *at <= update_at);
+					open_proposals
+						.try_insert(index, (update_at, proposal_id))
+						.map_err(|_| Error::<T>::TooManyOpenProposals)?;
+				}
+				Ok(())
+			})
+		}
+
+		/// Returns the deposit of `proposal_id` to its proposer, if there is one.
+		fn release_proposal_deposit(proposal_id: ProposalIdType) {
+			if let Some((who, deposit)) = <ProposalDeposits<T>>::take(proposal_id) {
+				T::VoteLockCurrency::unreserve(&who, deposit);
+			}
+		}
+
+		/// Updates the state of open proposals whose update moment has passed, as long as
+		/// `remaining_weight` allows.
+		///
+		/// This rejects stale proposals and approves confirmed ones without anyone having to call
+		/// `update_proposal_state`.
+		///
+		/// Returns the consumed weight.
+		pub fn update_due_proposals(mut remaining_weight: Weight) -> Weight {
+			let mut consumed = T::DbWeight::get().reads(2);
+			remaining_weight = remaining_weight.saturating_sub(consumed);
+			let now = <pallet_timestamp::Pallet<T>>::get();
+
+			let step_weight = <T as Config>::WeightInfo::update_proposal_state()
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+			while !remaining_weight.any_lt(step_weight) {
+				let Some(&(update_at, proposal_id)) = Self::open_proposals().first() else {
+					break;
+				};
+				if update_at > now {
+					break;
+				}
+				match Self::do_update_proposal_state(proposal_id) {
+					Ok(_) => {},
+					// retry once the enactment queue had a chance to drain
+					Err(Error::<T>::EnactmentQueueFull) => {
+						let _ = Self::index_open_proposal(
+							proposal_id,
+							Some(now.saturating_add(T::ConfirmationPeriod::get())),
+						);
+					},
+					Err(e) => {
+						log::error!(
+							target: "encointer",
+							"failed to update state of open proposal {proposal_id}: {e:?}"
+						);
+						let _ = Self::index_open_proposal(proposal_id, None);
+						Self::release_proposal_deposit(proposal_id);
+					},
+				}
+
+				consumed.saturating_accrue(step_weight);
+				remaining_weight.saturating_reduce(step_weight);
+			}
+			consumed
+		}
+
+		/// Enacts proposals from the `EnactmentQueue` as long as `remaining_weight` allows.
+		///
+		/// Proposals are only enacted during the `Registering` phase, so that they never
+		/// interfere with an ongoing ceremony (e.g. by changing its locations). Whatever does not
+		/// fit into the weight of this block is enacted in later blocks.
+		///
+		/// Returns the consumed weight.
+		pub fn enact_queued_proposals(mut remaining_weight: Weight) -> Weight {
+			let mut consumed = T::DbWeight::get().reads(1);
+			if <pallet_encointer_scheduler::Pallet<T>>::current_phase() !=
+				CeremonyPhaseType::Registering
+			{
+				return consumed;
+			}
+			remaining_weight = remaining_weight.saturating_sub(consumed);
+
+			// `enact_proposal` is benchmarked with the most expensive action, adding a location
+			let step_weight = <T as Config>::WeightInfo::enact_proposal()
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+			while !remaining_weight.any_lt(step_weight) {
+				// processed entries are removed, so the head of the queue is the cursor.
+				let Some((proposal_action_identifier, mut queue)) =
+					<EnactmentQueue<T>>::iter().next()
+				else {
+					consumed.saturating_accrue(T::DbWeight::get().reads(1));
+					break;
+				};
+
+				let maybe_proposal_id = (!queue.is_empty()).then(|| queue.remove(0));
+				if queue.is_empty() {
+					<EnactmentQueue<T>>::remove(proposal_action_identifier);
+				} else {
+					<EnactmentQueue<T>>::insert(proposal_action_identifier, queue);
+				}
+
+				if let Some(proposal_id) = maybe_proposal_id {
+					if let Err(e) = Self::enact_proposal(proposal_id) {
+						Self::deposit_event(Event::EnactmentFailed { proposal_id, reason: e })
+					}
+				}
+
+				consumed.saturating_accrue(step_weight);
+				remaining_weight.saturating_reduce(step_weight);
+			}
+			consumed
 		}
+
 		pub fn enact_proposal(proposal_id: ProposalIdType) -> DispatchResultWithPostInfo {
 			let mut proposal =
 				Self::proposals(proposal_id).ok_or(Error::<T>::InexistentProposal)?;
@@ -638,6 +1139,35 @@ pub mod pallet {
 						swap_option.clone(),
 					)?;
 				},
+				ProposalAction::SetLocationMeetupTimeOffset(cid, location, meetup_time_offset) => {
+					CommunitiesPallet::<T>::do_set_location_meetup_time_offset(
+						cid,
+						location,
+						meetup_time_offset,
+					)?;
+				},
+				ProposalAction::AddBootstrapper(cid, ref bootstrapper) => {
+					CommunitiesPallet::<T>::do_add_bootstrapper(cid, bootstrapper.clone())?;
+				},
+				ProposalAction::RemoveBootstrapper(cid, ref bootstrapper) => {
+					CommunitiesPallet::<T>::do_remove_bootstrapper(cid, bootstrapper.clone())?;
+				},
+				ProposalAction::ReplaceBootstrapper(cid, ref old, ref new) => {
+					CommunitiesPallet::<T>::do_replace_bootstrapper(cid, old.clone(), new.clone())?;
+				},
+				ProposalAction::SetDemurrageExemption(cid, ref account, exempt) => {
+					CommunitiesPallet::<T>::do_set_demurrage_exemption(
+						cid,
+						account.clone(),
+						exempt,
+					)?;
+				},
+				ProposalAction::UpdateExistentialDeposit(cid, existential_deposit) => {
+					CommunitiesPallet::<T>::do_update_existential_deposit(
+						cid,
+						existential_deposit,
+					)?;
+				},
 			};
 
 			proposal.state = ProposalState::Enacted;
@@ -653,21 +1183,8 @@ where
 	sp_core::H256: From<<T as frame_system::Config>::Hash>,
 	T::AccountId: AsRef<[u8; 32]>,
 {
-	fn on_ceremony_phase_change(new_phase: CeremonyPhaseType) {
-		match new_phase {
-			CeremonyPhaseType::Assigning => {
-				<EnactmentQueue<T>>::iter().for_each(|(_, proposal_ids)| {
-					for proposal_id in proposal_ids {
-						if let Err(e) = Self::enact_proposal(proposal_id) {
-							Self::deposit_event(Event::EnactmentFailed { proposal_id, reason: e })
-						}
-					}
-				});
-				let _ = <EnactmentQueue<T>>::clear(u32::MAX, None);
-			},
-			CeremonyPhaseType::Attesting => {},
-			CeremonyPhaseType::Registering => {},
-		}
+	fn on_ceremony_phase_change(_new_phase: CeremonyPhaseType) {
+		// enactment is driven by `on_idle` during the registering phase
 	}
 }
 
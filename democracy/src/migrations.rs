@@ -10,8 +10,13 @@ mod v0 {
 	use encointer_primitives::democracy::ProposalActionIdentifier;
 
 	#[storage_alias]
-	pub(super) type CancelledAt<T: Config> =
-		StorageMap<Pallet<T>, Blake2_128Concat, ProposalActionIdentifier, u64, OptionQuery>;
+	pub(super) type CancelledAt<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		ProposalActionIdentifier<<T as frame_system::Config>::AccountId>,
+		u64,
+		OptionQuery,
+	>;
 }
 
 pub mod v1 {
@@ -73,7 +78,7 @@ pub mod v1_storage {
 	pub type EnactmentQueue<T: Config> = StorageMap<
 		Pallet<T>,
 		Blake2_128Concat,
-		ProposalActionIdentifier,
+		ProposalActionIdentifier<<T as frame_system::Config>::AccountId>,
 		ProposalIdType,
 		OptionQuery,
 	>;
@@ -119,8 +124,10 @@ pub mod v2 {
 
 			// Step 1: Migrate existing v1 EnactmentQueue entries (single ProposalIdType)
 			// to v2 format (BoundedVec<ProposalIdType>).
-			let old_entries: Vec<(ProposalActionIdentifier, ProposalIdType)> =
-				v1_storage::EnactmentQueue::<T>::iter().collect();
+			let old_entries: Vec<(
+				ProposalActionIdentifier<<T as frame_system::Config>::AccountId>,
+				ProposalIdType,
+			)> = v1_storage::EnactmentQueue::<T>::iter().collect();
 			reads += old_entries.len() as u64;
 
 			// Clear the old storage completely
@@ -193,6 +200,76 @@ pub mod v2 {
 	}
 }
 
+pub mod v3 {
+	use super::*;
+	use encointer_primitives::democracy::ProposalIdType;
+
+	/// Populates the new `OpenProposals` index with all proposals that can still change state.
+	pub struct MigrateV2toV3<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config + frame_system::Config> OnRuntimeUpgrade for MigrateV2toV3<T>
+	where
+		sp_core::H256: From<<T as frame_system::Config>::Hash>,
+		T::AccountId: AsRef<[u8; 32]>,
+	{
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+			let count = Proposals::<T>::iter().filter(|(_, p)| p.state.can_update()).count() as u32;
+			log::info!(target: "democracy::migration::v3", "pre_upgrade: {count} open proposals");
+			Ok(count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version != 2 {
+				log::warn!(
+					target: "democracy::migration::v3",
+					"skipping migration: expected onchain version 2, got {onchain_version:?}"
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut reads = 1u64; // version read
+			let mut open_proposals: Vec<(T::Moment, ProposalIdType)> = Vec::new();
+			for (proposal_id, proposal) in Proposals::<T>::iter() {
+				reads += 1;
+				if let Some(update_at) = Pallet::<T>::next_update_at(&proposal) {
+					open_proposals.push((update_at, proposal_id));
+				}
+			}
+			open_proposals.sort();
+
+			let max = T::MaxOpenProposals::get() as usize;
+			if open_proposals.len() > max {
+				log::error!(
+					target: "democracy::migration::v3",
+					"{} open proposals exceed MaxOpenProposals, only indexing the first {max}",
+					open_proposals.len()
+				);
+				open_proposals.truncate(max);
+			}
+			OpenProposals::<T>::put(BoundedVec::truncate_from(open_proposals));
+
+			StorageVersion::new(3).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(reads, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+			assert_eq!(Pallet::<T>::on_chain_storage_version(), 3, "must upgrade to v3");
+			let count: u32 = Decode::decode(&mut state.as_slice())
+				.map_err(|_| sp_runtime::DispatchError::Other("failed to decode state"))?;
+			assert_eq!(
+				OpenProposals::<T>::get().len() as u32,
+				count.min(T::MaxOpenProposals::get()),
+				"open proposals must be indexed"
+			);
+			Ok(())
+		}
+	}
+}
+
 #[cfg(test)]
 #[cfg(feature = "try-runtime")]
 mod test {
@@ -326,6 +403,39 @@ mod test {
 		});
 	}
 
+	#[allow(deprecated)]
+	#[test]
+	fn migration_v2_to_v3_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(2).put::<Pallet<TestRuntime>>();
+
+			let proposal = |start, state| Proposal {
+				start,
+				start_cindex: 0,
+				action: ProposalAction::SetInactivityTimeout(42),
+				state,
+				electorate_size: 0,
+			};
+			Proposals::<TestRuntime>::insert(1, proposal(20, ProposalState::Ongoing));
+			Proposals::<TestRuntime>::insert(2, proposal(10, ProposalState::Ongoing));
+			Proposals::<TestRuntime>::insert(3, proposal(0, ProposalState::Approved));
+			Proposals::<TestRuntime>::insert(
+				4,
+				proposal(0, ProposalState::Confirming { since: 30 }),
+			);
+
+			let state = v3::MigrateV2toV3::<TestRuntime>::pre_upgrade().unwrap();
+			let _weight = v3::MigrateV2toV3::<TestRuntime>::on_runtime_upgrade();
+			v3::MigrateV2toV3::<TestRuntime>::post_upgrade(state).unwrap();
+
+			assert_eq!(Pallet::<TestRuntime>::on_chain_storage_version(), 3);
+			let ids: Vec<_> =
+				OpenProposals::<TestRuntime>::get().into_iter().map(|(_, id)| id).collect();
+			// confirmation period is shorter than the proposal lifetime
+			assert_eq!(ids, vec![4, 2, 1]);
+		});
+	}
+
 	#[allow(deprecated)]
 	#[test]
 	fn migration_v2_to_v2_is_noop() {
@@ -17,7 +17,7 @@
 use crate as dut;
 use encointer_primitives::{balances::BalanceType, scheduler::CeremonyPhaseType};
 use sp_runtime::{
-	traits::{ConstU128, ConstU64},
+	traits::{ConstBool, ConstU128, ConstU64},
 	BuildStorage,
 };
 pub use test_utils::AssetId;
@@ -53,6 +53,13 @@ impl dut::Config for TestRuntime {
 	// 40 6s blocks
 	type ProposalLifetime = ConstU64<240000>;
 	type MinTurnout = ConstU128<20>; // 2%
+	type CancelOrigin = EnsureAlice;
+	type MaxOpenProposals = ConstU32<100>;
+	type ProposalDeposit = ConstU128<100>;
+	type HybridTreasuryTally = ConstBool<true>;
+	type VoteLockCurrency = Balances;
+	type MinVoteLockPerReputation = ConstU128<1_000>;
+	type MaxVoteLocks = ConstU32<10>;
 	type WeightInfo = (); // 2%
 }
 
@@ -61,16 +68,26 @@ impl_frame_system!(TestRuntime);
 impl_timestamp!(TestRuntime, EncointerScheduler);
 impl_balances!(TestRuntime, System);
 impl_encointer_balances!(TestRuntime);
-impl_encointer_communities!(TestRuntime);
+impl_encointer_communities!(TestRuntime, EncointerCeremonies);
 impl_encointer_scheduler!(TestRuntime, EncointerDemocracy);
 impl_encointer_ceremonies!(TestRuntime);
 impl_encointer_reputation_commitments!(TestRuntime);
 impl_encointer_treasuries!(TestRuntime);
 
+/// Native tokens of Alice at genesis, enough to pay the deposits of her proposals.
+pub const ALICE_FUNDS: u128 = 1_000_000;
+
 // genesis values
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::<TestRuntime>::default().build_storage().unwrap();
 
+	pallet_balances::GenesisConfig::<TestRuntime> {
+		balances: vec![(AccountKeyring::Alice.into(), ALICE_FUNDS)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
 	dut::GenesisConfig::<TestRuntime> { proposal_count: 0, ..Default::default() }
 		.assimilate_storage(&mut t)
 		.unwrap();
@@ -29,15 +29,19 @@ use encointer_primitives::{
 		CommunityIdentifier, CommunityMetadata as CommunityMetadataType, Degree, GeoHash, Location,
 		NominalIncome as NominalIncomeType,
 	},
-	democracy::{ProposalAction, ProposalActionIdentifier, ProposalState, Tally, Vote},
+	democracy::{
+		Conviction, ConvictionTally, ProposalAction, ProposalActionIdentifier, ProposalFilter,
+		ProposalState, Tally, Vote, VoteLock,
+	},
 	treasuries::{SwapAssetOption, SwapNativeOption},
 };
 use frame_support::{
 	__private::bounded_vec,
 	assert_err, assert_ok,
-	traits::{OnFinalize, OnInitialize},
+	traits::{OnFinalize, OnIdle, OnInitialize},
+	weights::Weight,
 };
-use mock::{new_test_ext, EncointerDemocracy, RuntimeOrigin, System, TestRuntime};
+use mock::{new_test_ext, EncointerDemocracy, RuntimeOrigin, System, TestRuntime, ALICE_FUNDS};
 use sp_runtime::BoundedVec;
 use std::str::FromStr as StdFromStr;
 use test_utils::{
@@ -60,6 +64,10 @@ fn bob() -> AccountId {
 	AccountKeyring::Bob.into()
 }
 
+fn proposal_deposit() -> BalanceOf<TestRuntime> {
+	<TestRuntime as Config>::ProposalDeposit::get()
+}
+
 fn advance_n_blocks(n: u64) {
 	let mut blocknr = System::block_number();
 	for _ in 0..n {
@@ -85,6 +93,11 @@ fn run_to_block(n: u64) {
 	}
 }
 
+/// Run the idle hook with unlimited weight
+fn enact_queued_proposals() {
+	EncointerDemocracy::on_idle(System::block_number(), Weight::MAX);
+}
+
 /// Progress blocks until the phase changes
 fn run_to_next_phase() {
 	let phase = EncointerScheduler::current_phase();
@@ -412,6 +425,123 @@ fn eligible_reputations_fails_with_invalid_cindex() {
 	});
 }
 
+#[test]
+fn get_eligible_reputations_matches_validate_and_commit_reputations() {
+	new_test_ext().execute_with(|| {
+		let cid = create_cid();
+		let cid2 = register_test_community::<TestRuntime>(None, 10.0, 10.0);
+		let alice = alice();
+
+		let proposal_action = ProposalAction::SetInactivityTimeout(8);
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action)
+		));
+
+		// outside voting cindexes
+		EncointerCeremonies::fake_reputation((cid, 2), &alice, Reputation::VerifiedLinked(0));
+		EncointerCeremonies::fake_reputation((cid, 6), &alice, Reputation::VerifiedLinked(0));
+		// not verified
+		EncointerCeremonies::fake_reputation((cid, 3), &alice, Reputation::Unverified);
+		// eligible
+		EncointerCeremonies::fake_reputation((cid, 4), &alice, Reputation::VerifiedLinked(0));
+		EncointerCeremonies::fake_reputation((cid2, 4), &alice, Reputation::VerifiedUnlinked);
+		EncointerCeremonies::fake_reputation((cid2, 5), &alice, Reputation::VerifiedLinked(0));
+
+		let eligible = EncointerDemocracy::get_eligible_reputations(1, &alice).unwrap();
+		assert_eq!(eligible.len(), 3);
+		assert!(eligible.contains(&(cid, 4)));
+		assert!(eligible.contains(&(cid2, 4)));
+		assert!(eligible.contains(&(cid2, 5)));
+
+		// commit one of them
+		assert_eq!(
+			EncointerDemocracy::validate_and_commit_reputations(
+				1,
+				&alice,
+				&BoundedVec::try_from(vec![(cid2, 5)]).unwrap(),
+			),
+			Ok(1)
+		);
+		let eligible = EncointerDemocracy::get_eligible_reputations(1, &alice).unwrap();
+		assert_eq!(eligible.len(), 2);
+		assert!(!eligible.contains(&(cid2, 5)));
+
+		// whatever is returned is accepted
+		assert_eq!(
+			EncointerDemocracy::validate_and_commit_reputations(
+				1,
+				&alice,
+				&BoundedVec::try_from(eligible).unwrap(),
+			),
+			Ok(2)
+		);
+		assert_eq!(EncointerDemocracy::get_eligible_reputations(1, &alice).unwrap(), vec![]);
+	});
+}
+
+#[test]
+fn get_eligible_reputations_respects_community_access_policy() {
+	new_test_ext().execute_with(|| {
+		let cid = create_cid();
+		let cid2 = register_test_community::<TestRuntime>(None, 10.0, 10.0);
+		let alice = alice();
+
+		let proposal_action =
+			ProposalAction::UpdateNominalIncome(cid, NominalIncomeType::from(100u32));
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action)
+		));
+
+		EncointerCeremonies::fake_reputation((cid, 5), &alice, Reputation::VerifiedLinked(0));
+		EncointerCeremonies::fake_reputation((cid2, 5), &alice, Reputation::VerifiedLinked(0));
+
+		assert_eq!(
+			EncointerDemocracy::get_eligible_reputations(1, &alice).unwrap(),
+			vec![(cid, 5)]
+		);
+		assert_eq!(
+			EncointerDemocracy::get_eligible_reputations(2, &alice),
+			Err(Error::<TestRuntime>::InexistentProposal)
+		);
+	});
+}
+
+#[test]
+fn get_proposals_filters_by_state() {
+	new_test_ext().execute_with(|| {
+		let alice = alice();
+		for i in 0..4 {
+			assert_ok!(EncointerDemocracy::submit_proposal(
+				RuntimeOrigin::signed(alice.clone()),
+				Box::new(ProposalAction::SetInactivityTimeout(i))
+			));
+		}
+		for (proposal_id, state) in [
+			(2u128, ProposalState::Approved),
+			(3u128, ProposalState::Enacted),
+			(4u128, ProposalState::Cancelled),
+		] {
+			let mut proposal = EncointerDemocracy::proposals(proposal_id).unwrap();
+			proposal.state = state;
+			Proposals::<TestRuntime>::insert(proposal_id, proposal);
+		}
+
+		let ids = |filter| {
+			EncointerDemocracy::get_proposals(filter)
+				.into_iter()
+				.map(|(id, _)| id)
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(ids(ProposalFilter::All), vec![1, 2, 3, 4]);
+		assert_eq!(ids(ProposalFilter::Active), vec![1]);
+		assert_eq!(ids(ProposalFilter::Approved), vec![2]);
+		assert_eq!(ids(ProposalFilter::Enacted), vec![3]);
+		assert_eq!(ids(ProposalFilter::Failed), vec![4]);
+	});
+}
+
 #[test]
 fn voting_works() {
 	new_test_ext().execute_with(|| {
@@ -821,8 +951,7 @@ fn enactment_updates_proposal_metadata_and_enactment_queue() {
 			BoundedVec::try_from(vec![2u128]).unwrap(),
 		);
 
-		run_to_next_phase();
-		// first assigning phase after proposal lifetime ended
+		enact_queued_proposals();
 
 		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
 
@@ -895,11 +1024,10 @@ fn proposal_happy_flow() {
 			)
 		);
 
-		run_to_next_phase();
-		// first assigning phase after proposal lifetime ended
+		enact_queued_proposals();
 
 		assert_eq!(
-			event_at_index::<TestRuntime>(get_num_events::<TestRuntime>() - 2),
+			last_event::<TestRuntime>(),
 			Some(Event::ProposalEnacted { proposal_id: 1 }.into())
 		);
 
@@ -934,8 +1062,7 @@ fn enact_add_location_works() {
 
 		assert_eq!(EncointerCommunities::locations(cid, geo_hash.clone()).len(), 0);
 
-		run_to_next_phase();
-		// first assigning phase after proposal lifetime ended
+		enact_queued_proposals();
 
 		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
 		assert_eq!(EncointerDemocracy::enactment_queue(proposal_action.get_identifier()), None);
@@ -943,6 +1070,144 @@ fn enact_add_location_works() {
 	});
 }
 
+#[test]
+fn enact_set_location_meetup_time_offset_works() {
+	new_test_ext().execute_with(|| {
+		let cid = create_cid();
+		let alice = alice();
+		let location = Location { lat: Degree::from_num(10.0), lon: Degree::from_num(10.0) };
+		let _ = EncointerCommunities::do_add_location(cid, location);
+		let proposal_action =
+			ProposalAction::SetLocationMeetupTimeOffset(cid, location, Some(2 * 3600 * 1000));
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action.clone())
+		));
+
+		// directly inject the proposal into the enactment queue
+		EnactmentQueue::<TestRuntime>::insert(
+			proposal_action.clone().get_identifier(),
+			BoundedVec::try_from(vec![1u128]).unwrap(),
+		);
+
+		enact_queued_proposals();
+
+		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
+		assert_eq!(
+			EncointerCommunities::location_meetup_time_offset(cid, location),
+			Some(2 * 3600 * 1000)
+		);
+	});
+}
+
+#[test]
+fn enact_replace_bootstrapper_works() {
+	new_test_ext().execute_with(|| {
+		let cid = create_cid();
+		let alice = alice();
+		let new_bootstrapper: AccountId = AccountKeyring::One.into();
+		let proposal_action =
+			ProposalAction::ReplaceBootstrapper(cid, alice.clone(), new_bootstrapper.clone());
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action.clone())
+		));
+
+		// directly inject the proposal into the enactment queue
+		EnactmentQueue::<TestRuntime>::insert(
+			proposal_action.clone().get_identifier(),
+			BoundedVec::try_from(vec![1u128]).unwrap(),
+		);
+
+		enact_queued_proposals();
+
+		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
+		let bootstrappers = EncointerCommunities::bootstrappers(cid);
+		assert!(bootstrappers.contains(&new_bootstrapper));
+		assert!(!bootstrappers.contains(&alice));
+	});
+}
+
+#[test]
+fn enact_set_demurrage_exemption_works() {
+	new_test_ext().execute_with(|| {
+		let cid = create_cid();
+		let alice = alice();
+		let treasury: AccountId = AccountKeyring::One.into();
+		let proposal_action = ProposalAction::SetDemurrageExemption(cid, treasury.clone(), true);
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action.clone())
+		));
+
+		// directly inject the proposal into the enactment queue
+		EnactmentQueue::<TestRuntime>::insert(
+			proposal_action.clone().get_identifier(),
+			BoundedVec::try_from(vec![1u128]).unwrap(),
+		);
+
+		enact_queued_proposals();
+
+		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
+		assert!(EncointerBalances::is_demurrage_exempt(cid, &treasury));
+	});
+}
+
+#[test]
+fn queued_demurrage_exemption_only_blocks_proposals_for_the_same_account() {
+	new_test_ext().execute_with(|| {
+		let cid = create_cid();
+		let alice = alice();
+		let treasury: AccountId = AccountKeyring::One.into();
+		let proposal_action = ProposalAction::SetDemurrageExemption(cid, treasury.clone(), true);
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action.clone())
+		));
+		EnactmentQueue::<TestRuntime>::insert(
+			proposal_action.clone().get_identifier(),
+			BoundedVec::try_from(vec![1u128]).unwrap(),
+		);
+
+		assert_err!(
+			EncointerDemocracy::submit_proposal(
+				RuntimeOrigin::signed(alice.clone()),
+				Box::new(ProposalAction::SetDemurrageExemption(cid, treasury, false))
+			),
+			Error::<TestRuntime>::ProposalWaitingForEnactment
+		);
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(ProposalAction::SetDemurrageExemption(cid, AccountKeyring::Two.into(), true))
+		));
+	});
+}
+
+#[test]
+fn enact_update_existential_deposit_works() {
+	new_test_ext().execute_with(|| {
+		let cid = create_cid();
+		let alice = alice();
+		let existential_deposit = BalanceType::from_num(0.5);
+		let proposal_action = ProposalAction::UpdateExistentialDeposit(cid, existential_deposit);
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action.clone())
+		));
+
+		// directly inject the proposal into the enactment queue
+		EnactmentQueue::<TestRuntime>::insert(
+			proposal_action.clone().get_identifier(),
+			BoundedVec::try_from(vec![1u128]).unwrap(),
+		);
+
+		enact_queued_proposals();
+
+		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
+		assert_eq!(EncointerBalances::existential_deposit(cid), existential_deposit);
+	});
+}
+
 #[test]
 fn enact_remove_location_works() {
 	new_test_ext().execute_with(|| {
@@ -965,8 +1230,7 @@ fn enact_remove_location_works() {
 		);
 		assert_eq!(EncointerCommunities::locations(cid, geo_hash.clone()).len(), 1);
 
-		run_to_next_phase();
-		// first assigning phase after proposal lifetime ended
+		enact_queued_proposals();
 
 		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
 		assert_eq!(EncointerDemocracy::enactment_queue(proposal_action.get_identifier()), None);
@@ -997,8 +1261,7 @@ fn enact_update_community_metadata_works() {
 			BoundedVec::try_from(vec![1u128]).unwrap(),
 		);
 
-		run_to_next_phase();
-		// first assigning phase after proposal lifetime ended
+		enact_queued_proposals();
 
 		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
 		assert_eq!(EncointerDemocracy::enactment_queue(proposal_action.get_identifier()), None);
@@ -1030,8 +1293,7 @@ fn enact_update_demurrage_works() {
 
 		assert!(EncointerBalances::demurrage_per_block(cid) != demurrage);
 
-		run_to_next_phase();
-		// first assigning phase after proposal lifetime ended
+		enact_queued_proposals();
 
 		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
 		assert_eq!(EncointerDemocracy::enactment_queue(proposal_action.get_identifier()), None);
@@ -1057,8 +1319,7 @@ fn enact_update_nominal_income_works() {
 			BoundedVec::try_from(vec![1u128]).unwrap(),
 		);
 
-		run_to_next_phase();
-		// first assigning phase after proposal lifetime ended
+		enact_queued_proposals();
 
 		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
 		assert_eq!(EncointerDemocracy::enactment_queue(proposal_action.get_identifier()), None);
@@ -1083,8 +1344,7 @@ fn enact_set_inactivity_timeout_works() {
 			BoundedVec::try_from(vec![1u128]).unwrap(),
 		);
 
-		run_to_next_phase();
-		// first assigning phase after proposal lifetime ended
+		enact_queued_proposals();
 
 		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
 		assert_eq!(
@@ -1118,13 +1378,12 @@ fn enact_petition_works() {
 			BoundedVec::try_from(vec![1u128]).unwrap(),
 		);
 
-		run_to_next_phase();
-		// first assigning phase after proposal lifetime ended
+		enact_queued_proposals();
 
 		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
 		assert_eq!(EncointerDemocracy::enactment_queue(proposal_action.get_identifier()), None);
 
-		match event_at_index::<TestRuntime>(get_num_events::<TestRuntime>() - 3).unwrap() {
+		match event_at_index::<TestRuntime>(get_num_events::<TestRuntime>() - 2).unwrap() {
 			mock::RuntimeEvent::EncointerDemocracy(Event::PetitionApproved {
 				cid: maybe_cid,
 				text,
@@ -1161,14 +1420,13 @@ fn enact_spend_native_works() {
 			BoundedVec::try_from(vec![1u128]).unwrap(),
 		);
 
-		run_to_next_phase();
-		// first assigning phase after proposal lifetime ended
+		enact_queued_proposals();
 
 		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
 		assert_eq!(EncointerDemocracy::enactment_queue(proposal_action.get_identifier()), None);
 
 		assert_eq!(Balances::free_balance(&treasury), 400_000_000);
-		assert_eq!(Balances::free_balance(&beneficiary), amount);
+		assert_eq!(Balances::free_balance(&beneficiary), ALICE_FUNDS - proposal_deposit() + amount);
 	});
 }
 
@@ -1198,8 +1456,7 @@ fn enact_spend_asset_works() {
 			BoundedVec::try_from(vec![1u128]).unwrap(),
 		);
 
-		run_to_next_phase();
-		// first assigning phase after proposal lifetime ended
+		enact_queued_proposals();
 
 		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
 		assert_eq!(EncointerDemocracy::enactment_queue(proposal_action.get_identifier()), None);
@@ -1240,8 +1497,7 @@ fn enact_issue_swap_native_option_works() {
 			BoundedVec::try_from(vec![1u128]).unwrap(),
 		);
 
-		run_to_next_phase();
-		// first assigning phase after proposal lifetime ended
+		enact_queued_proposals();
 
 		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
 		assert_eq!(EncointerDemocracy::enactment_queue(proposal_action.get_identifier()), None);
@@ -1283,8 +1539,7 @@ fn enact_issue_swap_asset_option_works() {
 			BoundedVec::try_from(vec![1u128]).unwrap(),
 		);
 
-		run_to_next_phase();
-		// first assigning phase after proposal lifetime ended
+		enact_queued_proposals();
 
 		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
 		assert_eq!(EncointerDemocracy::enactment_queue(proposal_action.get_identifier()), None);
@@ -1314,10 +1569,9 @@ fn enactment_error_fires_event() {
 			BoundedVec::try_from(vec![1u128]).unwrap(),
 		);
 
-		run_to_next_phase();
-		// first assigning phase after proposal lifetime ended
+		enact_queued_proposals();
 
-		match event_at_index::<TestRuntime>(get_num_events::<TestRuntime>() - 2).unwrap() {
+		match last_event::<TestRuntime>().unwrap() {
 			mock::RuntimeEvent::EncointerDemocracy(Event::EnactmentFailed {
 				proposal_id: 1,
 				reason: _r,
@@ -1362,7 +1616,7 @@ fn multiple_approved_proposals_of_same_action_type_are_all_enqueued() {
 		let treasury = EncointerTreasuries::get_community_treasury_account_unchecked(Some(cid));
 		Balances::make_free_balance_be(&treasury, 500_000_000);
 
-		run_to_next_phase();
+		enact_queued_proposals();
 
 		// Both should be enacted
 		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
@@ -1424,3 +1678,723 @@ fn enactment_queue_appends_on_concurrent_approval() {
 		assert!(queue.contains(&2));
 	});
 }
+
+#[test]
+fn approval_is_deferred_while_enactment_queue_is_full() {
+	new_test_ext().execute_with(|| {
+		let alice = alice();
+		let cid = register_test_community::<TestRuntime>(None, 10.0, 10.0);
+		for p in &add_population(100, 0) {
+			EncointerCeremonies::fake_reputation(
+				(cid, 5),
+				&account_id(p),
+				Reputation::VerifiedLinked(0),
+			);
+		}
+		let proposal_action = ProposalAction::Petition(
+			Some(cid),
+			PalletString::try_from("petition".as_bytes().to_vec()).unwrap(),
+		);
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action.clone())
+		));
+		Tallies::<TestRuntime>::insert(1, Tally { turnout: 100, ayes: 100 });
+		assert!(!EncointerDemocracy::do_update_proposal_state(1).unwrap());
+		let since = Timestamp::get();
+		assert_eq!(
+			EncointerDemocracy::proposals(1).unwrap().state,
+			ProposalState::Confirming { since }
+		);
+
+		EnactmentQueue::<TestRuntime>::insert(
+			proposal_action.clone().get_identifier(),
+			BoundedVec::try_from(
+				(100..100 + ENACTMENT_QUEUE_MAX_PER_TYPE as u128).collect::<Vec<_>>(),
+			)
+			.unwrap(),
+		);
+		advance_n_blocks(11);
+
+		assert_err!(
+			EncointerDemocracy::do_update_proposal_state(1),
+			Error::<TestRuntime>::EnactmentQueueFull
+		);
+		assert_eq!(
+			EncointerDemocracy::proposals(1).unwrap().state,
+			ProposalState::Confirming { since }
+		);
+		assert_eq!(
+			EncointerDemocracy::last_approved_proposal_for_action(proposal_action.get_identifier()),
+			None
+		);
+
+		// the idle hook keeps the proposal open and retries later
+		EncointerDemocracy::update_due_proposals(Weight::MAX);
+		assert_eq!(
+			EncointerDemocracy::open_proposals().into_inner(),
+			vec![(Timestamp::get() + 60000, 1)]
+		);
+	});
+}
+
+#[test]
+fn cancel_proposal_removes_approved_proposal_from_enactment_queue() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let cid = create_cid();
+		let alice = alice();
+		let bob = bob();
+
+		let proposal_action1 = ProposalAction::SpendNative(Some(cid), alice.clone(), 100);
+		let proposal_action2 = ProposalAction::SpendNative(Some(cid), bob.clone(), 200);
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action1.clone())
+		));
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action2.clone())
+		));
+
+		for proposal_id in [1u128, 2u128] {
+			let mut proposal = EncointerDemocracy::proposals(proposal_id).unwrap();
+			proposal.state = ProposalState::Approved;
+			Proposals::<TestRuntime>::insert(proposal_id, proposal);
+		}
+		EnactmentQueue::<TestRuntime>::insert(
+			proposal_action1.clone().get_identifier(),
+			BoundedVec::try_from(vec![1u128, 2u128]).unwrap(),
+		);
+		LastApprovedProposalForAction::<TestRuntime>::insert(
+			proposal_action1.clone().get_identifier(),
+			(Timestamp::get(), 2u128),
+		);
+
+		assert_ok!(EncointerDemocracy::cancel_proposal(RuntimeOrigin::signed(alice.clone()), 2));
+
+		assert_eq!(
+			last_event::<TestRuntime>(),
+			Some(Event::ProposalCancelled { proposal_id: 2 }.into())
+		);
+		assert_eq!(EncointerDemocracy::proposals(2).unwrap().state, ProposalState::Cancelled);
+		assert_eq!(
+			EncointerDemocracy::enactment_queue(proposal_action1.clone().get_identifier())
+				.unwrap()
+				.into_inner(),
+			vec![1u128]
+		);
+		assert_eq!(
+			EncointerDemocracy::last_approved_proposal_for_action(
+				proposal_action1.clone().get_identifier()
+			),
+			None
+		);
+
+		let treasury = EncointerTreasuries::get_community_treasury_account_unchecked(Some(cid));
+		Balances::make_free_balance_be(&treasury, 500_000_000);
+
+		enact_queued_proposals();
+
+		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
+		assert_eq!(EncointerDemocracy::proposals(2).unwrap().state, ProposalState::Cancelled);
+		assert_eq!(Balances::free_balance(&alice), ALICE_FUNDS - 2 * proposal_deposit() + 100);
+		assert_eq!(Balances::free_balance(&bob), 0);
+	});
+}
+
+#[test]
+fn cancel_last_queued_proposal_unblocks_submission() {
+	new_test_ext().execute_with(|| {
+		let alice = alice();
+		let proposal_action = ProposalAction::SetInactivityTimeout(8);
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action.clone())
+		));
+		let mut proposal = EncointerDemocracy::proposals(1).unwrap();
+		proposal.state = ProposalState::Approved;
+		Proposals::<TestRuntime>::insert(1, proposal);
+		EnactmentQueue::<TestRuntime>::insert(
+			proposal_action.clone().get_identifier(),
+			BoundedVec::try_from(vec![1u128]).unwrap(),
+		);
+
+		assert_err!(
+			EncointerDemocracy::submit_proposal(
+				RuntimeOrigin::signed(alice.clone()),
+				Box::new(proposal_action.clone())
+			),
+			Error::<TestRuntime>::ProposalWaitingForEnactment
+		);
+
+		assert_ok!(EncointerDemocracy::cancel_proposal(RuntimeOrigin::root(), 1));
+		assert_eq!(EncointerDemocracy::enactment_queue(proposal_action.get_identifier()), None);
+
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action.clone())
+		));
+	});
+}
+
+#[test]
+fn cancel_proposal_fails_with_bad_origin() {
+	new_test_ext().execute_with(|| {
+		let alice = alice();
+		let proposal_action = ProposalAction::SetInactivityTimeout(8);
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action.clone())
+		));
+		let mut proposal = EncointerDemocracy::proposals(1).unwrap();
+		proposal.state = ProposalState::Approved;
+		Proposals::<TestRuntime>::insert(1, proposal);
+
+		assert_err!(
+			EncointerDemocracy::cancel_proposal(RuntimeOrigin::signed(bob()), 1),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Approved);
+	});
+}
+
+#[test]
+fn cancel_proposal_fails_if_not_approved() {
+	new_test_ext().execute_with(|| {
+		let alice = alice();
+		let proposal_action = ProposalAction::SetInactivityTimeout(8);
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action.clone())
+		));
+
+		assert_err!(
+			EncointerDemocracy::cancel_proposal(RuntimeOrigin::signed(alice.clone()), 1),
+			Error::<TestRuntime>::ProposalCannotBeCancelled
+		);
+
+		let mut proposal = EncointerDemocracy::proposals(1).unwrap();
+		proposal.state = ProposalState::Enacted;
+		Proposals::<TestRuntime>::insert(1, proposal);
+		assert_err!(
+			EncointerDemocracy::cancel_proposal(RuntimeOrigin::signed(alice.clone()), 1),
+			Error::<TestRuntime>::ProposalCannotBeCancelled
+		);
+
+		assert_err!(
+			EncointerDemocracy::cancel_proposal(RuntimeOrigin::signed(alice), 2),
+			Error::<TestRuntime>::InexistentProposal
+		);
+	});
+}
+
+#[test]
+fn enactment_is_deferred_outside_registering_phase() {
+	new_test_ext().execute_with(|| {
+		let alice = alice();
+		let proposal_action = ProposalAction::SetInactivityTimeout(13037);
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action.clone())
+		));
+		EnactmentQueue::<TestRuntime>::insert(
+			proposal_action.clone().get_identifier(),
+			BoundedVec::try_from(vec![1u128]).unwrap(),
+		);
+		let mut proposal = EncointerDemocracy::proposals(1).unwrap();
+		proposal.state = ProposalState::Approved;
+		Proposals::<TestRuntime>::insert(1, proposal);
+
+		run_to_next_phase();
+		assert_eq!(EncointerScheduler::current_phase(), CeremonyPhaseType::Assigning);
+		enact_queued_proposals();
+		run_to_next_phase();
+		assert_eq!(EncointerScheduler::current_phase(), CeremonyPhaseType::Attesting);
+		enact_queued_proposals();
+
+		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Approved);
+		assert!(
+			EncointerDemocracy::enactment_queue(proposal_action.clone().get_identifier()).is_some()
+		);
+
+		run_to_next_phase();
+		assert_eq!(EncointerScheduler::current_phase(), CeremonyPhaseType::Registering);
+		enact_queued_proposals();
+
+		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
+		assert_eq!(EncointerDemocracy::enactment_queue(proposal_action.get_identifier()), None);
+		assert_eq!(EncointerCeremonies::inactivity_timeout(), 13037);
+	});
+}
+
+#[test]
+fn enactment_is_bounded_by_remaining_weight() {
+	new_test_ext().execute_with(|| {
+		let cid = create_cid();
+		let alice = alice();
+		let bob = bob();
+
+		for (beneficiary, amount) in [(alice.clone(), 100), (bob.clone(), 200)] {
+			assert_ok!(EncointerDemocracy::submit_proposal(
+				RuntimeOrigin::signed(alice.clone()),
+				Box::new(ProposalAction::SpendNative(Some(cid), beneficiary, amount))
+			));
+		}
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(ProposalAction::SetInactivityTimeout(13037))
+		));
+		EnactmentQueue::<TestRuntime>::insert(
+			ProposalActionIdentifier::SpendNative(Some(cid)),
+			BoundedVec::try_from(vec![1u128, 2u128]).unwrap(),
+		);
+		EnactmentQueue::<TestRuntime>::insert(
+			ProposalActionIdentifier::SetInactivityTimeout,
+			BoundedVec::try_from(vec![3u128]).unwrap(),
+		);
+		let treasury = EncointerTreasuries::get_community_treasury_account_unchecked(Some(cid));
+		Balances::make_free_balance_be(&treasury, 500_000_000);
+
+		let db_weight: frame_support::weights::RuntimeDbWeight =
+			<TestRuntime as frame_system::Config>::DbWeight::get();
+		let one_step = <() as WeightInfo>::enact_proposal()
+			.saturating_add(db_weight.reads_writes(1, 1))
+			.saturating_add(db_weight.reads(1));
+		let enacted = || {
+			(1..=3u128)
+				.filter(|id| {
+					EncointerDemocracy::proposals(id).unwrap().state == ProposalState::Enacted
+				})
+				.count()
+		};
+
+		// not even a single step fits
+		EncointerDemocracy::on_idle(
+			System::block_number(),
+			one_step.saturating_sub(Weight::from_parts(1, 0)),
+		);
+		assert_eq!(enacted(), 0);
+
+		// one step per block
+		assert_eq!(EncointerDemocracy::on_idle(System::block_number(), one_step), one_step);
+		assert_eq!(enacted(), 1);
+		EncointerDemocracy::on_idle(System::block_number(), one_step);
+		assert_eq!(enacted(), 2);
+		EncointerDemocracy::on_idle(System::block_number(), one_step);
+		assert_eq!(enacted(), 3);
+		assert!(EnactmentQueue::<TestRuntime>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn open_proposals_are_indexed_by_next_update() {
+	new_test_ext().execute_with(|| {
+		let alice = alice();
+		let cid = register_test_community::<TestRuntime>(None, 10.0, 10.0);
+		let pairs = add_population(100, 0);
+		for p in &pairs {
+			EncointerCeremonies::fake_reputation(
+				(cid, 5),
+				&account_id(p),
+				Reputation::VerifiedLinked(0),
+			);
+		}
+		let start = Timestamp::get();
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(ProposalAction::SetInactivityTimeout(8))
+		));
+		advance_n_blocks(1);
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(ProposalAction::Petition(
+				Some(cid),
+				PalletString::try_from("petition".as_bytes().to_vec()).unwrap()
+			))
+		));
+		assert_eq!(
+			EncointerDemocracy::open_proposals().into_inner(),
+			vec![(start + 240001, 1), (start + BLOCKTIME + 240001, 2)]
+		);
+
+		// entering confirmation moves the proposal to the front
+		Tallies::<TestRuntime>::insert(2, Tally { turnout: 100, ayes: 100 });
+		let now = Timestamp::get();
+		assert!(!EncointerDemocracy::do_update_proposal_state(2).unwrap());
+		assert_eq!(
+			EncointerDemocracy::open_proposals().into_inner(),
+			vec![(now + 60001, 2), (start + 240001, 1)]
+		);
+
+		// final states are removed
+		let mut proposal = EncointerDemocracy::proposals(1).unwrap();
+		proposal.state = ProposalState::Confirming { since: now };
+		Proposals::<TestRuntime>::insert(1, proposal);
+		assert!(!EncointerDemocracy::do_update_proposal_state(1).unwrap());
+		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Ongoing);
+		advance_n_blocks(41);
+		assert!(!EncointerDemocracy::do_update_proposal_state(1).unwrap());
+		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Rejected);
+		assert_eq!(EncointerDemocracy::open_proposals().into_inner(), vec![(now + 60001, 2)]);
+	});
+}
+
+#[test]
+fn idle_hook_rejects_stale_proposals() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let alice = alice();
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(ProposalAction::SetInactivityTimeout(8))
+		));
+
+		advance_n_blocks(40);
+		enact_queued_proposals();
+		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Ongoing);
+
+		advance_n_blocks(1);
+		enact_queued_proposals();
+		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Rejected);
+		assert_eq!(
+			last_event::<TestRuntime>(),
+			Some(
+				Event::ProposalStateUpdated {
+					proposal_id: 1,
+					proposal_state: ProposalState::Rejected
+				}
+				.into()
+			)
+		);
+		assert!(EncointerDemocracy::open_proposals().is_empty());
+	});
+}
+
+#[test]
+fn idle_hook_approves_and_enacts_confirmed_proposals() {
+	new_test_ext().execute_with(|| {
+		let alice = alice();
+		let cid = create_cid();
+
+		EncointerCeremonies::fake_reputation((cid, 3), &alice, Reputation::VerifiedLinked(0));
+		EncointerCeremonies::fake_reputation((cid, 4), &alice, Reputation::VerifiedLinked(0));
+		EncointerCeremonies::fake_reputation((cid, 5), &alice, Reputation::VerifiedLinked(0));
+
+		let proposal_action =
+			ProposalAction::UpdateNominalIncome(cid, NominalIncomeType::from(13037u32));
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(proposal_action.clone())
+		));
+		assert_ok!(EncointerDemocracy::vote(
+			RuntimeOrigin::signed(alice.clone()),
+			1,
+			Vote::Aye,
+			BoundedVec::try_from(vec![(cid, 3), (cid, 4), (cid, 5)]).unwrap()
+		));
+		assert!(matches!(
+			EncointerDemocracy::proposals(1).unwrap().state,
+			ProposalState::Confirming { .. }
+		));
+
+		advance_n_blocks(10);
+		enact_queued_proposals();
+		assert!(matches!(
+			EncointerDemocracy::proposals(1).unwrap().state,
+			ProposalState::Confirming { .. }
+		));
+
+		// no transaction needed to complete the confirmation
+		advance_n_blocks(1);
+		enact_queued_proposals();
+		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Enacted);
+		assert_eq!(EncointerCommunities::nominal_income(cid), NominalIncomeType::from(13037u32));
+		assert!(EncointerDemocracy::open_proposals().is_empty());
+	});
+}
+
+#[test]
+fn submit_proposal_fails_with_too_many_open_proposals() {
+	new_test_ext().execute_with(|| {
+		let alice = alice();
+		OpenProposals::<TestRuntime>::put(
+			BoundedVec::try_from((100..200u128).map(|id| (u64::MAX, id)).collect::<Vec<_>>())
+				.unwrap(),
+		);
+		assert_err!(
+			EncointerDemocracy::submit_proposal(
+				RuntimeOrigin::signed(alice.clone()),
+				Box::new(ProposalAction::SetInactivityTimeout(8))
+			),
+			Error::<TestRuntime>::TooManyOpenProposals
+		);
+		assert_eq!(EncointerDemocracy::proposal_count(), 0);
+	});
+}
+
+#[test]
+fn submit_proposal_reserves_deposit_until_proposal_is_closed() {
+	new_test_ext().execute_with(|| {
+		let alice = alice();
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(ProposalAction::SetInactivityTimeout(8))
+		));
+		assert_eq!(Balances::reserved_balance(&alice), proposal_deposit());
+		assert_eq!(
+			EncointerDemocracy::proposal_deposits(1),
+			Some((alice.clone(), proposal_deposit()))
+		);
+
+		advance_n_blocks(41);
+		EncointerDemocracy::update_due_proposals(Weight::MAX);
+		assert_eq!(EncointerDemocracy::proposals(1).unwrap().state, ProposalState::Rejected);
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+		assert_eq!(Balances::free_balance(&alice), ALICE_FUNDS);
+		assert_eq!(EncointerDemocracy::proposal_deposits(1), None);
+	});
+}
+
+#[test]
+fn submit_proposal_fails_without_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_err!(
+			EncointerDemocracy::submit_proposal(
+				RuntimeOrigin::signed(bob()),
+				Box::new(ProposalAction::SetInactivityTimeout(8))
+			),
+			Error::<TestRuntime>::InsufficientBalance
+		);
+		assert_eq!(EncointerDemocracy::proposal_count(), 0);
+	});
+}
+
+fn submit_spend_native_proposal_with_voter(voter: &AccountId) -> CommunityIdentifier {
+	let cid = create_cid();
+	EncointerCeremonies::fake_reputation((cid, 3), voter, Reputation::VerifiedLinked(0));
+	EncointerCeremonies::fake_reputation((cid, 4), voter, Reputation::VerifiedLinked(0));
+	EncointerCeremonies::fake_reputation((cid, 5), voter, Reputation::VerifiedLinked(0));
+	assert_ok!(EncointerDemocracy::submit_proposal(
+		RuntimeOrigin::signed(voter.clone()),
+		Box::new(ProposalAction::SpendNative(Some(cid), voter.clone(), 100))
+	));
+	cid
+}
+
+#[test]
+fn vote_with_conviction_amplifies_tally_and_locks_funds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(System::block_number() + 1); // this is needed to assert events
+		let alice = alice();
+		Balances::make_free_balance_be(&alice, 10_000);
+		let cid = submit_spend_native_proposal_with_voter(&alice);
+		assert_eq!(EncointerDemocracy::conviction_tallies(1), Some(ConvictionTally::default()));
+
+		assert_ok!(EncointerDemocracy::vote_with_conviction(
+			RuntimeOrigin::signed(alice.clone()),
+			1,
+			Vote::Aye,
+			BoundedVec::try_from(vec![(cid, 3), (cid, 4), (cid, 5)]).unwrap(),
+			Conviction::Locked2x,
+			3_000
+		));
+
+		assert_eq!(EncointerDemocracy::tallies(1).unwrap(), Tally { turnout: 3, ayes: 3 });
+		assert_eq!(
+			EncointerDemocracy::conviction_tallies(1).unwrap(),
+			ConvictionTally { turnout: 9, ayes: 9 }
+		);
+		assert_eq!(
+			EncointerDemocracy::vote_locks(&alice).into_inner(),
+			vec![VoteLock { proposal_id: 1, amount: 3_000, unlock_cindex: 9 }]
+		);
+		assert_eq!(Balances::usable_balance(&alice), 7_000);
+		assert_eq!(
+			event_at_index::<TestRuntime>(get_num_events::<TestRuntime>() - 3).unwrap(),
+			mock::RuntimeEvent::EncointerDemocracy(Event::VoteLocked {
+				who: alice,
+				proposal_id: 1,
+				conviction: Conviction::Locked2x,
+				amount: 3_000,
+				unlock_cindex: 9,
+			})
+		);
+	});
+}
+
+#[test]
+fn vote_with_conviction_fails_for_non_treasury_proposal() {
+	new_test_ext().execute_with(|| {
+		let cid = create_cid();
+		let alice = alice();
+		Balances::make_free_balance_be(&alice, 10_000);
+		EncointerCeremonies::fake_reputation((cid, 5), &alice, Reputation::VerifiedLinked(0));
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(ProposalAction::SetInactivityTimeout(8))
+		));
+		assert_eq!(EncointerDemocracy::conviction_tallies(1), None);
+
+		assert_err!(
+			EncointerDemocracy::vote_with_conviction(
+				RuntimeOrigin::signed(alice.clone()),
+				1,
+				Vote::Aye,
+				BoundedVec::try_from(vec![(cid, 5)]).unwrap(),
+				Conviction::Locked1x,
+				1_000
+			),
+			Error::<TestRuntime>::ConvictionNotSupported
+		);
+	});
+}
+
+#[test]
+fn vote_with_conviction_fails_with_insufficient_lock_or_balance() {
+	new_test_ext().execute_with(|| {
+		let alice = alice();
+		Balances::make_free_balance_be(&alice, 2_000);
+		let cid = submit_spend_native_proposal_with_voter(&alice);
+		let reputations = BoundedVec::try_from(vec![(cid, 3), (cid, 4)]).unwrap();
+
+		// two votes require at least 2 * MinVoteLockPerReputation
+		assert_err!(
+			EncointerDemocracy::vote_with_conviction(
+				RuntimeOrigin::signed(alice.clone()),
+				1,
+				Vote::Aye,
+				reputations.clone(),
+				Conviction::Locked1x,
+				1_999
+			),
+			Error::<TestRuntime>::InsufficientVoteLock
+		);
+		assert_err!(
+			EncointerDemocracy::vote_with_conviction(
+				RuntimeOrigin::signed(alice.clone()),
+				1,
+				Vote::Aye,
+				reputations,
+				Conviction::Locked1x,
+				2_001
+			),
+			Error::<TestRuntime>::InsufficientBalance
+		);
+		assert_eq!(EncointerDemocracy::tallies(1).unwrap(), Tally { turnout: 0, ayes: 0 });
+		assert!(EncointerDemocracy::vote_locks(&alice).is_empty());
+	});
+}
+
+#[test]
+fn plain_vote_counts_once_in_conviction_tally() {
+	new_test_ext().execute_with(|| {
+		let alice = alice();
+		let cid = submit_spend_native_proposal_with_voter(&alice);
+
+		assert_ok!(EncointerDemocracy::vote(
+			RuntimeOrigin::signed(alice.clone()),
+			1,
+			Vote::Nay,
+			BoundedVec::try_from(vec![(cid, 3), (cid, 4)]).unwrap(),
+		));
+
+		assert_eq!(
+			EncointerDemocracy::conviction_tallies(1).unwrap(),
+			ConvictionTally { turnout: 2, ayes: 0 }
+		);
+		assert!(EncointerDemocracy::vote_locks(&alice).is_empty());
+	});
+}
+
+#[test]
+fn unlock_releases_expired_vote_locks() {
+	new_test_ext().execute_with(|| {
+		let alice = alice();
+		Balances::make_free_balance_be(&alice, 10_000);
+		let cid = submit_spend_native_proposal_with_voter(&alice);
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(ProposalAction::SpendNative(Some(cid), alice.clone(), 200))
+		));
+
+		assert_ok!(EncointerDemocracy::vote_with_conviction(
+			RuntimeOrigin::signed(alice.clone()),
+			1,
+			Vote::Aye,
+			BoundedVec::try_from(vec![(cid, 3)]).unwrap(),
+			Conviction::Locked1x,
+			4_000
+		));
+		assert_ok!(EncointerDemocracy::vote_with_conviction(
+			RuntimeOrigin::signed(alice.clone()),
+			2,
+			Vote::Aye,
+			BoundedVec::try_from(vec![(cid, 3)]).unwrap(),
+			Conviction::Locked2x,
+			1_000
+		));
+		// locks overlay
+		assert_eq!(Balances::usable_balance(&alice), 6_000);
+
+		// nothing expired yet
+		assert_ok!(EncointerDemocracy::unlock(RuntimeOrigin::signed(bob()), alice.clone()));
+		assert_eq!(EncointerDemocracy::vote_locks(&alice).len(), 2);
+		assert_eq!(Balances::usable_balance(&alice), 6_000);
+
+		// next ceremony cycle
+		run_to_next_phase();
+		run_to_next_phase();
+		run_to_next_phase();
+		assert_eq!(EncointerScheduler::current_ceremony_index(), 8);
+
+		assert_ok!(EncointerDemocracy::unlock(RuntimeOrigin::signed(bob()), alice.clone()));
+		assert_eq!(
+			EncointerDemocracy::vote_locks(&alice).into_inner(),
+			vec![VoteLock { proposal_id: 2, amount: 1_000, unlock_cindex: 9 }]
+		);
+		assert_eq!(Balances::usable_balance(&alice), 9_000);
+
+		run_to_next_phase();
+		run_to_next_phase();
+		run_to_next_phase();
+
+		assert_ok!(EncointerDemocracy::unlock(RuntimeOrigin::signed(bob()), alice.clone()));
+		assert!(EncointerDemocracy::vote_locks(&alice).is_empty());
+		assert!(Balances::locks(&alice).is_empty());
+		// only the proposal deposits remain reserved
+		assert_eq!(Balances::free_balance(&alice), 10_000 - 2 * proposal_deposit());
+	});
+}
+
+#[test]
+fn is_passing_uses_conviction_tally_for_treasury_proposals() {
+	new_test_ext().execute_with(|| {
+		let alice = alice();
+		let cid = register_test_community::<TestRuntime>(None, 10.0, 10.0);
+
+		// electorate is 100
+		let pairs = add_population(100, 0);
+		for p in pairs {
+			EncointerCeremonies::fake_reputation(
+				(cid, 5),
+				&account_id(&p),
+				Reputation::VerifiedLinked(0),
+			);
+		}
+
+		assert_ok!(EncointerDemocracy::submit_proposal(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(ProposalAction::SpendNative(Some(cid), alice.clone(), 100))
+		));
+
+		// 4 ayes out of 10 votes fails on reputation alone
+		Tallies::<TestRuntime>::insert(1, Tally { turnout: 10, ayes: 4 });
+		ConvictionTallies::<TestRuntime>::insert(1, ConvictionTally { turnout: 10, ayes: 4 });
+		assert!(!EncointerDemocracy::is_passing(1).unwrap());
+
+		// the ayes locked with conviction tip the balance
+		ConvictionTallies::<TestRuntime>::insert(1, ConvictionTally { turnout: 34, ayes: 31 });
+		assert!(EncointerDemocracy::is_passing(1).unwrap());
+	});
+}
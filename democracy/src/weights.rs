@@ -53,6 +53,10 @@ pub trait WeightInfo {
 	fn submit_proposal() -> Weight;
 	fn vote() -> Weight;
 	fn update_proposal_state() -> Weight;
+	fn cancel_proposal() -> Weight;
+	fn enact_proposal() -> Weight;
+	fn vote_with_conviction() -> Weight;
+	fn unlock() -> Weight;
 }
 
 // For tests
@@ -72,4 +76,24 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(9))
 			.saturating_add(RocksDbWeight::get().writes(3))
 	}
+	fn cancel_proposal() -> Weight {
+		Weight::from_parts(40_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	fn enact_proposal() -> Weight {
+		Weight::from_parts(8_160_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(216))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	fn vote_with_conviction() -> Weight {
+		Weight::from_parts(190_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(19))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+	fn unlock() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
 }
@@ -43,6 +43,7 @@ fn create_community<T: Config>() -> CommunityIdentifier {
 		CommunityMetadataType::default(),
 		None,
 		None,
+		None,
 	)
 	.ok();
 	let cid = CommunityIdentifier::new(location, bs).unwrap();
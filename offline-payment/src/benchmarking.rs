@@ -25,7 +25,7 @@ use crate::*;
 use sp_std::vec;
 
 use encointer_primitives::{
-	balances::BalanceType,
+	balances::{BalanceType, PaymentReference},
 	communities::{CommunityIdentifier, CommunityMetadata, Degree, Location},
 };
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
@@ -125,6 +125,7 @@ fn create_community<T: Config>() -> CommunityIdentifier {
 		CommunityMetadata::default(),
 		None,
 		None,
+		None,
 	)
 	.ok();
 
@@ -162,6 +163,7 @@ benchmarks! {
 		let bounded_proof: BoundedVec<u8, T::MaxProofSize> =
 			BoundedVec::try_from(PROOF_BYTES.to_vec()).expect("proof within bounds");
 		let proof_struct = Groth16ProofBytes { proof_bytes: bounded_proof };
+		let reference = PaymentReference::truncate_from(vec![1u8; 64]);
 	}: submit_offline_payment(
 		RawOrigin::Signed(submitter),
 		proof_struct,
@@ -169,7 +171,8 @@ benchmarks! {
 		recipient.clone(),
 		amount,
 		cid,
-		NULLIFIER
+		NULLIFIER,
+		Some(reference)
 	)
 	verify {
 		assert!(UsedNullifiers::<T>::contains_key(NULLIFIER));
@@ -195,13 +198,15 @@ benchmarks! {
 		let bounded_proof: BoundedVec<u8, T::MaxProofSize> =
 			BoundedVec::try_from(NATIVE_PROOF_BYTES.to_vec()).expect("proof within bounds");
 		let proof_struct = Groth16ProofBytes { proof_bytes: bounded_proof };
+		let reference = PaymentReference::truncate_from(vec![1u8; 64]);
 	}: submit_native_offline_payment(
 		RawOrigin::Signed(submitter),
 		proof_struct,
 		sender.clone(),
 		recipient.clone(),
 		amount,
-		NATIVE_NULLIFIER
+		NATIVE_NULLIFIER,
+		Some(reference)
 	)
 	verify {
 		assert!(UsedNullifiers::<T>::contains_key(NATIVE_NULLIFIER));
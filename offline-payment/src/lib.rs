@@ -37,7 +37,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use core::marker::PhantomData;
-use encointer_primitives::{balances::BalanceType, communities::CommunityIdentifier};
+use encointer_primitives::{
+	balances::{BalanceType, PaymentReference},
+	communities::CommunityIdentifier,
+};
 use frame_support::traits::{Currency, EnsureOrigin, ExistenceRequirement, Get};
 use frame_system::ensure_signed;
 use log::info;
@@ -226,20 +229,24 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// Offline identity registered for an account
 		OfflineIdentityRegistered { who: T::AccountId, commitment: [u8; 32] },
-		/// Offline payment settled successfully
+		/// Offline payment settled successfully. The `reference` is supplied by the submitter and
+		/// not covered by the proof.
 		OfflinePaymentSettled {
 			sender: T::AccountId,
 			recipient: T::AccountId,
 			cid: CommunityIdentifier,
 			amount: BalanceType,
 			nullifier: [u8; 32],
+			reference: Option<PaymentReference>,
 		},
-		/// Native token offline payment settled successfully
+		/// Native token offline payment settled successfully. The `reference` is supplied by the
+		/// submitter and not covered by the proof.
 		NativeOfflinePaymentSettled {
 			sender: T::AccountId,
 			recipient: T::AccountId,
 			amount: BalanceOf<T>,
 			nullifier: [u8; 32],
+			reference: Option<PaymentReference>,
 		},
 		/// Verification key was set
 		VerificationKeySet,
@@ -314,8 +321,12 @@ pub mod pallet {
 		/// * `amount` - The amount to transfer
 		/// * `cid` - The community identifier
 		/// * `nullifier` - The unique nullifier for this payment
+		/// * `reference` - An optional payment reference, e.g. an invoice number, to reconcile the
+		///   payment with. It is supplied by the submitter and not proven, so the recipient must
+		///   not rely on it having been agreed on by the sender.
 		#[pallet::call_index(1)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_offline_payment())]
+		#[allow(clippy::too_many_arguments)]
 		pub fn submit_offline_payment(
 			origin: OriginFor<T>,
 			proof: Groth16ProofBytes<T::MaxProofSize>,
@@ -324,6 +335,7 @@ pub mod pallet {
 			amount: BalanceType,
 			cid: CommunityIdentifier,
 			nullifier: [u8; 32],
+			reference: Option<PaymentReference>,
 		) -> DispatchResult {
 			// Anyone can submit
 			let _submitter = ensure_signed(origin)?;
@@ -383,7 +395,13 @@ pub mod pallet {
 			);
 
 			// 9. Execute transfer
-			pallet_encointer_balances::Pallet::<T>::do_transfer(cid, &sender, &recipient, amount)?;
+			pallet_encointer_balances::Pallet::<T>::do_transfer_with_reference(
+				cid,
+				&sender,
+				&recipient,
+				amount,
+				reference.clone(),
+			)?;
 
 			// 10. Mark nullifier as used
 			UsedNullifiers::<T>::insert(nullifier, ());
@@ -398,6 +416,7 @@ pub mod pallet {
 				cid,
 				amount,
 				nullifier,
+				reference,
 			});
 
 			Ok(())
@@ -407,7 +426,8 @@ pub mod pallet {
 		///
 		/// Same ZK circuit as CC payments, but uses a sentinel CID hash
 		/// (`blake2_256(b"encointer-native-token")`) and transfers native currency
-		/// via `T::Currency::transfer()`.
+		/// via `T::Currency::transfer()`. Like there, the optional `reference` is supplied by the
+		/// submitter and not proven.
 		#[pallet::call_index(3)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_native_offline_payment())]
 		pub fn submit_native_offline_payment(
@@ -417,6 +437,7 @@ pub mod pallet {
 			recipient: T::AccountId,
 			amount: BalanceOf<T>,
 			nullifier: [u8; 32],
+			reference: Option<PaymentReference>,
 		) -> DispatchResult {
 			let _submitter = ensure_signed(origin)?;
 
@@ -473,6 +494,7 @@ pub mod pallet {
 				recipient,
 				amount,
 				nullifier,
+				reference,
 			});
 
 			Ok(())
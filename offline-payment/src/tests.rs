@@ -15,7 +15,10 @@
 // along with Encointer.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{mock::*, Error, Event, Groth16ProofBytes, OfflineIdentities, UsedNullifiers};
-use encointer_primitives::{balances::BalanceType, communities::CommunityIdentifier};
+use encointer_primitives::{
+	balances::{BalanceType, PaymentReference},
+	communities::CommunityIdentifier,
+};
 use frame_support::{assert_noop, assert_ok, traits::Currency, BoundedVec};
 use sp_keyring::Sr25519Keyring;
 use test_utils::helpers::register_test_community;
@@ -146,7 +149,8 @@ fn submit_offline_payment_fails_without_verification_key() {
 				bob(),
 				amount,
 				cid,
-				nullifier
+				nullifier,
+				None
 			),
 			Error::<TestRuntime>::NoVerificationKey
 		);
@@ -174,7 +178,8 @@ fn submit_offline_payment_fails_with_unregistered_sender() {
 				bob(),
 				amount,
 				cid,
-				nullifier
+				nullifier,
+				None
 			),
 			Error::<TestRuntime>::NoOfflineIdentity
 		);
@@ -206,7 +211,8 @@ fn submit_offline_payment_fails_with_zero_amount() {
 				bob(),
 				BalanceType::from_num(0),
 				cid,
-				nullifier
+				nullifier,
+				None
 			),
 			Error::<TestRuntime>::AmountMustBePositive
 		);
@@ -239,7 +245,8 @@ fn submit_offline_payment_fails_when_sender_equals_recipient() {
 				alice(), // Same as sender
 				amount,
 				cid,
-				nullifier
+				nullifier,
+				None
 			),
 			Error::<TestRuntime>::SenderEqualsRecipient
 		);
@@ -272,7 +279,8 @@ fn submit_offline_payment_fails_with_insufficient_balance() {
 				bob(),
 				amount,
 				cid,
-				nullifier
+				nullifier,
+				None
 			),
 			Error::<TestRuntime>::InsufficientBalance
 		);
@@ -439,6 +447,7 @@ fn e2e_zk_payment_works() {
 		let bounded_proof: BoundedVec<u8, MaxProofSize> =
 			BoundedVec::try_from(proof_bytes).expect("Proof too large");
 		let proof_struct = Groth16ProofBytes { proof_bytes: bounded_proof };
+		let reference = PaymentReference::truncate_from(b"invoice-42".to_vec());
 
 		assert_ok!(EncointerOfflinePayment::submit_offline_payment(
 			RuntimeOrigin::signed(charlie()), // Anyone can submit
@@ -447,8 +456,30 @@ fn e2e_zk_payment_works() {
 			bob(),
 			amount,
 			cid,
-			nullifier
+			nullifier,
+			Some(reference.clone())
 		));
+		System::assert_has_event(
+			pallet_encointer_balances::Event::<TestRuntime>::TransferredWithReference(
+				cid,
+				alice(),
+				bob(),
+				amount,
+				reference.clone(),
+			)
+			.into(),
+		);
+		System::assert_last_event(
+			Event::<TestRuntime>::OfflinePaymentSettled {
+				sender: alice(),
+				recipient: bob(),
+				cid,
+				amount,
+				nullifier,
+				reference: Some(reference),
+			}
+			.into(),
+		);
 
 		// Step 8: Verify the payment was processed
 		assert_eq!(
@@ -475,7 +506,8 @@ fn e2e_zk_payment_works() {
 				bob(),
 				amount,
 				cid,
-				nullifier // Same nullifier
+				nullifier, // Same nullifier
+				None,
 			),
 			Error::<TestRuntime>::NullifierAlreadyUsed
 		);
@@ -626,7 +658,8 @@ fn e2e_invalid_proof_rejected() {
 				bob(),
 				amount,
 				cid,
-				nullifier
+				nullifier,
+				None
 			),
 			Error::<TestRuntime>::ProofDeserializationFailed
 		);
@@ -676,7 +709,8 @@ fn submit_native_fails_zero_amount() {
 				alice(),
 				bob(),
 				0u128,
-				nullifier
+				nullifier,
+				None
 			),
 			Error::<TestRuntime>::AmountMustBePositive
 		);
@@ -704,7 +738,8 @@ fn submit_native_fails_self_send() {
 				alice(),
 				alice(),
 				100u128,
-				nullifier
+				nullifier,
+				None
 			),
 			Error::<TestRuntime>::SenderEqualsRecipient
 		);
@@ -726,7 +761,8 @@ fn submit_native_fails_no_identity() {
 				alice(),
 				bob(),
 				100u128,
-				nullifier
+				nullifier,
+				None
 			),
 			Error::<TestRuntime>::NoOfflineIdentity
 		);
@@ -757,7 +793,8 @@ fn submit_native_fails_used_nullifier() {
 				alice(),
 				bob(),
 				100u128,
-				nullifier
+				nullifier,
+				None
 			),
 			Error::<TestRuntime>::NullifierAlreadyUsed
 		);
@@ -786,7 +823,8 @@ fn submit_native_fails_no_vk() {
 				alice(),
 				bob(),
 				100u128,
-				nullifier
+				nullifier,
+				None
 			),
 			Error::<TestRuntime>::NoVerificationKey
 		);
@@ -815,7 +853,8 @@ fn submit_native_fails_insufficient_balance() {
 				alice(),
 				bob(),
 				100u128,
-				nullifier
+				nullifier,
+				None
 			),
 			Error::<TestRuntime>::InsufficientBalance
 		);
@@ -854,7 +893,8 @@ fn submit_native_fails_invalid_proof() {
 				alice(),
 				bob(),
 				100u128,
-				nullifier
+				nullifier,
+				None
 			),
 			Error::<TestRuntime>::ProofDeserializationFailed
 		);
@@ -890,7 +930,8 @@ fn nullifier_shared_between_cc_and_native() {
 				alice(),
 				bob(),
 				100u128,
-				nullifier
+				nullifier,
+				None
 			),
 			Error::<TestRuntime>::NullifierAlreadyUsed
 		);
@@ -909,7 +950,8 @@ fn nullifier_shared_between_cc_and_native() {
 				bob(),
 				BalanceType::from_num(10),
 				cid,
-				nullifier2
+				nullifier2,
+				None
 			),
 			Error::<TestRuntime>::NullifierAlreadyUsed
 		);
@@ -983,6 +1025,7 @@ fn e2e_native_zk_payment_works() {
 		let bounded_proof: BoundedVec<u8, MaxProofSize> =
 			BoundedVec::try_from(proof_bytes).expect("Proof too large");
 		let proof_struct = Groth16ProofBytes { proof_bytes: bounded_proof };
+		let reference = PaymentReference::truncate_from(b"invoice-42".to_vec());
 
 		assert_ok!(EncointerOfflinePayment::submit_native_offline_payment(
 			RuntimeOrigin::signed(charlie()),
@@ -990,8 +1033,19 @@ fn e2e_native_zk_payment_works() {
 			alice(),
 			bob(),
 			amount,
-			nullifier
+			nullifier,
+			Some(reference.clone())
 		));
+		System::assert_last_event(
+			Event::<TestRuntime>::NativeOfflinePaymentSettled {
+				sender: alice(),
+				recipient: bob(),
+				amount,
+				nullifier,
+				reference: Some(reference),
+			}
+			.into(),
+		);
 
 		assert_eq!(<Balances as Currency<_>>::free_balance(&alice()), 900);
 		assert_eq!(<Balances as Currency<_>>::free_balance(&bob()), 100);
@@ -1008,7 +1062,8 @@ fn e2e_native_zk_payment_works() {
 				alice(),
 				bob(),
 				amount,
-				nullifier
+				nullifier,
+				None
 			),
 			Error::<TestRuntime>::NullifierAlreadyUsed
 		);
@@ -18,16 +18,23 @@ use ep_core::fixed::types::I64F64;
 use log::{trace, warn};
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_std::fmt::Debug;
+use sp_std::{fmt::Debug, vec::Vec};
 
 #[cfg(feature = "serde_derive")]
 use serde::{Deserialize, Serialize};
-use sp_runtime::traits::{AtLeast32Bit, Convert};
+use sp_runtime::{
+	traits::{AtLeast32Bit, ConstU32, Convert},
+	BoundedVec,
+};
 
-use crate::fixed::{
-	traits::ToFixed,
-	transcendental::exp,
-	types::{U64F64, U66F62},
+use crate::{
+	communities::CommunityIdentifier,
+	fixed::{
+		traits::ToFixed,
+		transcendental::exp,
+		types::{U64F64, U66F62},
+	},
+	scheduler::CeremonyIndexType,
 };
 #[cfg(feature = "serde_derive")]
 use ep_core::serde::serialize_fixed;
@@ -47,6 +54,11 @@ pub type Demurrage = I64F64;
 
 pub type FeeConversionFactorType = u128;
 
+pub type StandingOrderIdType = u64;
+
+/// Free-form reference attached to a payment, e.g. an invoice number
+pub type PaymentReference = BoundedVec<u8, ConstU32<64>>;
+
 #[derive(
 	Encode,
 	Decode,
@@ -133,6 +145,130 @@ where
 	}
 }
 
+/// An amount of community currency an owner allows a spender to transfer on its behalf.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Default,
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
+pub struct Allowance<BlockNumber, Balance> {
+	/// The remaining allowance, which is subject to the same demurrage as the owner's balance
+	pub amount: BalanceEntry<BlockNumber>,
+	/// The last block at which the allowance can be used. `None` if it does not expire
+	pub expiry: Option<BlockNumber>,
+	/// The native tokens reserved from the owner as long as the allowance exists
+	pub deposit: Balance,
+}
+
+/// How often a standing order pays out.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
+pub enum StandingOrderPeriod<BlockNumber> {
+	/// Every `n` blocks
+	Blocks(BlockNumber),
+	/// Once per ceremony cycle
+	CeremonyCycle,
+}
+
+/// When a standing order is due next. Orders due at a block sort before orders due at a
+/// ceremony.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
+pub enum StandingOrderDue<BlockNumber> {
+	/// Due once this block has been reached
+	Block(BlockNumber),
+	/// Due once this ceremony index has been reached
+	Ceremony(CeremonyIndexType),
+}
+
+/// A recurring payment of community currency.
+#[derive(
+	Encode, Decode, DecodeWithMemTracking, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
+pub struct StandingOrder<AccountId, BlockNumber, Balance> {
+	pub payer: AccountId,
+	pub recipient: AccountId,
+	pub cid: CommunityIdentifier,
+	#[cfg_attr(feature = "serde_derive", serde(with = "serialize_fixed"))]
+	pub amount: BalanceType,
+	pub period: StandingOrderPeriod<BlockNumber>,
+	/// No payments are made after this block. `None` if the order runs until it is cancelled
+	pub end: Option<BlockNumber>,
+	/// The native tokens reserved from the payer as long as the order exists
+	pub deposit: Balance,
+}
+
+pub mod consts {
+	pub const BALANCE_HISTORY_KEY: &[u8] = b"balance_history";
+	pub const BALANCE_HISTORY_LINK_KEY: &[u8] = b"balance_history_link";
+	pub const BALANCE_HISTORY_HEAD_KEY: &[u8] = b"balance_history_head";
+}
+
+/// Offchain index key of the principal of `account` in `cid` after its change in `block`.
+pub fn balance_history_key<AccountId: Encode, BlockNumber: Encode>(
+	cid: &CommunityIdentifier,
+	account: &AccountId,
+	block: &BlockNumber,
+) -> Vec<u8> {
+	(consts::BALANCE_HISTORY_KEY, cid, account, block).encode()
+}
+
+/// Offchain index key of the block of the change of `account` in `cid` preceding the one in
+/// `block`. The value is `None` if the account was created in `block`.
+pub fn balance_history_link_key<AccountId: Encode, BlockNumber: Encode>(
+	cid: &CommunityIdentifier,
+	account: &AccountId,
+	block: &BlockNumber,
+) -> Vec<u8> {
+	(consts::BALANCE_HISTORY_LINK_KEY, cid, account, block).encode()
+}
+
+/// Offchain index key of the block of the most recent change of `account` in `cid`.
+pub fn balance_history_head_key<AccountId: Encode>(
+	cid: &CommunityIdentifier,
+	account: &AccountId,
+) -> Vec<u8> {
+	(consts::BALANCE_HISTORY_HEAD_KEY, cid, account).encode()
+}
+
 /// e^(-demurrage_per_block * elapsed_blocks) within [0,1).
 ///
 /// It will take the absolute value of the `demurrage_per_block` if it is negative.
@@ -16,9 +16,12 @@
 
 pub use crate::scheduler::CeremonyIndexType;
 use crate::{
-	communities::{CommunityIdentifier, Location},
+	balances::BalanceType,
+	communities::{CommunityIdentifier, GeoHash, Location},
 	scheduler::CeremonyPhaseType,
 };
+#[cfg(feature = "serde_derive")]
+use ep_core::serde::serialize_fixed;
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 #[cfg(feature = "serde_derive")]
@@ -36,11 +39,14 @@ pub type AttestationIndexType = u64;
 pub type CommunityCeremony = (CommunityIdentifier, CeremonyIndexType);
 pub type InactivityTimeoutType = u32;
 pub type EndorsementTicketsType = u8;
+pub type RegionIndexType = u32;
 
 /// reputation lifetime may not be longer than CeremonyIndexShort::MAX, otherwise double-using
 /// reputation is possible. therefore, we restrict the type to u8
 pub type ReputationLifetimeType = u32;
 pub type MeetupTimeOffsetType = i32;
+/// Meetup time offsets are limited to [-8h, 8h] [ms]
+pub const MAX_MEETUP_TIME_OFFSET_MS: MeetupTimeOffsetType = 8 * 3600 * 1000;
 pub type MeetupData<AccountId, Moment> =
 	(CeremonyIndexType, MeetupIndexType, Vec<AccountId>, Location, Moment);
 pub type ReputationCountType = u128;
@@ -109,7 +115,19 @@ pub enum ParticipantType {
 	Newbie,
 }
 
-#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Default, Debug, TypeInfo, MaxEncodedLen)]
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	Default,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
 #[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
 pub struct ClaimOfAttendance<Signature, AccountId, Moment> {
@@ -361,6 +379,76 @@ pub struct Assignment {
 	pub locations: AssignmentParams,
 }
 
+impl Assignment {
+	pub fn params(&self, group: AssignmentGroup) -> AssignmentParams {
+		match group {
+			AssignmentGroup::BootstrappersReputables => self.bootstrappers_reputables,
+			AssignmentGroup::Endorsees => self.endorsees,
+			AssignmentGroup::Newbies => self.newbies,
+		}
+	}
+}
+
+/// Participant groups which are spread over the meetups independently of each other.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+pub enum AssignmentGroup {
+	BootstrappersReputables,
+	Endorsees,
+	Newbies,
+}
+
+impl AssignmentGroup {
+	pub const ALL: [AssignmentGroup; 3] = [
+		AssignmentGroup::BootstrappersReputables,
+		AssignmentGroup::Endorsees,
+		AssignmentGroup::Newbies,
+	];
+}
+
+impl AssignmentCount {
+	/// number of assigned participants in `group`
+	pub fn of_group(&self, group: AssignmentGroup) -> ParticipantIndexType {
+		match group {
+			AssignmentGroup::BootstrappersReputables => self.bootstrappers + self.reputables,
+			AssignmentGroup::Endorsees => self.endorsees,
+			AssignmentGroup::Newbies => self.newbies,
+		}
+	}
+}
+
+/// Meetup assignment for the participants who registered a preference for `region`.
+///
+/// The region owns the meetup indices `meetup_offset + 1..=meetup_offset + meetup_count`, which all
+/// take place at locations within the region.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct RegionalAssignment {
+	pub region: GeoHash,
+	pub meetup_offset: MeetupIndexType,
+	pub meetup_count: MeetupIndexType,
+	pub counts: AssignmentCount,
+	pub params: Assignment,
+}
+
+impl RegionalAssignment {
+	pub fn contains_meetup(&self, meetup_index: MeetupIndexType) -> bool {
+		meetup_index > self.meetup_offset && meetup_index <= self.meetup_offset + self.meetup_count
+	}
+}
+
 // Todo: abstract AssignmentParams trait and use two different structs: AssignmentParams,
 // LocationAssignmentParams
 #[derive(
@@ -443,6 +531,73 @@ pub struct AggregatedAccountData<AccountId, Moment> {
 	pub personal: Option<AggregatedAccountDataPersonal<AccountId, Moment>>,
 }
 
+/// The meetup an account is assigned to at the current ceremony.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
+pub struct MeetupAssignment<AccountId, Moment> {
+	pub meetup_index: MeetupIndexType,
+	pub participants: Vec<AccountId>,
+	pub location: Option<Location>,
+	pub time: Option<Moment>,
+}
+
+/// All attestations registered at one ceremony of a community.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
+pub struct CeremonyAttestations<AccountId> {
+	pub ceremony_index: CeremonyIndexType,
+	/// Number of meetups the ceremony was split into.
+	pub meetup_count: MeetupIndexType,
+	/// Each attester with the participants it attested.
+	pub attestations: Vec<(AccountId, Vec<AccountId>)>,
+}
+
+/// Thresholds above which repeated co-attendance of two accounts is considered suspicious.
+#[derive(Encode, Decode, DecodeWithMemTracking, Copy, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
+pub struct CollusionDetectionParams {
+	/// Minimum number of ceremonies two accounts must have met at.
+	pub min_co_attendances: u32,
+	/// Minimum ratio of actual to expected co-attendances under random assignment.
+	#[cfg_attr(feature = "serde_derive", serde(with = "serialize_fixed"))]
+	pub min_excess_ratio: BalanceType,
+}
+
+impl Default for CollusionDetectionParams {
+	fn default() -> Self {
+		Self { min_co_attendances: 3, min_excess_ratio: BalanceType::from_num(3) }
+	}
+}
+
+/// Two accounts that met more often than random meetup assignment explains.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
+pub struct SuspiciousPair<AccountId> {
+	pub accounts: (AccountId, AccountId),
+	/// Number of ceremonies at which both accounts attested each other.
+	pub co_attendances: u32,
+	/// Number of ceremonies both accounts attended.
+	pub shared_ceremonies: u32,
+	/// Co-attendances expected if meetups were assigned at random.
+	#[cfg_attr(feature = "serde_derive", serde(with = "serialize_fixed"))]
+	pub expected_co_attendances: BalanceType,
+}
+
+/// Result of a collusion analysis over the attestation history of a community.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
+pub struct CollusionReport<AccountId> {
+	pub analyzed_ceremonies: Vec<CeremonyIndexType>,
+	pub suspicious_pairs: Vec<SuspiciousPair<AccountId>>,
+	/// Groups of accounts connected by suspicious pairs, each sorted.
+	pub clusters: Vec<Vec<AccountId>>,
+}
+
 #[derive(
 	Encode,
 	Decode,
@@ -490,6 +645,71 @@ pub enum MeetupResult {
 	MeetupValidationIndexOutOfBounds,
 }
 
+/// Number of meetups of a ceremony per `MeetupResult`.
+#[derive(
+	Default,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
+pub struct MeetupResultCounts {
+	pub ok: MeetupIndexType,
+	pub votes_not_dependable: MeetupIndexType,
+	pub meetup_validation_index_out_of_bounds: MeetupIndexType,
+	/// Meetups which have not been evaluated until the end of the reward issuance.
+	pub not_evaluated: MeetupIndexType,
+}
+
+impl MeetupResultCounts {
+	pub fn add(&mut self, result: MeetupResult) {
+		let count = match result {
+			MeetupResult::Ok => &mut self.ok,
+			MeetupResult::VotesNotDependable => &mut self.votes_not_dependable,
+			MeetupResult::MeetupValidationIndexOutOfBounds =>
+				&mut self.meetup_validation_index_out_of_bounds,
+		};
+		*count = count.saturating_add(1);
+	}
+}
+
+/// Statistics of a community ceremony, which are kept after its registry has been purged.
+#[derive(
+	Default,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
+pub struct CeremonyStats {
+	pub bootstrapper_count: ParticipantIndexType,
+	pub reputable_count: ParticipantIndexType,
+	pub endorsee_count: ParticipantIndexType,
+	pub newbie_count: ParticipantIndexType,
+	pub meetup_count: MeetupIndexType,
+	pub meetup_results: MeetupResultCounts,
+	pub reputation_count: ReputationCountType,
+	/// Total nominal income issued as rewards.
+	#[cfg_attr(feature = "serde_derive", serde(with = "serialize_fixed"))]
+	pub issued: BalanceType,
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -257,12 +257,35 @@ pub enum AnnouncementSigner {
 #[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
 pub enum CommunityRules {
+	/// Local currency with strict meetups: every meetup is assigned one of the community's
+	/// locations and takes place at the local meetup time, within the global location and time
+	/// tolerances.
 	#[default]
 	LoCo,
+	/// Like `LoCo`, but the location and time tolerances are widened, so participants may meet
+	/// further away from the assigned location and outside the exact meetup time. Attestations
+	/// must carry the attestee's signed claim of attendance, against which the tolerances are
+	/// checked.
 	LoCoFlex,
+	/// Meetups are not assigned a location or time. Participants find each other during the
+	/// attesting phase, so the number of meetups isn't limited by the community's locations.
+	/// To compensate, every participant must be attested by all other participants, and
+	/// attestations must carry the attestee's signed claim of attendance.
 	BeeDance,
 }
 
+impl CommunityRules {
+	/// Returns true if meetups are assigned a location and a meetup time.
+	pub fn has_meetup_locations(&self) -> bool {
+		!matches!(self, CommunityRules::BeeDance)
+	}
+
+	/// Returns true if attestations must carry the attestee's signed claim of attendance.
+	pub fn requires_signed_claims(&self) -> bool {
+		!matches!(self, CommunityRules::LoCo)
+	}
+}
+
 #[derive(
 	Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
 )]
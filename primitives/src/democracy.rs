@@ -1,6 +1,6 @@
 use crate::{
-	balances::Demurrage,
-	ceremonies::{CommunityCeremony, InactivityTimeoutType},
+	balances::{BalanceType, Demurrage},
+	ceremonies::{CommunityCeremony, InactivityTimeoutType, MeetupTimeOffsetType},
 	communities::{
 		CommunityIdentifier, CommunityMetadata as CommunityMetadataType, Location,
 		NominalIncome as NominalIncomeType,
@@ -63,6 +63,118 @@ pub enum Vote {
 	Nay,
 }
 
+/// Amplification of a reputation vote by locking native tokens.
+///
+/// Every step doubles the lock period but only adds one more vote multiple, so longer locks
+/// yield diminishing returns.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Default,
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
+pub enum Conviction {
+	/// no lock, 1x votes
+	#[default]
+	None,
+	/// locked for 1 ceremony cycle, 2x votes
+	Locked1x,
+	/// locked for 2 ceremony cycles, 3x votes
+	Locked2x,
+	/// locked for 4 ceremony cycles, 4x votes
+	Locked3x,
+	/// locked for 8 ceremony cycles, 5x votes
+	Locked4x,
+	/// locked for 16 ceremony cycles, 6x votes
+	Locked5x,
+	/// locked for 32 ceremony cycles, 7x votes
+	Locked6x,
+}
+
+impl Conviction {
+	/// Number of ceremony cycles the native tokens stay locked.
+	pub fn lock_periods(self) -> CeremonyIndexType {
+		match self {
+			Conviction::None => 0,
+			Conviction::Locked1x => 1,
+			Conviction::Locked2x => 2,
+			Conviction::Locked3x => 4,
+			Conviction::Locked4x => 8,
+			Conviction::Locked5x => 16,
+			Conviction::Locked6x => 32,
+		}
+	}
+
+	/// Amplified vote count for `votes` reputation votes.
+	pub fn votes(self, votes: VoteCountType) -> VoteCountType {
+		let multiplier: VoteCountType = match self {
+			Conviction::None => 1,
+			Conviction::Locked1x => 2,
+			Conviction::Locked2x => 3,
+			Conviction::Locked3x => 4,
+			Conviction::Locked4x => 5,
+			Conviction::Locked5x => 6,
+			Conviction::Locked6x => 7,
+		};
+		votes.saturating_mul(multiplier)
+	}
+}
+
+/// Tally of conviction-amplified votes, kept next to the reputation `Tally` for proposals in
+/// hybrid tally mode.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Default,
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
+pub struct ConvictionTally {
+	pub turnout: VoteCountType,
+	pub ayes: VoteCountType,
+}
+
+/// Native tokens locked by a conviction vote.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
+pub struct VoteLock<Balance> {
+	pub proposal_id: ProposalIdType,
+	pub amount: Balance,
+	/// first ceremony index at which the tokens can be unlocked
+	pub unlock_cindex: CeremonyIndexType,
+}
+
 #[derive(
 	Encode,
 	Decode,
@@ -99,23 +211,20 @@ pub enum ProposalAction<AccountId, Balance, Moment, AssetId> {
 	IssueSwapNativeOption(CommunityIdentifier, AccountId, SwapNativeOption<Balance, Moment>),
 	SpendAsset(Option<CommunityIdentifier>, AccountId, Balance, AssetId),
 	IssueSwapAssetOption(CommunityIdentifier, AccountId, SwapAssetOption<Balance, Moment, AssetId>),
+	SetLocationMeetupTimeOffset(CommunityIdentifier, Location, Option<MeetupTimeOffsetType>),
+	AddBootstrapper(CommunityIdentifier, AccountId),
+	RemoveBootstrapper(CommunityIdentifier, AccountId),
+	ReplaceBootstrapper(CommunityIdentifier, AccountId, AccountId),
+	SetDemurrageExemption(CommunityIdentifier, AccountId, bool),
+	UpdateExistentialDeposit(CommunityIdentifier, BalanceType),
 }
 
 #[derive(
-	Encode,
-	Decode,
-	DecodeWithMemTracking,
-	Debug,
-	Clone,
-	Copy,
-	PartialEq,
-	Eq,
-	TypeInfo,
-	MaxEncodedLen,
+	Encode, Decode, DecodeWithMemTracking, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen,
 )]
 #[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
-pub enum ProposalActionIdentifier {
+pub enum ProposalActionIdentifier<AccountId> {
 	AddLocation(CommunityIdentifier),
 	RemoveLocation(CommunityIdentifier),
 	UpdateCommunityMetadata(CommunityIdentifier),
@@ -127,9 +236,17 @@ pub enum ProposalActionIdentifier {
 	IssueSwapNativeOption(CommunityIdentifier),
 	SpendAsset(Option<CommunityIdentifier>),
 	IssueSwapAssetOption(CommunityIdentifier),
+	SetLocationMeetupTimeOffset(CommunityIdentifier),
+	AddBootstrapper(CommunityIdentifier),
+	RemoveBootstrapper(CommunityIdentifier),
+	ReplaceBootstrapper(CommunityIdentifier),
+	SetDemurrageExemption(CommunityIdentifier, AccountId),
+	UpdateExistentialDeposit(CommunityIdentifier),
 }
 
-impl<AccountId, Balance, Moment, AssetId> ProposalAction<AccountId, Balance, Moment, AssetId> {
+impl<AccountId: Clone, Balance, Moment, AssetId>
+	ProposalAction<AccountId, Balance, Moment, AssetId>
+{
 	pub fn get_access_policy(&self) -> ProposalAccessPolicy {
 		match self {
 			ProposalAction::AddLocation(cid, _) => ProposalAccessPolicy::Community(*cid),
@@ -147,10 +264,18 @@ impl<AccountId, Balance, Moment, AssetId> ProposalAction<AccountId, Balance, Mom
 			ProposalAction::SpendAsset(Some(cid), ..) => ProposalAccessPolicy::Community(*cid),
 			ProposalAction::SpendAsset(None, ..) => ProposalAccessPolicy::Global,
 			ProposalAction::IssueSwapAssetOption(cid, ..) => ProposalAccessPolicy::Community(*cid),
+			ProposalAction::SetLocationMeetupTimeOffset(cid, ..) =>
+				ProposalAccessPolicy::Community(*cid),
+			ProposalAction::AddBootstrapper(cid, _) => ProposalAccessPolicy::Community(*cid),
+			ProposalAction::RemoveBootstrapper(cid, _) => ProposalAccessPolicy::Community(*cid),
+			ProposalAction::ReplaceBootstrapper(cid, ..) => ProposalAccessPolicy::Community(*cid),
+			ProposalAction::SetDemurrageExemption(cid, ..) => ProposalAccessPolicy::Community(*cid),
+			ProposalAction::UpdateExistentialDeposit(cid, _) =>
+				ProposalAccessPolicy::Community(*cid),
 		}
 	}
 
-	pub fn get_identifier(&self) -> ProposalActionIdentifier {
+	pub fn get_identifier(&self) -> ProposalActionIdentifier<AccountId> {
 		match self {
 			ProposalAction::AddLocation(cid, _) => ProposalActionIdentifier::AddLocation(*cid),
 			ProposalAction::RemoveLocation(cid, _) =>
@@ -173,9 +298,32 @@ impl<AccountId, Balance, Moment, AssetId> ProposalAction<AccountId, Balance, Mom
 				ProposalActionIdentifier::SpendAsset(*maybe_cid),
 			ProposalAction::IssueSwapAssetOption(cid, ..) =>
 				ProposalActionIdentifier::IssueSwapAssetOption(*cid),
+			ProposalAction::SetLocationMeetupTimeOffset(cid, ..) =>
+				ProposalActionIdentifier::SetLocationMeetupTimeOffset(*cid),
+			ProposalAction::AddBootstrapper(cid, _) =>
+				ProposalActionIdentifier::AddBootstrapper(*cid),
+			ProposalAction::RemoveBootstrapper(cid, _) =>
+				ProposalActionIdentifier::RemoveBootstrapper(*cid),
+			ProposalAction::ReplaceBootstrapper(cid, ..) =>
+				ProposalActionIdentifier::ReplaceBootstrapper(*cid),
+			ProposalAction::SetDemurrageExemption(cid, account, _) =>
+				ProposalActionIdentifier::SetDemurrageExemption(*cid, account.clone()),
+			ProposalAction::UpdateExistentialDeposit(cid, _) =>
+				ProposalActionIdentifier::UpdateExistentialDeposit(*cid),
 		}
 	}
 
+	/// Returns true if the action spends or commits treasury funds.
+	pub fn spends_treasury_funds(&self) -> bool {
+		matches!(
+			self,
+			ProposalAction::SpendNative(..) |
+				ProposalAction::IssueSwapNativeOption(..) |
+				ProposalAction::SpendAsset(..) |
+				ProposalAction::IssueSwapAssetOption(..)
+		)
+	}
+
 	/// Returns true if the action supersedes other proposals of the same action type when approved.
 	pub fn supersedes_same_action(&self) -> bool {
 		match self {
@@ -190,6 +338,12 @@ impl<AccountId, Balance, Moment, AssetId> ProposalAction<AccountId, Balance, Mom
 			ProposalAction::IssueSwapNativeOption(..) => false,
 			ProposalAction::SpendAsset(_, _, _, _) => false,
 			ProposalAction::IssueSwapAssetOption(..) => false,
+			ProposalAction::SetLocationMeetupTimeOffset(..) => true,
+			ProposalAction::AddBootstrapper(..) => false,
+			ProposalAction::RemoveBootstrapper(..) => false,
+			ProposalAction::ReplaceBootstrapper(..) => false,
+			ProposalAction::SetDemurrageExemption(..) => false,
+			ProposalAction::UpdateExistentialDeposit(_, _) => true,
 		}
 	}
 }
@@ -215,6 +369,7 @@ pub enum ProposalState<Moment> {
 	SupersededBy { id: ProposalIdType },
 	Rejected,
 	Enacted,
+	Cancelled,
 }
 
 impl<Moment: PartialEq> ProposalState<Moment> {
@@ -223,9 +378,49 @@ impl<Moment: PartialEq> ProposalState<Moment> {
 	}
 
 	pub fn has_failed(self) -> bool {
-		matches!(self, Self::SupersededBy { id: _ } | Self::Rejected)
+		matches!(self, Self::SupersededBy { id: _ } | Self::Rejected | Self::Cancelled)
 	}
 }
+/// Selects proposals by their state, e.g. for the `encointer_getProposals` rpc.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Default,
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde_derive", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_derive", serde(rename_all = "camelCase"))]
+pub enum ProposalFilter {
+	#[default]
+	All,
+	/// `Ongoing` or `Confirming`
+	Active,
+	/// `Approved` and waiting for enactment
+	Approved,
+	Enacted,
+	/// `Rejected`, `SupersededBy` or `Cancelled`
+	Failed,
+}
+
+impl ProposalFilter {
+	pub fn matches<Moment: PartialEq>(&self, state: ProposalState<Moment>) -> bool {
+		match self {
+			ProposalFilter::All => true,
+			ProposalFilter::Active => state.can_update(),
+			ProposalFilter::Approved => matches!(state, ProposalState::Approved),
+			ProposalFilter::Enacted => matches!(state, ProposalState::Enacted),
+			ProposalFilter::Failed => state.has_failed(),
+		}
+	}
+}
+
 #[derive(
 	Encode, Decode, DecodeWithMemTracking, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen,
 )]
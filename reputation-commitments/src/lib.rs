@@ -100,12 +100,13 @@ pub mod pallet {
 			Ok(current_id)
 		}
 
-		pub fn do_commit_reputation(
+		/// Checks whether `account` could commit its reputation of `(cid, cindex)` for `purpose`
+		/// without actually committing it.
+		pub fn can_commit_reputation(
 			account: &T::AccountId,
 			cid: CommunityIdentifier,
 			cindex: CeremonyIndexType,
 			purpose: PurposeIdType,
-			commitment_hash: Option<H256>,
 		) -> Result<(), Error<T>> {
 			if !<Purposes<T>>::contains_key(purpose) {
 				return Err(<Error<T>>::InexistentPurpose);
@@ -123,6 +124,17 @@ pub mod pallet {
 			if <Commitments<T>>::contains_key((cid, cindex), (purpose, &account)) {
 				return Err(<Error<T>>::AlreadyCommitted);
 			}
+			Ok(())
+		}
+
+		pub fn do_commit_reputation(
+			account: &T::AccountId,
+			cid: CommunityIdentifier,
+			cindex: CeremonyIndexType,
+			purpose: PurposeIdType,
+			commitment_hash: Option<H256>,
+		) -> Result<(), Error<T>> {
+			Self::can_commit_reputation(account, cid, cindex, purpose)?;
 
 			<Commitments<T>>::insert((cid, cindex), (purpose, &account), commitment_hash);
 			info!(
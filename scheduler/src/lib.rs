@@ -29,7 +29,7 @@ use frame_support::{
 };
 use log::{info, warn};
 use sp_runtime::traits::{CheckedDiv, One, Saturating, Zero};
-use sp_std::{ops::Rem, prelude::*};
+use sp_std::{marker::PhantomData, ops::Rem, prelude::*};
 
 // Logger target
 const LOG: &str = "encointer";
@@ -286,6 +286,15 @@ impl<T: Config> OnTimestampSet<T::Moment> for Pallet<T> {
 	}
 }
 
+/// Provides the current ceremony index to pallets which do not depend on the scheduler.
+pub struct CurrentCeremonyIndexGetter<T>(PhantomData<T>);
+
+impl<T: Config> Get<CeremonyIndexType> for CurrentCeremonyIndexGetter<T> {
+	fn get() -> CeremonyIndexType {
+		<CurrentCeremonyIndex<T>>::get()
+	}
+}
+
 /// An event handler for when the ceremony phase changes.
 #[impl_trait_for_tuples::impl_for_tuples(30)]
 pub trait OnCeremonyPhaseChange {
@@ -68,6 +68,7 @@ where
 		Default::default(),
 		None,
 		None,
+		None,
 	)
 	.unwrap();
 	CommunityIdentifier::new(location, bs).unwrap()
@@ -176,6 +176,17 @@ macro_rules! impl_encointer_balances {
 			type RuntimeEvent = RuntimeEvent;
 			type DefaultDemurrage = DefaultDemurrage;
 			type ExistentialDeposit = EncointerBalancesExistentialDeposit;
+			type RuntimeHoldReason = RuntimeHoldReason;
+			type RuntimeFreezeReason = RuntimeFreezeReason;
+			type MaxHolds = frame_support::traits::ConstU32<4>;
+			type MaxFreezes = frame_support::traits::ConstU32<4>;
+			type DepositCurrency = Balances;
+			type ApprovalDeposit = frame_support::traits::ConstU128<10>;
+			type StandingOrderDeposit = frame_support::traits::ConstU128<10>;
+			type CurrentCeremonyIndex = pallet_encointer_scheduler::CurrentCeremonyIndexGetter<$t>;
+			type MaxStandingOrders = frame_support::traits::ConstU32<100>;
+			type MaxStandingOrdersPerPayer = frame_support::traits::ConstU32<10>;
+			type MaxTransfersPerBatch = frame_support::traits::ConstU32<100>;
 			type WeightInfo = ();
 			type CeremonyMaster = EnsureAlice;
 		}
@@ -184,7 +195,7 @@ macro_rules! impl_encointer_balances {
 
 #[macro_export]
 macro_rules! impl_encointer_communities {
-	($t:ident) => {
+	($t:ident, $on_bootstrappers_changed:ty) => {
 		use sp_core::ConstU32;
 		impl pallet_encointer_communities::Config for $t {
 			type RuntimeEvent = RuntimeEvent;
@@ -195,8 +206,12 @@ macro_rules! impl_encointer_communities {
 			type MaxBootstrappers = ConstU32<15>;
 			type MaxLocationsPerGeohash = ConstU32<10>;
 			type MaxCommunityIdentifiersPerGeohash = ConstU32<10>;
+			type OnBootstrappersChanged = $on_bootstrappers_changed;
 		}
 	};
+	($t:ident) => {
+		impl_encointer_communities!($t, ());
+	};
 }
 
 #[macro_export]
@@ -259,8 +274,12 @@ macro_rules! impl_encointer_ceremonies {
 			type MeetupSizeTarget = MeetupSizeTarget;
 			type MeetupMinSize = MeetupMinSize;
 			type MeetupNewbieLimitDivider = MeetupNewbieLimitDivider;
+			type MinRegionalMeetupCount = frame_support::traits::ConstU64<2>;
 			type WeightInfo = ();
 			type MaxAttestations = ConstU32<25>;
+			type LoCoFlexToleranceFactor = ConstU32<3>;
+			type MaxDelegatedNewbieTickets = frame_support::traits::ConstU8<5>;
+			type MaxRegistrationProofs = ConstU32<3>;
 		}
 	};
 }